@@ -0,0 +1,75 @@
+use baby_shark::{
+    mesh::{corner_table::prelude::CornerTableD, half_edge::prelude::HalfEdgeMeshD, traits::Mesh},
+    decimation::{prelude::EdgeDecimator, edge_decimation::ConstantErrorDecimationCriteria},
+    remeshing::incremental::IncrementalRemesher
+};
+use criterion::{criterion_group, criterion_main, Criterion, BatchSize};
+use nalgebra::Point3;
+
+fn uv_sphere(rings: usize, segments: usize) -> (Vec<Point3<f64>>, Vec<usize>) {
+    let mut vertices = vec![Point3::new(0.0, 0.0, 1.0), Point3::new(0.0, 0.0, -1.0)];
+    let mut indices = Vec::new();
+
+    for ring in 1..rings {
+        let theta = std::f64::consts::PI * ring as f64 / rings as f64;
+
+        for segment in 0..segments {
+            let phi = 2.0 * std::f64::consts::PI * segment as f64 / segments as f64;
+            vertices.push(Point3::new(theta.sin() * phi.cos(), theta.sin() * phi.sin(), theta.cos()));
+        }
+    }
+
+    let vertex = |ring: usize, segment: usize| 2 + (ring - 1) * segments + segment % segments;
+
+    for segment in 0..segments {
+        indices.extend([0, vertex(1, segment), vertex(1, segment + 1)]);
+        indices.extend([1, vertex(rings - 1, segment + 1), vertex(rings - 1, segment)]);
+
+        for ring in 1..rings - 1 {
+            indices.extend([vertex(ring, segment), vertex(ring + 1, segment), vertex(ring + 1, segment + 1)]);
+            indices.extend([vertex(ring, segment), vertex(ring + 1, segment + 1), vertex(ring, segment + 1)]);
+        }
+    }
+
+    return (vertices, indices);
+}
+
+fn criterion_benchmark(c: &mut Criterion) {
+    let (vertices, indices) = uv_sphere(100, 200);
+
+    let mut group = c.benchmark_group("decimation");
+
+    group.bench_function("corner table", |b| b.iter_batched(
+        || CornerTableD::from_vertices_and_indices(&vertices, &indices),
+        |mut mesh| EdgeDecimator::new().decimation_criteria(ConstantErrorDecimationCriteria::new(0.001)).decimate(&mut mesh),
+        BatchSize::LargeInput
+    ));
+
+    group.bench_function("half-edge", |b| b.iter_batched(
+        || HalfEdgeMeshD::from_vertices_and_indices(&vertices, &indices),
+        |mut mesh| EdgeDecimator::new().decimation_criteria(ConstantErrorDecimationCriteria::new(0.001)).decimate(&mut mesh),
+        BatchSize::LargeInput
+    ));
+
+    group.finish();
+
+    let mut group = c.benchmark_group("remeshing");
+    group.sample_size(10);
+
+    group.bench_function("corner table", |b| b.iter_batched(
+        || CornerTableD::from_vertices_and_indices(&vertices, &indices),
+        |mut mesh| IncrementalRemesher::new().with_iterations_count(3).remesh(&mut mesh, 0.03),
+        BatchSize::LargeInput
+    ));
+
+    group.bench_function("half-edge", |b| b.iter_batched(
+        || HalfEdgeMeshD::from_vertices_and_indices(&vertices, &indices),
+        |mut mesh| IncrementalRemesher::new().with_iterations_count(3).remesh(&mut mesh, 0.03),
+        BatchSize::LargeInput
+    ));
+
+    group.finish();
+}
+
+criterion_group!(benches, criterion_benchmark);
+criterion_main!(benches);
@@ -3,3 +3,5 @@ pub mod float_hash;
 pub mod utils;
 pub mod edge_collapse;
 pub mod vertex_shift;
+pub mod topology;
+pub mod orientation;
@@ -0,0 +1,217 @@
+use std::collections::HashMap;
+
+use nalgebra::Point3;
+
+use crate::{algo::utils::triple_product, geometry::traits::RealNumber};
+
+/// Face orientation repair applied to index buffer before mesh is built, see [orient_faces]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum FaceOrientation {
+    /// Winding of faces is left as is
+    Keep,
+    /// Winding is made consistent across shared edges, first face of every component keeps its winding
+    Consistent,
+    /// Winding is made consistent and closed components are oriented so their normals point outward
+    Outward
+}
+
+impl Default for FaceOrientation {
+    #[inline]
+    fn default() -> Self {
+        return FaceOrientation::Keep;
+    }
+}
+
+///
+/// Repairs winding of triangles given by index buffer (three indices per face).
+/// Winding is flood-filled from first face of every component across edges shared by exactly two faces,
+/// faces that traverse shared edge in same direction as their neighbor are flipped.
+/// With [FaceOrientation::Outward] components without boundary and non-manifold edges are also flipped
+/// as a whole when their signed volume is negative.
+/// Face is flipped by swapping its second and third index. Returns indices of flipped faces in ascending order.
+///
+/// ## Example
+/// ```ignore
+/// let flipped = orient_faces(&vertices, &mut indices, FaceOrientation::Outward);
+/// let mesh = CornerTableF::from_vertices_and_indices(&vertices, &indices);
+/// ```
+///
+pub fn orient_faces<TScalar: RealNumber>(vertices: &[Point3<TScalar>], indices: &mut [usize], orientation: FaceOrientation) -> Vec<usize> {
+    if orientation == FaceOrientation::Keep {
+        return Vec::new();
+    }
+
+    let faces_count = indices.len() / 3;
+
+    // Faces of every undirected edge
+    let mut edge_faces: HashMap<(usize, usize), Vec<usize>> = HashMap::with_capacity(indices.len());
+
+    for face in 0..faces_count {
+        for (start, end) in face_edges(indices, face) {
+            if start != end {
+                edge_faces.entry((start.min(end), start.max(end))).or_default().push(face);
+            }
+        }
+    }
+
+    let mut flipped = vec![false; faces_count];
+    let mut visited = vec![false; faces_count];
+    let mut stack = Vec::new();
+
+    for seed in 0..faces_count {
+        if visited[seed] {
+            continue;
+        }
+
+        let mut component = Vec::new();
+        visited[seed] = true;
+        stack.push(seed);
+
+        while let Some(face) = stack.pop() {
+            component.push(face);
+
+            for (start, end) in face_edges(indices, face) {
+                let Some([f1, f2]) = edge_faces.get(&(start.min(end), start.max(end))).map(Vec::as_slice) else {
+                    continue;
+                };
+
+                let neighbor = if *f1 == face { *f2 } else { *f1 };
+
+                if visited[neighbor] {
+                    continue;
+                }
+
+                // Consistently oriented neighbor traverses shared edge in opposite direction
+                if face_edges(indices, neighbor).contains(&(start, end)) {
+                    flip_face(indices, neighbor);
+                    flipped[neighbor] = true;
+                }
+
+                visited[neighbor] = true;
+                stack.push(neighbor);
+            }
+        }
+
+        if orientation == FaceOrientation::Outward && is_closed(indices, &component, &edge_faces) && signed_volume(vertices, indices, &component) < TScalar::zero() {
+            for face in component {
+                flip_face(indices, face);
+                flipped[face] = !flipped[face];
+            }
+        }
+    }
+
+    return (0..faces_count).filter(|face| flipped[*face]).collect();
+}
+
+/// Directed edges of face
+#[inline]
+fn face_edges(indices: &[usize], face: usize) -> [(usize, usize); 3] {
+    let (v1, v2, v3) = (indices[face * 3], indices[face * 3 + 1], indices[face * 3 + 2]);
+    return [(v1, v2), (v2, v3), (v3, v1)];
+}
+
+#[inline]
+fn flip_face(indices: &mut [usize], face: usize) {
+    indices.swap(face * 3 + 1, face * 3 + 2);
+}
+
+/// Returns `true` when every edge of component is shared by exactly two faces
+fn is_closed(indices: &[usize], component: &[usize], edge_faces: &HashMap<(usize, usize), Vec<usize>>) -> bool {
+    return component.iter()
+        .flat_map(|face| face_edges(indices, *face))
+        .all(|(start, end)| edge_faces.get(&(start.min(end), start.max(end))).is_some_and(|faces| faces.len() == 2));
+}
+
+/// Six times signed volume enclosed by faces
+fn signed_volume<TScalar: RealNumber>(vertices: &[Point3<TScalar>], indices: &[usize], component: &[usize]) -> TScalar {
+    let mut volume = TScalar::zero();
+
+    for face in component {
+        let [p1, p2, p3] = [0, 1, 2].map(|i| vertices[indices[face * 3 + i]].coords);
+        volume += triple_product(&p1, &p2, &p3);
+    }
+
+    return volume;
+}
+
+#[cfg(test)]
+mod tests {
+    use nalgebra::Point3;
+
+    use crate::{
+        algo::topology::topology_stats,
+        mesh::{corner_table::{prelude::CornerTableF, test_helpers::create_uv_sphere_mesh}, polygon_soup::data_structure::PolygonSoup, traits::Mesh}
+    };
+
+    use super::{orient_faces, FaceOrientation};
+
+    fn sphere_buffers() -> (Vec<Point3<f32>>, Vec<usize>) {
+        let mesh = create_uv_sphere_mesh(6, 8);
+        let vertices = mesh.vertices().map(|vertex| *mesh.vertex_position(&vertex)).collect();
+        let indices = mesh.faces()
+            .flat_map(|face| {
+                let (v1, v2, v3) = mesh.face_vertices(&face);
+                return [v1.index(), v2.index(), v3.index()];
+            })
+            .collect();
+
+        return (vertices, indices);
+    }
+
+    #[test]
+    fn should_make_winding_consistent() {
+        let (vertices, original) = sphere_buffers();
+        let mut indices = original.clone();
+
+        for face in [3, 10, 11, 40] {
+            indices.swap(face * 3 + 1, face * 3 + 2);
+        }
+
+        assert!(!topology_stats(&CornerTableF::from_vertices_and_indices(&vertices, &indices)).is_closed);
+
+        let flipped = orient_faces(&vertices, &mut indices, FaceOrientation::Consistent);
+        assert_eq!(flipped, vec![3, 10, 11, 40]);
+        assert_eq!(indices, original);
+
+        let stats = topology_stats(&CornerTableF::from_vertices_and_indices(&vertices, &indices));
+        assert!(stats.is_closed);
+        assert_eq!(stats.genus, Some(0));
+    }
+
+    #[test]
+    fn should_orient_closed_component_outward() {
+        let (vertices, original) = sphere_buffers();
+        let faces_count = original.len() / 3;
+
+        // Inside out sphere
+        let mut indices = original.clone();
+        for face in 0..faces_count {
+            indices.swap(face * 3 + 1, face * 3 + 2);
+        }
+
+        assert!(orient_faces(&vertices, &mut indices.clone(), FaceOrientation::Consistent).is_empty());
+        assert_eq!(orient_faces(&vertices, &mut indices, FaceOrientation::Outward).len(), faces_count);
+        assert_eq!(indices, original);
+
+        // First face is inverted, so flood fill turns sphere inside out before it is flipped as a whole
+        indices.swap(1, 2);
+        assert_eq!(orient_faces(&vertices, &mut indices, FaceOrientation::Outward), vec![0]);
+        assert_eq!(indices, original);
+
+        // Open surface is not reoriented
+        let mut open = vec![0, 2, 1];
+        assert!(orient_faces(&vertices, &mut open, FaceOrientation::Outward).is_empty());
+        assert!(orient_faces(&vertices, &mut indices, FaceOrientation::Keep).is_empty());
+    }
+
+    #[test]
+    fn should_orient_polygon_soup() {
+        let mut soup = PolygonSoup::new();
+        soup.add_face(Point3::new(0.0, 0.0, 0.0), Point3::new(1.0, 0.0, 0.0), Point3::new(1.0, 1.0, 0.0));
+        soup.add_face(Point3::new(0.0, 0.0, 0.0), Point3::new(1.0, 0.0, 0.0), Point3::new(0.0, -1.0, 0.0));
+
+        assert_eq!(soup.orient_faces(FaceOrientation::Consistent), vec![3]);
+        assert_eq!(soup.face_positions(&3).p2(), &Point3::new(0.0, -1.0, 0.0));
+        assert!(soup.orient_faces(FaceOrientation::Consistent).is_empty());
+    }
+}
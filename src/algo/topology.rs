@@ -0,0 +1,264 @@
+use std::collections::{HashMap, HashSet};
+
+use crate::mesh::traits::TopologicalMesh;
+
+///
+/// Topological summary of mesh, see [topology_stats]
+///
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct TopologyStats {
+    /// Number of vertices with incident faces, isolated vertices are ignored
+    pub vertices: usize,
+    pub edges: usize,
+    pub faces: usize,
+    /// Number of components connected through shared vertices
+    pub components: usize,
+    /// Number of boundary loops (holes)
+    pub boundary_loops: usize,
+    /// `V - E + F`
+    pub euler_characteristic: isize,
+    /// Total genus of all components, `None` when mesh is not manifold
+    pub genus: Option<usize>,
+    /// `true` when mesh has no boundary edges
+    pub is_closed: bool,
+    /// `true` when every edge is shared by at most two consistently oriented faces
+    /// and faces around every vertex form single fan
+    pub is_manifold: bool
+}
+
+impl TopologyStats {
+    /// Number of holes, same as number of boundary loops
+    #[inline]
+    pub fn holes(&self) -> usize {
+        return self.boundary_loops;
+    }
+}
+
+///
+/// Returns boundary loops of mesh as ordered vertex cycles.
+/// Vertices of loop follow direction of boundary edges in their faces, first vertex is not repeated at the end.
+/// Vertex where several boundary loops touch is visited by each of them.
+///
+pub fn boundary_loops<TMesh: TopologicalMesh>(mesh: &TMesh) -> Vec<Vec<TMesh::VertexDescriptor>> {
+    // Directed boundary edges grouped by start vertex
+    let mut outgoing: HashMap<_, Vec<_>> = HashMap::new();
+
+    for edge in mesh.edges().filter(|edge| mesh.is_edge_on_boundary(edge)) {
+        let (start, end) = oriented_edge_vertices(mesh, &edge);
+        outgoing.entry(start).or_default().push(end);
+    }
+
+    let mut starts: Vec<_> = outgoing.keys().copied().collect();
+    starts.sort();
+
+    let mut loops = Vec::new();
+
+    for start in starts {
+        while let Some(mut next) = outgoing.get_mut(&start).and_then(|ends| ends.pop()) {
+            let mut boundary_loop = vec![start];
+
+            while next != start {
+                boundary_loop.push(next);
+
+                match outgoing.get_mut(&next).and_then(|ends| ends.pop()) {
+                    Some(end) => next = end,
+                    None => break
+                }
+            }
+
+            loops.push(boundary_loop);
+        }
+    }
+
+    return loops;
+}
+
+///
+/// Computes Euler characteristic, genus, number of holes and components of mesh,
+/// and checks whether it is closed and manifold. Can be used to validate mesh before
+/// operations that require closed manifold input, like volume computation or hole filling.
+///
+pub fn topology_stats<TMesh: TopologicalMesh>(mesh: &TMesh) -> TopologyStats {
+    let mut vertex_faces = HashMap::new();
+    let mut directed_edges = HashSet::new();
+    let mut is_manifold = true;
+    let mut faces = 0;
+
+    for face in mesh.faces() {
+        let (v1, v2, v3) = mesh.face_vertices(&face);
+
+        for (start, end) in [(v1, v2), (v2, v3), (v3, v1)] {
+            *vertex_faces.entry(start).or_insert(0) += 1;
+
+            // Same directed edge in two faces means non-manifold edge or inconsistent orientation
+            is_manifold &= directed_edges.insert((start, end));
+        }
+
+        faces += 1;
+    }
+
+    // Faces not reachable from fan of vertex
+    for (vertex, count) in &vertex_faces {
+        let mut fan = 0;
+        mesh.faces_around_vertex(vertex, |_| fan += 1);
+        is_manifold &= fan == *count;
+    }
+
+    let edges = mesh.edges().count();
+    let boundary_loops = boundary_loops(mesh);
+
+    // Vertex where boundary loops touch is not manifold
+    let boundary_vertices: usize = boundary_loops.iter().map(|boundary_loop| boundary_loop.len()).sum();
+    let unique_boundary_vertices: HashSet<_> = boundary_loops.iter().flatten().collect();
+    is_manifold &= boundary_vertices == unique_boundary_vertices.len();
+
+    let components = count_components(mesh, vertex_faces.keys().copied());
+    let euler_characteristic = vertex_faces.len() as isize - edges as isize + faces as isize;
+
+    // χ = 2c - 2g - b for orientable surfaces
+    let double_genus = 2 * components as isize - boundary_loops.len() as isize - euler_characteristic;
+    let genus = if is_manifold && double_genus >= 0 && double_genus % 2 == 0 {
+        Some(double_genus as usize / 2)
+    } else {
+        None
+    };
+
+    return TopologyStats {
+        vertices: vertex_faces.len(),
+        edges,
+        faces,
+        components,
+        boundary_loops: boundary_loops.len(),
+        euler_characteristic,
+        genus,
+        is_closed: boundary_loops.is_empty(),
+        is_manifold
+    };
+}
+
+/// Returns vertices of boundary edge in order of its face
+fn oriented_edge_vertices<TMesh: TopologicalMesh>(mesh: &TMesh, edge: &TMesh::EdgeDescriptor) -> (TMesh::VertexDescriptor, TMesh::VertexDescriptor) {
+    let (start, end) = mesh.edge_vertices(edge);
+    let (face, _) = mesh.edge_faces(edge);
+    let (v1, v2, v3) = mesh.face_vertices(&face);
+
+    if [(v1, v2), (v2, v3), (v3, v1)].contains(&(start, end)) {
+        return (start, end);
+    }
+
+    return (end, start);
+}
+
+/// Counts groups of vertices connected by faces
+fn count_components<TMesh: TopologicalMesh>(mesh: &TMesh, vertices: impl Iterator<Item = TMesh::VertexDescriptor>) -> usize {
+    let vertex_index: HashMap<_, _> = vertices.enumerate().map(|(index, vertex)| (vertex, index)).collect();
+    let mut parents: Vec<_> = (0..vertex_index.len()).collect();
+
+    fn root(parents: &mut [usize], mut index: usize) -> usize {
+        while parents[index] != index {
+            parents[index] = parents[parents[index]];
+            index = parents[index];
+        }
+
+        return index;
+    }
+
+    for face in mesh.faces() {
+        let (v1, v2, v3) = mesh.face_vertices(&face);
+        let r1 = root(&mut parents, vertex_index[&v1]);
+
+        for vertex in [v2, v3] {
+            let r = root(&mut parents, vertex_index[&vertex]);
+            parents[r] = r1;
+        }
+    }
+
+    return (0..parents.len()).filter(|index| root(&mut parents, *index) == *index).count();
+}
+
+#[cfg(test)]
+mod tests {
+    use nalgebra::Point3;
+
+    use crate::mesh::{
+        corner_table::{prelude::CornerTableF, test_helpers::{create_uv_sphere_mesh, create_torus_mesh, create_unit_square_mesh}},
+        half_edge::prelude::HalfEdgeMeshF,
+        traits::Mesh
+    };
+
+    use super::{boundary_loops, topology_stats};
+
+    #[test]
+    fn closed_meshes() {
+        let sphere = topology_stats(&create_uv_sphere_mesh(6, 8));
+        assert!(sphere.is_closed);
+        assert!(sphere.is_manifold);
+        assert_eq!(sphere.euler_characteristic, 2);
+        assert_eq!(sphere.genus, Some(0));
+        assert_eq!(sphere.components, 1);
+        assert_eq!(sphere.holes(), 0);
+
+        let torus = topology_stats(&create_torus_mesh(8, 6));
+        assert!(torus.is_closed);
+        assert_eq!(torus.euler_characteristic, 0);
+        assert_eq!(torus.genus, Some(1));
+
+        let half_edge_torus = topology_stats(&HalfEdgeMeshF::from_corner_table(&create_torus_mesh(8, 6)));
+        assert_eq!(half_edge_torus, torus);
+    }
+
+    #[test]
+    fn mesh_with_holes() {
+        let sphere = create_uv_sphere_mesh(6, 8);
+        let (cap, rest): (Vec<_>, Vec<_>) = sphere.faces().partition(|face| sphere.face_positions(face).center().z.abs() > 0.9);
+        assert!(!cap.is_empty());
+
+        let (mesh, _) = sphere.extract_faces(&rest);
+        let stats = topology_stats(&mesh);
+        assert!(!stats.is_closed);
+        assert!(stats.is_manifold);
+        assert_eq!(stats.holes(), 2);
+        assert_eq!(stats.genus, Some(0));
+        assert_eq!(stats.euler_characteristic, 0);
+
+        let loops = boundary_loops(&mesh);
+        assert_eq!(loops.len(), 2);
+
+        for boundary_loop in &loops {
+            assert_eq!(boundary_loop.len(), 8);
+
+            for (index, vertex) in boundary_loop.iter().enumerate() {
+                let next = boundary_loop[(index + 1) % boundary_loop.len()];
+                assert!(mesh.edges().any(|edge| {
+                    let (v1, v2) = mesh.edge_vertices(&edge);
+                    return (v1, v2) == (*vertex, next) || (v2, v1) == (*vertex, next);
+                }));
+            }
+        }
+    }
+
+    #[test]
+    fn square_and_two_components() {
+        let square = create_unit_square_mesh();
+        let loops = boundary_loops(&square);
+        assert_eq!(loops.len(), 1);
+        assert_eq!(loops[0].len(), 4);
+
+        let stats = topology_stats(&square);
+        assert_eq!(stats.euler_characteristic, 1);
+        assert_eq!(stats.genus, Some(0));
+
+        let mesh = CornerTableF::from_vertices_and_indices(&[
+            Point3::new(0.0, 0.0, 0.0),
+            Point3::new(1.0, 0.0, 0.0),
+            Point3::new(0.0, 1.0, 0.0),
+            Point3::new(5.0, 0.0, 0.0),
+            Point3::new(6.0, 0.0, 0.0),
+            Point3::new(5.0, 1.0, 0.0)
+        ], &[0, 1, 2, 3, 4, 5]);
+        let stats = topology_stats(&mesh);
+        assert_eq!(stats.components, 2);
+        assert_eq!(stats.holes(), 2);
+        assert_eq!(stats.genus, Some(0));
+    }
+}
@@ -2,6 +2,10 @@ use std::{collections::{BinaryHeap, HashMap}, cmp::Ordering};
 
 use nalgebra::{Vector4, Matrix4, Point3};
 use num_traits::{cast, Float};
+#[cfg(feature = "rayon")]
+use rayon::iter::{ParallelExtend, ParallelIterator};
+#[cfg(feature = "rayon")]
+use crate::mesh::traits::ParallelMesh;
 
 use crate::{
     mesh::traits::{
@@ -80,7 +84,27 @@ impl<TMesh: Mesh> Default for QuadricError<TMesh> {
     }
 }
 
-impl<TMesh: Mesh + TopologicalMesh> CollapseStrategy<TMesh> for QuadricError<TMesh> {
+impl<TMesh: Mesh + TopologicalMesh> QuadricError<TMesh> {
+    /// Vertex error quadric = sum of quadrics of one ring faces
+    fn vertex_quadric(mesh: &TMesh, vertex: &TMesh::VertexDescriptor) -> Matrix4<TMesh::ScalarType> {
+        let mut quadric = Matrix4::zeros();
+
+        mesh.faces_around_vertex(vertex, |face| {
+            let plane = mesh.face_positions(face).plane();
+            let n = plane.get_normal();
+            let d = plane.get_distance();
+
+            let p = Vector4::new(n.x, n.y, n.z, -d);
+            let p_t = p.transpose();
+
+            quadric += p * p_t;
+        });
+
+        return quadric;
+    }
+}
+
+impl<TMesh: TopologicalMesh> CollapseStrategy<TMesh> for QuadricError<TMesh> {
     fn set(&mut self, mesh: &TMesh) {
         // Preallocate memory
         if let (_, Some(max_size)) = mesh.vertices().size_hint() {
@@ -88,21 +112,7 @@ impl<TMesh: Mesh + TopologicalMesh> CollapseStrategy<TMesh> for QuadricError<TMe
         }
 
         for vertex in mesh.vertices() {
-            let mut quadric = Matrix4::zeros();
-
-            // Vertex error quadric = sum of quadrics of one ring faces
-            mesh.faces_around_vertex(&vertex, |face| {
-                let plane = mesh.face_positions(face).plane();
-                let n = plane.get_normal();
-                let d = plane.get_distance();
-
-                let p = Vector4::new(n.x, n.y, n.z, -d);
-                let p_t = p.transpose();
-
-                quadric += p * p_t;
-            });
-
-            self.vertex_quadric_map.insert(vertex, quadric);  
+            self.vertex_quadric_map.insert(vertex, Self::vertex_quadric(mesh, &vertex));
         }
     }
 
@@ -133,6 +143,43 @@ impl<TMesh: Mesh + TopologicalMesh> CollapseStrategy<TMesh> for QuadricError<TMe
     }
 }
 
+/// 
+/// Same as [QuadricError], but vertex quadrics are initialized in parallel.
+/// 
+#[cfg(feature = "rayon")]
+pub struct ParallelQuadricError<TMesh: Mesh>(QuadricError<TMesh>);
+
+#[cfg(feature = "rayon")]
+impl<TMesh: Mesh> Default for ParallelQuadricError<TMesh> {
+    fn default() -> Self {
+        return Self(QuadricError::default());
+    }
+}
+
+#[cfg(feature = "rayon")]
+impl<TMesh: TopologicalMesh + ParallelMesh> CollapseStrategy<TMesh> for ParallelQuadricError<TMesh> {
+    fn set(&mut self, mesh: &TMesh) {
+        self.0.vertex_quadric_map.par_extend(
+            mesh.par_vertices().map(|vertex| (vertex, QuadricError::vertex_quadric(mesh, &vertex)))
+        );
+    }
+
+    #[inline]
+    fn get_cost(&self, mesh: &TMesh, edge: &<TMesh as Mesh>::EdgeDescriptor) -> <TMesh as Mesh>::ScalarType {
+        return self.0.get_cost(mesh, edge);
+    }
+
+    #[inline]
+    fn get_placement(&self, mesh: &TMesh, edge: &<TMesh as Mesh>::EdgeDescriptor) -> Point3<<TMesh as Mesh>::ScalarType> {
+        return self.0.get_placement(mesh, edge);
+    }
+
+    #[inline]
+    fn collapse_edge(&mut self, mesh: &TMesh, edge: &<TMesh as Mesh>::EdgeDescriptor) {
+        self.0.collapse_edge(mesh, edge);
+    }
+}
+
 
 
 ///
@@ -239,6 +286,9 @@ where
 
         self.fill_queue(mesh);
         self.collapse_edges(mesh);
+
+        #[cfg(debug_assertions)]
+        mesh.assert_valid();
     }
 
     /// Collapse edges
@@ -474,3 +524,24 @@ where
         return Self::new(origin, radii_error);
     }
 }
+
+#[cfg(all(test, feature = "rayon"))]
+mod tests {
+    use crate::mesh::{corner_table::{prelude::CornerTableF, test_helpers::create_uv_sphere_mesh}, traits::Mesh};
+
+    use super::{CollapseStrategy, QuadricError, ParallelQuadricError};
+
+    #[test]
+    fn parallel_quadrics_should_match_sequential() {
+        let mesh = create_uv_sphere_mesh(20, 40);
+
+        let mut parallel = ParallelQuadricError::<CornerTableF>::default();
+        parallel.set(&mesh);
+
+        let mut sequential = QuadricError::<CornerTableF>::default();
+        sequential.set(&mesh);
+
+        assert_eq!(parallel.0.vertex_quadric_map.len(), mesh.vertices().count());
+        assert_eq!(parallel.0.vertex_quadric_map, sequential.vertex_quadric_map);
+    }
+}
@@ -1,4 +1,10 @@
 use super::edge_decimation::{IncrementalDecimator, QuadricError};
+#[cfg(feature = "rayon")]
+use super::edge_decimation::ParallelQuadricError;
 
 /// Mesh decimation through edge collapsing. For details see [IncrementalDecimator].
 pub type EdgeDecimator<TMesh, TEdgeDecimationCriteria> = IncrementalDecimator<TMesh, QuadricError<TMesh>, TEdgeDecimationCriteria>;
+
+/// Same as [EdgeDecimator], but collapse costs are initialized in parallel.
+#[cfg(feature = "rayon")]
+pub type ParallelEdgeDecimator<TMesh, TEdgeDecimationCriteria> = IncrementalDecimator<TMesh, ParallelQuadricError<TMesh>, TEdgeDecimationCriteria>;
@@ -1,6 +1,8 @@
 use nalgebra::Point3;
 use nalgebra_glm::{min2, max2};
 use num_traits::cast;
+#[cfg(feature = "serde")]
+use serde::{Serialize, Deserialize};
 
 use crate::{geometry::traits::{ClosestPoint3, HasScalarType, RealNumber, Number}};
 
@@ -8,6 +10,7 @@ use super::{line_segment3::LineSegment3, plane3::Plane3, triangle3::Triangle3, s
 
 /// 3D bounding box
 #[derive(Clone, Copy)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub struct Box3<TScalar: Number> {
     min: Point3<TScalar>,
     max: Point3<TScalar>
@@ -1,5 +1,7 @@
 use nalgebra::{Point3, Vector3};
 use num_traits::Float;
+#[cfg(feature = "serde")]
+use serde::{Serialize, Deserialize};
 
 use crate::geometry::traits::{
     RealNumber, 
@@ -12,6 +14,7 @@ use crate::geometry::traits::{
 use super::{box3::Box3, line3::Line3};
 
 /// n * x - d = 0
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub struct Plane3<TScalar: Number> {
     normal: Vector3<TScalar>,
     distance: TScalar
@@ -3,6 +3,8 @@ use std::mem::swap;
 use nalgebra::{Point3, Vector3};
 use nalgebra_glm::{min2, max2};
 use num_traits::{Float, cast};
+#[cfg(feature = "serde")]
+use serde::{Serialize, Deserialize};
 
 use crate::{
     geometry::{traits::{
@@ -50,6 +52,7 @@ impl<TScalar: RealNumber> BarycentricCoordinates<TScalar> {
 
 /// 3D triangle
 #[derive(Debug)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub struct Triangle3<TScalar: Number> {
     a: Point3<TScalar>,
     b: Point3<TScalar>,
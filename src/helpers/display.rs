@@ -1,14 +1,9 @@
-use std::{fmt::Display, cell::UnsafeCell};
+use crate::mesh::corner_table::descriptors::ElementIndex;
 
-pub fn display_option<T: Display>(o: &Option<T>) -> String {
-    match o {
-        Some(s) => format!("{}", s),
-        None => "None".to_string(),
+pub fn display_index<TIndex: ElementIndex>(index: &TIndex) -> String {
+    if *index == TIndex::INVALID {
+        return "None".to_string();
     }
-}
 
-pub fn display_unsafecell<T: Display>(cell: &UnsafeCell<T>) -> String {
-    unsafe {
-        format!("{}", *cell.get())
-    }
+    return index.to_string();
 }
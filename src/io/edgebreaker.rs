@@ -0,0 +1,1301 @@
+use std::{
+    io::{Read, BufReader, BufRead, Write, BufWriter},
+    fs::{OpenOptions, File}, path::Path
+};
+use nalgebra::Point3;
+use simba::scalar::SupersetOf;
+
+use crate::{
+    geometry::traits::RealNumber,
+    mesh::{
+        corner_table::{table::CornerTable, connectivity::corner::face_corners, descriptors::ElementIndex},
+        traits::Mesh
+    }
+};
+
+use super::{traits::{MeshReader, MeshWriter}, error::{MeshIoError, OffsetReader, build_mesh}};
+
+const EDGEBREAKER_MAGIC: &[u8] = b"EBRK";
+const EDGEBREAKER_VERSION: u8 = 1;
+const MAX_QUANTIZATION_BITS: u8 = 30;
+const NONE: usize = usize::MAX;
+
+///
+/// Edgebreaker operation. Describes how triangle is attached to the boundary of already processed region,
+/// tip is the vertex of triangle opposite to the gate edge it is entered through.
+///
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Symbol {
+    /// Tip vertex is new
+    C,
+    /// Left neighbour is processed
+    L,
+    /// Both neighbours are processed, boundary loop is closed
+    E,
+    /// Right neighbour is processed
+    R,
+    /// Tip vertex is on current boundary loop, loop is split in two
+    S,
+    /// Tip vertex is on another boundary loop (handle), loops are merged
+    M
+}
+
+/// Prediction rule of quantized vertex position
+#[derive(Debug, Clone, Copy)]
+enum Prediction {
+    /// Position of previous vertex
+    Delta,
+    /// Parallelogram rule `a + b - opposite` across gate edge `(a, b)`
+    Parallelogram { a: usize, b: usize, opposite: usize }
+}
+
+///
+/// Edgebreaker mesh codec writer. Connectivity is encoded as CLERS string (about 2 bits per triangle),
+/// vertex positions are quantized to uniform grid over bounding box and stored as residuals of parallelogram prediction.
+///
+/// Holes are closed by fans of triangles around dummy vertices before encoding and removed by decoder.
+/// Offsets of `S` operations and handles (`M` operations) are stored explicitly, so meshes of any genus are supported.
+/// Non-manifold vertices are split. Order of vertices and faces is not preserved.
+///
+/// ## Example
+/// ```ignore
+/// let writer = EdgebreakerWriter::new().with_quantization_bits(16);
+/// writer.write_edgebreaker_to_file(&mesh, Path::new("mesh.ebc"))?;
+///
+/// let mesh: CornerTableF = EdgebreakerReader::new().read_edgebreaker_from_file(Path::new("mesh.ebc"))?;
+/// ```
+///
+pub struct EdgebreakerWriter {
+    quantization_bits: u8
+}
+
+impl EdgebreakerWriter {
+    pub fn new() -> Self {
+        return Self {
+            quantization_bits: 14
+        };
+    }
+
+    /// Set number of bits per quantized coordinate (from 1 to 30). Default is `14`
+    #[inline]
+    pub fn with_quantization_bits(mut self, bits: u8) -> Self {
+        assert!((1..=MAX_QUANTIZATION_BITS).contains(&bits), "Quantization bits should be in range [1, {}]", MAX_QUANTIZATION_BITS);
+        self.quantization_bits = bits;
+        return self;
+    }
+
+    pub fn write_edgebreaker_to_file<TScalar: RealNumber, TIndex: ElementIndex>(&self, mesh: &CornerTable<TScalar, TIndex>, path: &Path) -> Result<(), MeshIoError> {
+        let file = OpenOptions::new()
+            .write(true)
+            .truncate(true)
+            .create(true)
+            .open(path)?;
+        let mut writer = BufWriter::new(file);
+
+        self.write_edgebreaker(mesh, &mut writer)?;
+        writer.flush()?;
+
+        return Ok(());
+    }
+
+    pub fn write_edgebreaker<TBuffer, TScalar, TIndex>(&self, mesh: &CornerTable<TScalar, TIndex>, writer: &mut BufWriter<TBuffer>) -> Result<(), MeshIoError>
+    where
+        TBuffer: Write,
+        TScalar: RealNumber,
+        TIndex: ElementIndex
+    {
+        let connectivity = ClosedConnectivity::from_corner_table(mesh);
+        let mut encoder = Encoder::new(&connectivity);
+
+        let mut components = 0;
+        for triangle in 0..connectivity.triangles_count() {
+            if !encoder.visited[triangle] {
+                encoder.encode_component(triangle * 3);
+                components += 1;
+            }
+        }
+
+        // Quantize positions
+        let mut positions = vec![None; connectivity.first_dummy];
+        for vertex in mesh.vertices() {
+            let position = mesh.vertex_position(&vertex);
+            let position = [position.x, position.y, position.z].map(|coordinate| coordinate.to_f64().unwrap_or_default());
+            positions[vertex.index()] = Some(position);
+        }
+
+        // Vertices without faces are stored after connected ones
+        let mut is_encoded = vec![false; connectivity.first_dummy];
+        for vertex in encoder.decoded_vertices.iter().filter(|vertex| **vertex < connectivity.first_dummy) {
+            is_encoded[*vertex] = true;
+        }
+
+        let isolated: Vec<_> = (0..connectivity.first_dummy).filter(|vertex| positions[*vertex].is_some() && !is_encoded[*vertex]).collect();
+        for vertex in &isolated {
+            encoder.decoded_vertices.push(*vertex);
+            encoder.predictions.push(Prediction::Delta);
+        }
+
+        let (min, step) = quantization_grid(positions.iter().flatten(), self.quantization_bits);
+        let quantized: Vec<_> = encoder.decoded_vertices.iter()
+            .map(|vertex| positions.get(*vertex).copied().flatten().map(|position| quantize(&position, &min, step)))
+            .collect();
+
+        // Header
+        writer.write_all(EDGEBREAKER_MAGIC)?;
+        writer.write_all(&[EDGEBREAKER_VERSION, self.quantization_bits])?;
+        for value in min.iter().chain([step].iter()) {
+            writer.write_all(&value.to_le_bytes())?;
+        }
+
+        // Connectivity
+        write_varint(writer, components)?;
+        write_varint(writer, encoder.symbols_count as u64)?;
+        writer.write_all(&encoder.symbols.bytes)?;
+
+        for offset in &encoder.offsets {
+            write_varint(writer, *offset as u64)?;
+        }
+
+        // Dummy vertices
+        let dummies: Vec<_> = (0..quantized.len()).filter(|index| quantized[*index].is_none()).collect();
+        write_varint(writer, dummies.len() as u64)?;
+
+        let mut previous_dummy = 0;
+        for dummy in dummies {
+            write_varint(writer, (dummy - previous_dummy) as u64)?;
+            previous_dummy = dummy;
+        }
+
+        write_varint(writer, isolated.len() as u64)?;
+
+        // Positions
+        let mut last = [0; 3];
+        for (index, position) in quantized.iter().enumerate() {
+            if let Some(position) = position {
+                let prediction = predict(&quantized[..index], encoder.predictions[index], last);
+
+                for i in 0..3 {
+                    write_varint(writer, zigzag(position[i] - prediction[i]))?;
+                }
+
+                last = *position;
+            }
+        }
+
+        return Ok(());
+    }
+}
+
+impl Default for EdgebreakerWriter {
+    #[inline]
+    fn default() -> Self {
+        return Self::new();
+    }
+}
+
+impl<TScalar: RealNumber, TIndex: ElementIndex> MeshWriter<CornerTable<TScalar, TIndex>> for EdgebreakerWriter {
+    #[inline]
+    fn supports_extension(&self, extension: &str) -> bool {
+        return extension == "ebc";
+    }
+
+    fn write(&self, mesh: &CornerTable<TScalar, TIndex>, writer: &mut dyn Write) -> Result<(), MeshIoError> {
+        let mut writer = BufWriter::new(writer);
+        self.write_edgebreaker(mesh, &mut writer)?;
+        writer.flush()?;
+
+        return Ok(());
+    }
+}
+
+///
+/// Edgebreaker mesh codec reader. See [EdgebreakerWriter] for details.
+///
+pub struct EdgebreakerReader {
+    symbols: Vec<Symbol>,
+    indices: Vec<usize>,
+    predictions: Vec<Prediction>
+}
+
+impl EdgebreakerReader {
+    pub fn new() -> Self {
+        return Self {
+            symbols: Vec::new(),
+            indices: Vec::new(),
+            predictions: Vec::new()
+        };
+    }
+
+    /// Reads mesh from file
+    pub fn read_edgebreaker_from_file<TMesh>(&mut self, filepath: &Path) -> Result<TMesh, MeshIoError>
+    where
+        TMesh: Mesh,
+        TMesh::ScalarType: SupersetOf<f64>
+    {
+        let file = OpenOptions::new().read(true).open(filepath)?;
+        let mut reader = BufReader::new(file);
+
+        return self.read_edgebreaker::<File, TMesh>(&mut reader);
+    }
+
+    /// Reads mesh from buffer
+    pub fn read_edgebreaker<TBuffer, TMesh>(&mut self, reader: &mut BufReader<TBuffer>) -> Result<TMesh, MeshIoError>
+    where
+        TBuffer: Read,
+        TMesh: Mesh,
+        TMesh::ScalarType: SupersetOf<f64>
+    {
+        self.symbols.clear();
+        self.indices.clear();
+        self.predictions.clear();
+
+        let reader = &mut OffsetReader::new(reader, 0);
+
+        // Header
+        let mut magic = [0; 4];
+        reader.read_exact(&mut magic)?;
+        if magic != EDGEBREAKER_MAGIC {
+            return Err(edgebreaker_error("missing `EBRK` magic number"));
+        }
+
+        let mut version_and_bits = [0; 2];
+        reader.read_exact(&mut version_and_bits)?;
+        let [version, bits] = version_and_bits;
+
+        if version != EDGEBREAKER_VERSION {
+            return Err(MeshIoError::UnsupportedEncoding(format!("Edgebreaker version {}", version)));
+        }
+
+        if !(1..=MAX_QUANTIZATION_BITS).contains(&bits) {
+            return Err(edgebreaker_error("invalid number of quantization bits"));
+        }
+
+        let mut grid = [0.0; 4];
+        for value in grid.iter_mut() {
+            let mut bytes = [0; 8];
+            reader.read_exact(&mut bytes)?;
+            *value = f64::from_le_bytes(bytes);
+        }
+
+        // Connectivity
+        let components = read_varint(reader)?;
+        let symbols_count = read_varint(reader)?;
+
+        let mut bits_reader = BitReader::new();
+        for _ in 0..symbols_count {
+            self.symbols.push(bits_reader.read_symbol(reader)?);
+        }
+
+        self.decode_connectivity(reader, components)?;
+
+        // Dummy vertices
+        let vertices_count = self.predictions.len();
+        let mut is_dummy = vec![false; vertices_count];
+        let mut dummy = 0;
+
+        for _ in 0..read_varint(reader)? {
+            dummy += read_varint(reader)? as usize;
+            *is_dummy.get_mut(dummy).ok_or_else(|| edgebreaker_error("dummy vertex index is out of range"))? = true;
+        }
+
+        // Count is not trusted, isolated vertices are added only as their positions are read
+        let isolated = read_varint(reader)?;
+
+        // Positions
+        let mut quantized = Vec::with_capacity(self.predictions.len());
+        let mut last = [0; 3];
+
+        for (index, is_dummy) in is_dummy.iter().enumerate() {
+            if *is_dummy {
+                quantized.push(None);
+                continue;
+            }
+
+            let prediction = predict(&quantized, self.predictions[index], last);
+            let mut position = [0; 3];
+
+            for i in 0..3 {
+                position[i] = prediction[i].wrapping_add(unzigzag(read_varint(reader)?));
+            }
+
+            quantized.push(Some(position));
+            last = position;
+        }
+
+        // Isolated vertices are stored after all other ones as deltas from previous position
+        for _ in 0..isolated {
+            let mut position = [0; 3];
+
+            for i in 0..3 {
+                position[i] = last[i].wrapping_add(unzigzag(read_varint(reader)?));
+            }
+
+            quantized.push(Some(position));
+            is_dummy.push(false);
+            last = position;
+        }
+
+        // Remove dummy vertices and faces around them
+        let mut vertex_map = vec![NONE; quantized.len()];
+        let mut vertices = Vec::with_capacity(quantized.len());
+
+        for (index, position) in quantized.iter().enumerate() {
+            if let Some(position) = position {
+                vertex_map[index] = vertices.len();
+
+                let point = Point3::new(
+                    grid[0] + position[0] as f64 * grid[3],
+                    grid[1] + position[1] as f64 * grid[3],
+                    grid[2] + position[2] as f64 * grid[3]
+                );
+                vertices.push(point.cast::<TMesh::ScalarType>());
+            }
+        }
+
+        let indices: Vec<_> = self.indices
+            .chunks_exact(3)
+            .filter(|face| face.iter().all(|vertex| !is_dummy[*vertex]))
+            .flatten()
+            .map(|vertex| vertex_map[*vertex])
+            .collect();
+
+        return build_mesh(&vertices, &indices, false);
+    }
+
+    /// Rebuilds faces from CLERS string, operation offsets are read from stream
+    fn decode_connectivity<TBuffer: BufRead>(&mut self, reader: &mut OffsetReader<TBuffer>, components: u64) -> Result<(), MeshIoError> {
+        let mut loops = BoundaryLoops::new();
+        let mut symbols = std::mem::take(&mut self.symbols).into_iter();
+
+        for _ in 0..components {
+            let first = self.add_vertex(Prediction::Delta);
+            let second = self.add_vertex(Prediction::Delta);
+            let third = self.add_vertex(Prediction::Delta);
+            self.indices.extend([first, second, third]);
+
+            let mut stack = Vec::new();
+            let [_, _, mut gate] = loops.start(first, second, third);
+
+            loop {
+                let a_node = gate;
+                let b_node = loops.next[a_node];
+                let (a, b) = (loops.vertex[a_node], loops.vertex[b_node]);
+                let symbol = symbols.next().ok_or_else(|| edgebreaker_error("CLERS string is too short"))?;
+
+                let tip = match symbol {
+                    Symbol::C => {
+                        let tip = self.add_vertex(Prediction::Parallelogram { a, b, opposite: loops.opposite[a_node] });
+                        gate = loops.attach_new_vertex(a_node, tip);
+                        tip
+                    },
+                    Symbol::L => {
+                        let tip = loops.vertex[loops.prev[a_node]];
+                        gate = loops.attach_left(a_node);
+                        tip
+                    },
+                    Symbol::R => {
+                        let tip = loops.vertex[loops.next[b_node]];
+                        loops.attach_right(a_node);
+                        tip
+                    },
+                    Symbol::E => {
+                        let tip_node = loops.next[b_node];
+                        if loops.next[tip_node] != a_node {
+                            return Err(edgebreaker_error("E operation on boundary loop longer than 3 vertices"));
+                        }
+
+                        self.indices.extend([loops.vertex[tip_node], a, b]);
+
+                        match stack.pop() {
+                            Some(pending) => {
+                                gate = pending;
+                                continue;
+                            },
+                            None => break,
+                        }
+                    },
+                    Symbol::S => {
+                        let offset = read_varint(reader)? as usize;
+                        let tip_node = walk_loop(&loops, b_node, offset, |node| loops.next[node])?;
+
+                        if offset == 0 || tip_node == a_node {
+                            return Err(edgebreaker_error("invalid S operation offset"));
+                        }
+
+                        loops.split(a_node, tip_node);
+                        stack.push(a_node);
+                        gate = tip_node;
+                        loops.vertex[tip_node]
+                    },
+                    Symbol::M => {
+                        let depth = read_varint(reader)? as usize;
+                        let offset = read_varint(reader)? as usize;
+
+                        if depth >= stack.len() {
+                            return Err(edgebreaker_error("invalid M operation loop"));
+                        }
+
+                        let pending = stack.remove(stack.len() - 1 - depth);
+                        let tip_node = walk_loop(&loops, pending, offset, |node| loops.prev[node])?;
+
+                        gate = loops.merge(a_node, tip_node);
+                        loops.vertex[tip_node]
+                    },
+                };
+
+                self.indices.extend([tip, a, b]);
+            }
+        }
+
+        if symbols.next().is_some() {
+            return Err(edgebreaker_error("CLERS string is too long"));
+        }
+
+        return Ok(());
+    }
+
+    #[inline]
+    fn add_vertex(&mut self, prediction: Prediction) -> usize {
+        self.predictions.push(prediction);
+        return self.predictions.len() - 1;
+    }
+}
+
+impl Default for EdgebreakerReader {
+    #[inline]
+    fn default() -> Self {
+        return Self::new();
+    }
+}
+
+impl<TMesh> MeshReader<TMesh> for EdgebreakerReader
+where
+    TMesh: Mesh,
+    TMesh::ScalarType: SupersetOf<f64>
+{
+    #[inline]
+    fn supports_extension(&self, extension: &str) -> bool {
+        return extension == "ebc";
+    }
+
+    #[inline]
+    fn can_read(&self, header: &[u8]) -> bool {
+        return header.starts_with(EDGEBREAKER_MAGIC);
+    }
+
+    #[inline]
+    fn read(&mut self, reader: &mut dyn Read) -> Result<TMesh, MeshIoError> {
+        return self.read_edgebreaker(&mut BufReader::new(reader));
+    }
+}
+
+///
+/// Connectivity of corner table with holes closed by fans of triangles around dummy vertices.
+/// Every corner has opposite one.
+///
+struct ClosedConnectivity {
+    vertices: Vec<usize>,
+    opposite: Vec<usize>,
+    /// Vertices starting from this index are dummy
+    first_dummy: usize
+}
+
+impl ClosedConnectivity {
+    fn from_corner_table<TScalar: RealNumber, TIndex: ElementIndex>(mesh: &CornerTable<TScalar, TIndex>) -> Self {
+        let faces: Vec<_> = mesh.faces().collect();
+
+        // Deleted faces are skipped, so corners are renumbered
+        let mut corners_map = vec![NONE; faces.last().map_or(0, |face| face.index() * 3 + 3)];
+        for (index, face) in faces.iter().enumerate() {
+            for (i, corner) in face_corners(*face).into_iter().enumerate() {
+                corners_map[corner.index()] = index * 3 + i;
+            }
+        }
+
+        let mut vertices = Vec::with_capacity(faces.len() * 3);
+        let mut opposite = vec![NONE; faces.len() * 3];
+
+        for face in &faces {
+            for corner_index in face_corners(*face) {
+                let corner = mesh.get_corner(corner_index).unwrap();
+                vertices.push(corner.get_vertex_index().index());
+
+                if let Some(opposite_corner) = corner.get_opposite_corner_index().and_then(|c| corners_map.get(c.index())) {
+                    opposite[corners_map[corner_index.index()]] = *opposite_corner;
+                }
+            }
+        }
+
+        let mut connectivity = Self {
+            vertices,
+            opposite,
+            first_dummy: mesh.vertices().max().map_or(0, |vertex| vertex.index() + 1)
+        };
+        connectivity.close_holes();
+
+        return connectivity;
+    }
+
+    #[inline]
+    fn triangles_count(&self) -> usize {
+        return self.vertices.len() / 3;
+    }
+
+    /// Adds dummy vertex for each hole and connects it with all edges of hole
+    fn close_holes(&mut self) {
+        let mut dummy = self.first_dummy;
+
+        for corner in 0..self.vertices.len() {
+            if self.opposite[corner] != NONE {
+                continue;
+            }
+
+            let mut hole = vec![corner];
+            loop {
+                let next_corner = self.next_boundary_corner(*hole.last().unwrap());
+                if next_corner == corner {
+                    break;
+                }
+
+                hole.push(next_corner);
+            }
+
+            // Boundary edge `x -> y` gets triangle `(dummy, y, x)`
+            let first_fan_corner = self.vertices.len();
+            for boundary_corner in &hole {
+                let fan_corner = self.vertices.len();
+                self.vertices.extend([dummy, self.vertices[previous(*boundary_corner)], self.vertices[next(*boundary_corner)]]);
+                self.opposite.extend([*boundary_corner, NONE, NONE]);
+                self.opposite[*boundary_corner] = fan_corner;
+            }
+
+            // Edge `dummy -> y` of triangle is shared with next triangle of fan
+            for i in 0..hole.len() {
+                let current = first_fan_corner + i * 3;
+                let next_triangle = first_fan_corner + (i + 1) % hole.len() * 3;
+                self.opposite[current + 2] = next_triangle + 1;
+                self.opposite[next_triangle + 1] = current + 2;
+            }
+
+            dummy += 1;
+        }
+    }
+
+    /// Returns corner opposite to boundary edge that starts where edge opposite to `corner` ends
+    fn next_boundary_corner(&self, corner: usize) -> usize {
+        let mut current = corner;
+
+        loop {
+            let candidate = next(current);
+            match self.opposite[candidate] {
+                NONE => return candidate,
+                opposite => current = opposite,
+            }
+        }
+    }
+}
+
+///
+/// Boundaries between processed and not processed parts of mesh, stored as cyclic linked lists of nodes.
+/// Same vertex can be referenced by several nodes. Outgoing edge of node is oriented as not processed triangle adjacent to it.
+///
+struct BoundaryLoops {
+    vertex: Vec<usize>,
+    next: Vec<usize>,
+    prev: Vec<usize>,
+    /// Vertex of processed triangle opposite to outgoing edge
+    opposite: Vec<usize>
+}
+
+impl BoundaryLoops {
+    fn new() -> Self {
+        return Self {
+            vertex: Vec::new(),
+            next: Vec::new(),
+            prev: Vec::new(),
+            opposite: Vec::new()
+        };
+    }
+
+    fn add_node(&mut self, vertex: usize, opposite: usize) -> usize {
+        self.vertex.push(vertex);
+        self.opposite.push(opposite);
+        self.next.push(NONE);
+        self.prev.push(NONE);
+
+        return self.vertex.len() - 1;
+    }
+
+    #[inline]
+    fn link(&mut self, from: usize, to: usize) {
+        self.next[from] = to;
+        self.prev[to] = from;
+    }
+
+    /// Creates loop around triangle `(v0, v1, v2)`. Returns nodes of vertices, gate is outgoing edge of `v2`.
+    fn start(&mut self, v0: usize, v1: usize, v2: usize) -> [usize; 3] {
+        let n0 = self.add_node(v0, v1);
+        let n1 = self.add_node(v1, v2);
+        let n2 = self.add_node(v2, v0);
+
+        self.link(n0, n2);
+        self.link(n2, n1);
+        self.link(n1, n0);
+
+        return [n0, n1, n2];
+    }
+
+    /// C operation. Inserts new tip between `a` and `b`, returns its node.
+    fn attach_new_vertex(&mut self, a_node: usize, tip: usize) -> usize {
+        let b_node = self.next[a_node];
+        let tip_node = self.add_node(tip, self.vertex[a_node]);
+
+        self.opposite[a_node] = self.vertex[b_node];
+        self.link(a_node, tip_node);
+        self.link(tip_node, b_node);
+
+        return tip_node;
+    }
+
+    /// L operation. Tip precedes `a`, `a` is removed from loop. Returns node of tip.
+    fn attach_left(&mut self, a_node: usize) -> usize {
+        let tip_node = self.prev[a_node];
+        let b_node = self.next[a_node];
+
+        self.opposite[tip_node] = self.vertex[a_node];
+        self.link(tip_node, b_node);
+
+        return tip_node;
+    }
+
+    /// R operation. Tip follows `b`, `b` is removed from loop.
+    fn attach_right(&mut self, a_node: usize) {
+        let b_node = self.next[a_node];
+        let tip_node = self.next[b_node];
+
+        self.opposite[a_node] = self.vertex[b_node];
+        self.link(a_node, tip_node);
+    }
+
+    ///
+    /// S operation. Splits loop into `tip -> b -> ... -> tip` (continues with `tip_node`)
+    /// and `a -> tip -> ... -> a` (continues with `a_node`). Returns new node of tip in second loop.
+    ///
+    fn split(&mut self, a_node: usize, tip_node: usize) -> usize {
+        let b_node = self.next[a_node];
+        let after_tip = self.next[tip_node];
+        let tip_copy = self.add_node(self.vertex[tip_node], self.opposite[tip_node]);
+
+        self.opposite[tip_node] = self.vertex[a_node];
+        self.link(tip_node, b_node);
+
+        self.opposite[a_node] = self.vertex[b_node];
+        self.link(a_node, tip_copy);
+        self.link(tip_copy, after_tip);
+
+        return tip_copy;
+    }
+
+    ///
+    /// M operation. Merges current loop with loop containing `tip_node` into `a -> tip -> ... -> tip -> b`.
+    /// Returns new node of tip that precedes `b`.
+    ///
+    fn merge(&mut self, a_node: usize, tip_node: usize) -> usize {
+        let b_node = self.next[a_node];
+        let before_tip = self.prev[tip_node];
+        let tip_copy = self.add_node(self.vertex[tip_node], self.vertex[a_node]);
+
+        self.link(before_tip, tip_copy);
+        self.link(tip_copy, b_node);
+
+        self.opposite[a_node] = self.vertex[b_node];
+        self.link(a_node, tip_node);
+
+        return tip_copy;
+    }
+}
+
+/// Moves `steps` times along loop, fails when loop is shorter than number of steps
+fn walk_loop<TStep: Fn(usize) -> usize>(loops: &BoundaryLoops, start: usize, steps: usize, step: TStep) -> Result<usize, MeshIoError> {
+    if steps >= loops.vertex.len() {
+        return Err(edgebreaker_error("operation offset is out of range"));
+    }
+
+    let mut node = start;
+    for _ in 0..steps {
+        node = step(node);
+    }
+
+    return Ok(node);
+}
+
+/// Traverses mesh and records CLERS string
+struct Encoder<'a> {
+    connectivity: &'a ClosedConnectivity,
+    visited: Vec<bool>,
+    loops: BoundaryLoops,
+    /// Corner of not processed triangle opposite to outgoing edge of node
+    node_corner: Vec<usize>,
+    corner_node: Vec<usize>,
+    is_pending: Vec<bool>,
+    /// Gates of loops waiting to be processed
+    stack: Vec<(usize, usize)>,
+
+    symbols: BitWriter,
+    symbols_count: usize,
+    offsets: Vec<usize>,
+    decoded_vertices: Vec<usize>,
+    predictions: Vec<Prediction>
+}
+
+impl<'a> Encoder<'a> {
+    fn new(connectivity: &'a ClosedConnectivity) -> Self {
+        return Self {
+            connectivity,
+            visited: vec![false; connectivity.triangles_count()],
+            loops: BoundaryLoops::new(),
+            node_corner: Vec::new(),
+            corner_node: vec![NONE; connectivity.vertices.len()],
+            is_pending: Vec::new(),
+            stack: Vec::new(),
+            symbols: BitWriter::new(),
+            symbols_count: 0,
+            offsets: Vec::new(),
+            decoded_vertices: Vec::new(),
+            predictions: Vec::new()
+        };
+    }
+
+    fn encode_component(&mut self, corner: usize) {
+        let vertices = &self.connectivity.vertices;
+        let opposite = &self.connectivity.opposite;
+        let (c0, c1, c2) = (corner, next(corner), previous(corner));
+
+        self.visited[corner / 3] = true;
+
+        let v0 = self.add_vertex(vertices[c0], Prediction::Delta);
+        let v1 = self.add_vertex(vertices[c1], Prediction::Delta);
+        let v2 = self.add_vertex(vertices[c2], Prediction::Delta);
+
+        let [n0, n1, n2] = self.loops.start(v0, v1, v2);
+        self.set_node_corner(n0, opposite[c1]);
+        self.set_node_corner(n1, opposite[c2]);
+        self.set_node_corner(n2, opposite[c0]);
+
+        let mut gate = Some((n2, opposite[c0]));
+        while let Some((node, corner)) = gate {
+            gate = self.encode_triangle(node, corner);
+        }
+    }
+
+    /// Encodes triangle of `corner` entered through outgoing edge of `a_node`. Returns next gate.
+    fn encode_triangle(&mut self, a_node: usize, corner: usize) -> Option<(usize, usize)> {
+        let right = self.connectivity.opposite[next(corner)];
+        let left = self.connectivity.opposite[previous(corner)];
+        let right_visited = self.visited[right / 3];
+        let left_visited = self.visited[left / 3];
+        let tip_node = self.find_tip_node(corner);
+
+        self.visited[corner / 3] = true;
+
+        let tip_node = match tip_node {
+            Some(tip_node) => tip_node,
+            None => {
+                self.write_symbol(Symbol::C);
+
+                let a = self.loops.vertex[a_node];
+                let b = self.loops.vertex[self.loops.next[a_node]];
+                let prediction = Prediction::Parallelogram { a, b, opposite: self.loops.opposite[a_node] };
+                let tip = self.add_vertex(self.connectivity.vertices[corner], prediction);
+                let tip_node = self.loops.attach_new_vertex(a_node, tip);
+
+                self.set_node_corner(a_node, left);
+                self.set_node_corner(tip_node, right);
+
+                return Some((tip_node, right));
+            }
+        };
+
+        match (left_visited, right_visited) {
+            (true, true) => {
+                self.write_symbol(Symbol::E);
+                debug_assert_eq!(self.loops.next[tip_node], a_node);
+
+                let pending = self.stack.pop();
+                if let Some((node, _)) = pending {
+                    self.is_pending[node] = false;
+                }
+
+                return pending;
+            },
+            (true, false) => {
+                self.write_symbol(Symbol::L);
+                debug_assert_eq!(self.loops.prev[a_node], tip_node);
+
+                let tip_node = self.loops.attach_left(a_node);
+                self.set_node_corner(tip_node, right);
+
+                return Some((tip_node, right));
+            },
+            (false, true) => {
+                self.write_symbol(Symbol::R);
+                debug_assert_eq!(self.loops.next[self.loops.next[a_node]], tip_node);
+
+                self.loops.attach_right(a_node);
+                self.set_node_corner(a_node, left);
+
+                return Some((a_node, left));
+            },
+            (false, false) => {
+                match self.offset_in_loop(a_node, tip_node) {
+                    Some(offset) => {
+                        self.write_symbol(Symbol::S);
+                        self.offsets.push(offset);
+
+                        let tip_corner = self.node_corner[tip_node];
+                        let tip_copy = self.loops.split(a_node, tip_node);
+
+                        self.set_node_corner(tip_copy, tip_corner);
+                        self.set_node_corner(tip_node, right);
+                        self.set_node_corner(a_node, left);
+
+                        self.is_pending[a_node] = true;
+                        self.stack.push((a_node, left));
+
+                        return Some((tip_node, right));
+                    },
+                    None => {
+                        // Tip is on another loop, find its gate
+                        let mut offset = 0;
+                        let mut node = tip_node;
+                        while !self.is_pending[node] {
+                            node = self.loops.next[node];
+                            offset += 1;
+                        }
+
+                        let index = self.stack.iter().position(|(pending, _)| *pending == node).unwrap();
+                        self.write_symbol(Symbol::M);
+                        self.offsets.push(self.stack.len() - 1 - index);
+                        self.offsets.push(offset);
+
+                        self.stack.remove(index);
+                        self.is_pending[node] = false;
+
+                        let tip_copy = self.loops.merge(a_node, tip_node);
+                        self.set_node_corner(tip_copy, right);
+                        self.set_node_corner(a_node, left);
+
+                        return Some((tip_copy, right));
+                    },
+                }
+            },
+        }
+    }
+
+    ///
+    /// Returns boundary node of tip vertex of triangle by swinging around tip through not processed triangles.
+    /// Returns `None` when all triangles around tip are not processed, i.e. tip is new vertex.
+    ///
+    fn find_tip_node(&self, corner: usize) -> Option<usize> {
+        let mut current = corner;
+
+        loop {
+            let edge_corner = previous(current);
+            let neighbour = self.connectivity.opposite[edge_corner];
+
+            if self.visited[neighbour / 3] {
+                return Some(self.corner_node[edge_corner]);
+            }
+
+            current = previous(neighbour);
+
+            if current == corner {
+                return None;
+            }
+        }
+    }
+
+    /// Returns number of steps from `b` to `tip` along current loop, `None` when tip is on another loop
+    fn offset_in_loop(&self, a_node: usize, tip_node: usize) -> Option<usize> {
+        let mut node = self.loops.next[a_node];
+        let mut offset = 0;
+
+        while node != tip_node {
+            if node == a_node {
+                return None;
+            }
+
+            node = self.loops.next[node];
+            offset += 1;
+        }
+
+        return Some(offset);
+    }
+
+    fn set_node_corner(&mut self, node: usize, corner: usize) {
+        if self.node_corner.len() <= node {
+            self.node_corner.resize(node + 1, NONE);
+            self.is_pending.resize(node + 1, false);
+        }
+
+        self.node_corner[node] = corner;
+        self.corner_node[corner] = node;
+    }
+
+    fn add_vertex(&mut self, vertex: usize, prediction: Prediction) -> usize {
+        self.decoded_vertices.push(vertex);
+        self.predictions.push(prediction);
+        return self.decoded_vertices.len() - 1;
+    }
+
+    fn write_symbol(&mut self, symbol: Symbol) {
+        self.symbols_count += 1;
+
+        let (code, length) = match symbol {
+            Symbol::C => (0b0, 1),
+            Symbol::S => (0b100, 3),
+            Symbol::R => (0b101, 3),
+            Symbol::L => (0b110, 3),
+            Symbol::E => (0b1110, 4),
+            Symbol::M => (0b1111, 4),
+        };
+
+        for i in (0..length).rev() {
+            self.symbols.push(code >> i & 1 == 1);
+        }
+    }
+}
+
+struct BitWriter {
+    bytes: Vec<u8>,
+    length: usize
+}
+
+impl BitWriter {
+    fn new() -> Self {
+        return Self {
+            bytes: Vec::new(),
+            length: 0
+        };
+    }
+
+    #[allow(clippy::manual_is_multiple_of)] // is_multiple_of requires Rust 1.87
+    fn push(&mut self, bit: bool) {
+        if self.length % 8 == 0 {
+            self.bytes.push(0);
+        }
+
+        if bit {
+            *self.bytes.last_mut().unwrap() |= 1 << (self.length % 8);
+        }
+
+        self.length += 1;
+    }
+}
+
+struct BitReader {
+    byte: u8,
+    position: usize
+}
+
+impl BitReader {
+    fn new() -> Self {
+        return Self {
+            byte: 0,
+            position: 8
+        };
+    }
+
+    fn read_bit<TBuffer: BufRead>(&mut self, reader: &mut OffsetReader<TBuffer>) -> Result<bool, MeshIoError> {
+        if self.position == 8 {
+            let mut byte = [0];
+            reader.read_exact(&mut byte)?;
+            self.byte = byte[0];
+            self.position = 0;
+        }
+
+        let bit = self.byte >> self.position & 1 == 1;
+        self.position += 1;
+
+        return Ok(bit);
+    }
+
+    fn read_symbol<TBuffer: BufRead>(&mut self, reader: &mut OffsetReader<TBuffer>) -> Result<Symbol, MeshIoError> {
+        if !self.read_bit(reader)? {
+            return Ok(Symbol::C);
+        }
+
+        let symbol = match (self.read_bit(reader)?, self.read_bit(reader)?) {
+            (false, false) => Symbol::S,
+            (false, true) => Symbol::R,
+            (true, false) => Symbol::L,
+            (true, true) => if self.read_bit(reader)? { Symbol::M } else { Symbol::E },
+        };
+
+        return Ok(symbol);
+    }
+}
+
+/// Returns minimal corner of bounding box and size of grid cell
+fn quantization_grid<'a, TPoints: Iterator<Item = &'a [f64; 3]>>(points: TPoints, bits: u8) -> ([f64; 3], f64) {
+    let mut min = [f64::MAX; 3];
+    let mut max = [f64::MIN; 3];
+
+    for point in points {
+        for i in 0..3 {
+            min[i] = min[i].min(point[i]);
+            max[i] = max[i].max(point[i]);
+        }
+    }
+
+    if min[0] > max[0] {
+        return ([0.0; 3], 0.0);
+    }
+
+    let extent = (0..3).map(|i| max[i] - min[i]).fold(0.0, f64::max);
+    let step = extent / ((1u64 << bits) - 1) as f64;
+
+    return (min, step);
+}
+
+#[inline]
+fn quantize(point: &[f64; 3], min: &[f64; 3], step: f64) -> [i64; 3] {
+    if step == 0.0 {
+        return [0; 3];
+    }
+
+    return [0, 1, 2].map(|i| ((point[i] - min[i]) / step).round() as i64);
+}
+
+/// Predicts quantized position of next vertex from already known ones
+fn predict(known: &[Option<[i64; 3]>], prediction: Prediction, last: [i64; 3]) -> [i64; 3] {
+    let Prediction::Parallelogram { a, b, opposite } = prediction else {
+        return last;
+    };
+
+    let get = |index: usize| known.get(index).copied().flatten();
+
+    match (get(a), get(b), get(opposite)) {
+        (Some(a), Some(b), Some(opposite)) => return [0, 1, 2].map(|i| a[i].wrapping_add(b[i]).wrapping_sub(opposite[i])),
+        (Some(a), Some(b), None) => return [0, 1, 2].map(|i| a[i].wrapping_add(b[i]).div_euclid(2)),
+        (Some(known), None, _) | (None, Some(known), _) => return known,
+        (None, None, _) => return last,
+    }
+}
+
+/// Next corner of face in raw corner indexing of [ClosedConnectivity]
+#[inline]
+fn next(corner: usize) -> usize {
+    return if corner % 3 == 2 { corner - 2 } else { corner + 1 };
+}
+
+/// Previous corner of face in raw corner indexing of [ClosedConnectivity]
+#[inline]
+#[allow(clippy::manual_is_multiple_of)] // is_multiple_of requires Rust 1.87
+fn previous(corner: usize) -> usize {
+    return if corner % 3 == 0 { corner + 2 } else { corner - 1 };
+}
+
+#[inline]
+fn zigzag(value: i64) -> u64 {
+    return ((value << 1) ^ (value >> 63)) as u64;
+}
+
+#[inline]
+fn unzigzag(value: u64) -> i64 {
+    return (value >> 1) as i64 ^ -((value & 1) as i64);
+}
+
+fn write_varint<TBuffer: Write>(writer: &mut BufWriter<TBuffer>, mut value: u64) -> Result<(), MeshIoError> {
+    loop {
+        let byte = (value & 0x7F) as u8;
+        value >>= 7;
+
+        if value == 0 {
+            writer.write_all(&[byte])?;
+            return Ok(());
+        }
+
+        writer.write_all(&[byte | 0x80])?;
+    }
+}
+
+fn read_varint<TBuffer: BufRead>(reader: &mut OffsetReader<TBuffer>) -> Result<u64, MeshIoError> {
+    let mut value = 0;
+    let mut byte = [0];
+
+    for shift in (0..64).step_by(7) {
+        reader.read_exact(&mut byte)?;
+        value |= ((byte[0] & 0x7F) as u64) << shift;
+
+        if byte[0] & 0x80 == 0 {
+            return Ok(value);
+        }
+    }
+
+    return Err(edgebreaker_error("invalid variable length integer"));
+}
+
+#[inline]
+fn edgebreaker_error(message: &str) -> MeshIoError {
+    return MeshIoError::InvalidData(format!("Edgebreaker: {}", message));
+}
+
+#[cfg(test)]
+mod tests {
+    use std::io::{BufReader, BufWriter};
+    use nalgebra::Point3;
+    use test_case::test_case;
+
+    use crate::{
+        mesh::{
+            corner_table::{prelude::{CornerTableF, CompactCornerTableF}, test_helpers::{create_unit_cross_square_mesh, create_unit_square_mesh, create_uv_sphere_mesh, create_torus_mesh}},
+            traits::Mesh
+        },
+        io::MeshIoError
+    };
+
+    use super::{EdgebreakerReader, EdgebreakerWriter};
+
+    fn round_trip(mesh: &CornerTableF, writer: EdgebreakerWriter) -> (Vec<u8>, CornerTableF) {
+        let mut buffer = BufWriter::new(Vec::new());
+        writer.write_edgebreaker(mesh, &mut buffer).expect("Write Edgebreaker");
+        let data = buffer.into_inner().unwrap();
+
+        let decoded = EdgebreakerReader::new().read_edgebreaker(&mut BufReader::new(data.as_slice())).expect("Read Edgebreaker");
+
+        return (data, decoded);
+    }
+
+    /// Returns sorted triangles as sorted positions
+    fn triangles(mesh: &CornerTableF, precision: f32) -> Vec<[[i64; 3]; 3]> {
+        let mut triangles: Vec<_> = mesh.faces()
+            .map(|face| {
+                let triangle = mesh.face_positions(&face);
+                let mut vertices = [triangle.p1(), triangle.p2(), triangle.p3()]
+                    .map(|p| [p.x, p.y, p.z].map(|coordinate| (coordinate / precision).round() as i64));
+
+                // Keep orientation, start from smallest vertex
+                let min = (0..3).min_by_key(|i| vertices[*i]).unwrap();
+                vertices.rotate_left(min);
+                vertices
+            })
+            .collect();
+
+        triangles.sort();
+        return triangles;
+    }
+
+    #[test_case(create_unit_square_mesh(); "square")]
+    #[test_case(create_unit_cross_square_mesh(); "cross square")]
+    #[test_case(create_uv_sphere_mesh(8, 12); "sphere")]
+    #[test_case(create_torus_mesh(9, 7); "torus")]
+    fn lossless_round_trip(mesh: CornerTableF) {
+        let (_, decoded) = round_trip(&mesh, EdgebreakerWriter::new().with_quantization_bits(30));
+
+        assert_eq!(decoded.faces().count(), mesh.faces().count());
+        assert_eq!(decoded.vertices().count(), mesh.vertices().count());
+        assert_eq!(triangles(&decoded, 1e-4), triangles(&mesh, 1e-4));
+    }
+
+    #[test]
+    fn multiple_components_with_holes() {
+        let mut vertices = Vec::new();
+        let mut indices = Vec::new();
+
+        // Sphere without some faces and separate torus
+        let sphere = create_uv_sphere_mesh(6, 10);
+        let torus = create_torus_mesh(8, 5);
+
+        for (mesh, with_holes) in [(&sphere, true), (&torus, false)] {
+            let offset = vertices.len();
+            vertices.extend(mesh.vertices().map(|vertex| *mesh.vertex_position(&vertex)));
+
+            for (index, face) in mesh.faces().enumerate() {
+                if with_holes && (index == 5 || index == 60) {
+                    continue;
+                }
+
+                let (v1, v2, v3) = mesh.face_vertices(&face);
+                indices.extend([v1.index() + offset, v2.index() + offset, v3.index() + offset]);
+            }
+        }
+
+        // Isolated vertex
+        vertices.push(Point3::new(5.0, 5.0, 5.0));
+
+        let mesh = CornerTableF::from_vertices_and_indices(&vertices, &indices);
+        let (_, decoded) = round_trip(&mesh, EdgebreakerWriter::new().with_quantization_bits(30));
+
+        assert_eq!(decoded.faces().count(), mesh.faces().count());
+        assert_eq!(decoded.vertices().count(), mesh.vertices().count());
+        assert_eq!(triangles(&decoded, 1e-4), triangles(&mesh, 1e-4));
+    }
+
+    #[test]
+    fn compact_index_mesh() {
+        let mesh = create_torus_mesh(9, 7);
+        let vertices: Vec<_> = mesh.vertices().map(|vertex| *mesh.vertex_position(&vertex)).collect();
+        let indices: Vec<_> = mesh.faces()
+            .flat_map(|face| {
+                let (v1, v2, v3) = mesh.face_vertices(&face);
+                return [v1.index(), v2.index(), v3.index()];
+            })
+            .collect();
+        let compact = CompactCornerTableF::from_vertices_and_indices(&vertices, &indices);
+
+        let mut buffer = BufWriter::new(Vec::new());
+        EdgebreakerWriter::new().write_edgebreaker(&compact, &mut buffer).expect("Write Edgebreaker");
+
+        let (data, _) = round_trip(&mesh, EdgebreakerWriter::new());
+        assert_eq!(buffer.into_inner().unwrap(), data);
+    }
+
+    #[test]
+    fn quantization() {
+        let mesh = create_uv_sphere_mesh(20, 30);
+        let (data, decoded) = round_trip(&mesh, EdgebreakerWriter::new().with_quantization_bits(8));
+
+        // Cell size of 8-bit grid over [-1, 1] is 2/255
+        let max_error = 1.0 / 255.0 + 1e-6;
+
+        assert_eq!(decoded.faces().count(), mesh.faces().count());
+        assert!(data.len() < mesh.faces().count() * 5);
+
+        for vertex in decoded.vertices() {
+            let position = decoded.vertex_position(&vertex);
+            let error = mesh.vertices()
+                .map(|original| (mesh.vertex_position(&original) - position).amax())
+                .fold(f32::MAX, f32::min);
+
+            assert!(error <= max_error);
+        }
+    }
+
+    #[test]
+    fn read_invalid() {
+        let mesh = create_uv_sphere_mesh(6, 8);
+        let mut buffer = BufWriter::new(Vec::new());
+        EdgebreakerWriter::new().write_edgebreaker(&mesh, &mut buffer).unwrap();
+        let data = buffer.into_inner().unwrap();
+
+        let truncated: Result<CornerTableF, _> = EdgebreakerReader::new().read_edgebreaker(&mut BufReader::new(&data[..data.len() - 5]));
+        assert!(matches!(truncated, Err(MeshIoError::TruncatedData { .. })));
+
+        let mut version = data.clone();
+        version[4] = 2;
+        let version: Result<CornerTableF, _> = EdgebreakerReader::new().read_edgebreaker(&mut BufReader::new(version.as_slice()));
+        assert!(matches!(version, Err(MeshIoError::UnsupportedEncoding(_))));
+
+        // Mesh without faces ends with number of isolated vertices, claim much more of them than stored
+        let mut buffer = BufWriter::new(Vec::new());
+        EdgebreakerWriter::new().write_edgebreaker(&CornerTableF::new(), &mut buffer).unwrap();
+        let mut oversized = buffer.into_inner().unwrap();
+        assert_eq!(oversized.pop(), Some(0));
+        oversized.extend([0x80, 0x80, 0x80, 0x80, 0x80, 0x80, 0x80, 0x80, 0x10]);
+        oversized.extend([2, 2, 2]);
+
+        let oversized: Result<CornerTableF, _> = EdgebreakerReader::new().read_edgebreaker(&mut BufReader::new(oversized.as_slice()));
+        assert!(matches!(oversized, Err(MeshIoError::TruncatedData { .. })));
+    }
+}
@@ -0,0 +1,242 @@
+use std::{
+    fmt::Display,
+    io::{self, Read, BufRead, ErrorKind},
+    collections::HashSet
+};
+use nalgebra::Point3;
+use num_traits::Float;
+
+use crate::mesh::traits::Mesh;
+
+///
+/// Error returned by mesh readers and writers
+///
+#[derive(Debug)]
+pub enum MeshIoError {
+    /// Error of underlying stream or file system
+    Io(io::Error),
+    /// Input ended unexpectedly. `offset` is position of incomplete value in bytes from the start of input.
+    TruncatedData { offset: u64 },
+    /// Number of triangles found in file differs from number declared in its header
+    TriangleCountMismatch { expected: usize, actual: usize },
+    /// Vertex has NaN or infinite coordinate. Vertices are numbered in order they appear in file.
+    NonFiniteCoordinate { vertex: usize },
+    /// File encoding or version is not supported
+    UnsupportedEncoding(String),
+    /// No reader/writer for given format
+    UnsupportedFormat(String),
+    /// Malformed file content or mesh that can't be represented in requested format
+    InvalidData(String),
+    /// Face references same vertex more than once
+    DegenerateFace { face: usize },
+    /// Face shares directed edge with another face (non-manifold edge or inconsistent orientation), reported by strict readers only
+    NonManifoldFace { face: usize }
+}
+
+impl Display for MeshIoError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            MeshIoError::Io(err) => return write!(f, "{}", err),
+            MeshIoError::TruncatedData { offset } => return write!(f, "data is truncated at byte {}", offset),
+            MeshIoError::TriangleCountMismatch { expected, actual } =>
+                return write!(f, "header declares {} triangles but file contains {}", expected, actual),
+            MeshIoError::NonFiniteCoordinate { vertex } => return write!(f, "vertex {} has non-finite coordinate", vertex),
+            MeshIoError::UnsupportedEncoding(message) => return write!(f, "unsupported encoding: {}", message),
+            MeshIoError::UnsupportedFormat(message) => return write!(f, "unsupported format: {}", message),
+            MeshIoError::InvalidData(message) => return write!(f, "{}", message),
+            MeshIoError::DegenerateFace { face } => return write!(f, "face {} is degenerate", face),
+            MeshIoError::NonManifoldFace { face } => return write!(f, "face {} is non-manifold", face),
+        }
+    }
+}
+
+impl std::error::Error for MeshIoError {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match self {
+            MeshIoError::Io(err) => return Some(err),
+            _ => return None,
+        }
+    }
+}
+
+impl From<io::Error> for MeshIoError {
+    #[inline]
+    fn from(err: io::Error) -> Self {
+        return MeshIoError::Io(err);
+    }
+}
+
+impl From<MeshIoError> for io::Error {
+    fn from(err: MeshIoError) -> Self {
+        match err {
+            MeshIoError::Io(err) => return err,
+            MeshIoError::TruncatedData { .. } => return io::Error::new(ErrorKind::UnexpectedEof, err),
+            MeshIoError::UnsupportedFormat(_) => return io::Error::new(ErrorKind::Unsupported, err),
+            _ => return io::Error::new(ErrorKind::InvalidData, err),
+        }
+    }
+}
+
+///
+/// Reader that keeps track of number of consumed bytes,
+/// so that unexpected end of input can be reported with its position
+///
+pub(super) struct OffsetReader<TBuffer> {
+    inner: TBuffer,
+    offset: u64
+}
+
+impl<TBuffer: BufRead> OffsetReader<TBuffer> {
+    #[inline]
+    pub fn new(inner: TBuffer, offset: u64) -> Self {
+        return Self { inner, offset };
+    }
+
+    /// Number of bytes consumed so far
+    #[inline]
+    pub fn offset(&self) -> u64 {
+        return self.offset;
+    }
+
+    /// Returns `true` when there is no more data
+    #[inline]
+    pub fn is_at_end(&mut self) -> Result<bool, MeshIoError> {
+        return Ok(self.inner.fill_buf()?.is_empty());
+    }
+
+    /// Same as [Read::read_exact] but reports end of input as [MeshIoError::TruncatedData]
+    pub fn read_exact(&mut self, buf: &mut [u8]) -> Result<(), MeshIoError> {
+        let offset = self.offset;
+
+        match self.inner.read_exact(buf) {
+            Ok(()) => {
+                self.offset += buf.len() as u64;
+                return Ok(());
+            },
+            Err(err) if err.kind() == ErrorKind::UnexpectedEof => return Err(MeshIoError::TruncatedData { offset }),
+            Err(err) => return Err(err.into()),
+        }
+    }
+}
+
+impl<TBuffer: BufRead> Read for OffsetReader<TBuffer> {
+    #[inline]
+    fn read(&mut self, buf: &mut [u8]) -> io::Result<usize> {
+        let count = self.inner.read(buf)?;
+        self.offset += count as u64;
+        return Ok(count);
+    }
+}
+
+impl<TBuffer: BufRead> BufRead for OffsetReader<TBuffer> {
+    #[inline]
+    fn fill_buf(&mut self) -> io::Result<&[u8]> {
+        return self.inner.fill_buf();
+    }
+
+    #[inline]
+    fn consume(&mut self, amt: usize) {
+        self.inner.consume(amt);
+        self.offset += amt as u64;
+    }
+}
+
+///
+/// Creates mesh from vertices and indices read from file.
+/// Faces that share directed edge with another face (non-manifold edges or inconsistent orientation)
+/// are kept by mesh builder, see [CornerTable::from_vertices_and_indices_with_duplicates](crate::mesh::corner_table::table::CornerTable::from_vertices_and_indices_with_duplicates).
+/// Faces referencing same vertex more than once are skipped.
+/// With `strict` they are reported as [MeshIoError::NonManifoldFace] and [MeshIoError::DegenerateFace] instead.
+///
+pub(super) fn build_mesh<TMesh: Mesh>(vertices: &[Point3<TMesh::ScalarType>], indices: &[usize], strict: bool) -> Result<TMesh, MeshIoError> {
+    return build_mesh_with_duplicates(vertices, indices, strict).map(|(mesh, _)| mesh);
+}
+
+///
+/// Same as [build_mesh], also returns `(original, duplicate)` pairs of vertices split by mesh builder,
+/// see [Mesh::from_vertices_and_indices_with_duplicates]. Duplicates are appended after `vertices`.
+///
+pub(super) fn build_mesh_with_duplicates<TMesh: Mesh>(vertices: &[Point3<TMesh::ScalarType>], indices: &[usize], strict: bool) -> Result<(TMesh, Vec<(usize, usize)>), MeshIoError> {
+    if let Some(vertex) = vertices.iter().position(|v| v.iter().any(|coordinate| !Float::is_finite(*coordinate))) {
+        return Err(MeshIoError::NonFiniteCoordinate { vertex });
+    }
+
+    let mut edges = HashSet::with_capacity(if strict { indices.len() } else { 0 });
+
+    for (face, triangle) in indices.chunks_exact(3).enumerate() {
+        if let Some(index) = triangle.iter().find(|index| **index >= vertices.len()) {
+            return Err(MeshIoError::InvalidData(format!("face {} references missing vertex {}", face, index)));
+        }
+
+        if !strict {
+            continue;
+        }
+
+        if is_degenerate(triangle) {
+            return Err(MeshIoError::DegenerateFace { face });
+        }
+
+        let (v1, v2, v3) = (triangle[0], triangle[1], triangle[2]);
+
+        // Each directed edge can belong to one face only
+        for edge in [(v1, v2), (v2, v3), (v3, v1)] {
+            if !edges.insert(edge) {
+                return Err(MeshIoError::NonManifoldFace { face });
+            }
+        }
+    }
+
+    if !strict && indices.chunks_exact(3).any(is_degenerate) {
+        let mut indices = indices.to_vec();
+        remove_degenerate_faces(&mut indices);
+
+        return Ok(TMesh::from_vertices_and_indices_with_duplicates(vertices, &indices));
+    }
+
+    return Ok(TMesh::from_vertices_and_indices_with_duplicates(vertices, indices));
+}
+
+///
+/// Removes faces that reference same vertex more than once from index buffer.
+/// Returns indices of removed faces in ascending order, so that readers can drop data attached to them.
+///
+pub(super) fn remove_degenerate_faces(indices: &mut Vec<usize>) -> Vec<usize> {
+    let removed: Vec<_> = indices.chunks_exact(3)
+        .enumerate()
+        .filter(|(_, triangle)| is_degenerate(triangle))
+        .map(|(face, _)| face)
+        .collect();
+
+    remove_face_data(indices, &removed, 3);
+
+    return removed;
+}
+
+/// Removes data of given faces (sorted in ascending order), every face has `values_per_face` consecutive values
+pub(super) fn remove_face_data<T>(data: &mut Vec<T>, faces: &[usize], values_per_face: usize) {
+    if faces.is_empty() {
+        return;
+    }
+
+    let mut value = 0;
+    data.retain(|_| {
+        let keep = faces.binary_search(&(value / values_per_face)).is_err();
+        value += 1;
+        return keep;
+    });
+}
+
+#[inline]
+fn is_degenerate(triangle: &[usize]) -> bool {
+    return triangle[0] == triangle[1] || triangle[1] == triangle[2] || triangle[2] == triangle[0];
+}
+
+/// Converts error of reading text line. Non UTF-8 input is reported as [MeshIoError::UnsupportedEncoding].
+#[inline]
+pub(super) fn text_error(err: io::Error, format: &str) -> MeshIoError {
+    if err.kind() == ErrorKind::InvalidData {
+        return MeshIoError::UnsupportedEncoding(format!("{} file is not valid UTF-8", format));
+    }
+
+    return MeshIoError::Io(err);
+}
@@ -0,0 +1,647 @@
+use std::{
+    mem::size_of,
+    io::{Read, BufReader, BufRead, Write, BufWriter},
+    fs::{OpenOptions, File}, path::Path, collections::HashMap
+};
+use nalgebra::{Point3, Vector3};
+use serde_json::{json, Value};
+use simba::scalar::SupersetOf;
+
+use crate::{algo::utils::{cast, cwise_max, cwise_min}, mesh::traits::Mesh};
+
+use super::{traits::{MeshReader, MeshWriter}, error::{MeshIoError, OffsetReader, build_mesh}};
+
+const GLB_MAGIC: u32 = 0x46546C67;
+const GLB_VERSION: u32 = 2;
+const GLB_HEADER_SIZE: usize = 12;
+const GLB_CHUNK_HEADER_SIZE: usize = 8;
+const CHUNK_TYPE_JSON: u32 = 0x4E4F534A;
+const CHUNK_TYPE_BIN: u32 = 0x004E4942;
+
+const TARGET_ARRAY_BUFFER: u32 = 34962;
+const TARGET_ELEMENT_ARRAY_BUFFER: u32 = 34963;
+
+const COMPONENT_TYPE_UNSIGNED_BYTE: u64 = 5121;
+const COMPONENT_TYPE_UNSIGNED_SHORT: u64 = 5123;
+const COMPONENT_TYPE_UNSIGNED_INT: u64 = 5125;
+const COMPONENT_TYPE_FLOAT: u64 = 5126;
+
+const MODE_TRIANGLES: u64 = 4;
+
+///
+/// Writer of binary glTF 2.0 (`.glb`) files.
+/// Mesh is written as single indexed triangles primitive with positions and, optionally, vertex normals
+/// stored in embedded binary buffer. Mesh without faces is written as empty scene, its isolated vertices are dropped.
+///
+pub struct GltfWriter {
+    write_normals: bool
+}
+
+impl GltfWriter {
+    pub fn new() -> Self {
+        return Self {
+            write_normals: false
+        };
+    }
+
+    /// Set flag indicating whether vertex normals should be written. Default is `false`
+    #[inline]
+    pub fn with_normals(mut self, write_normals: bool) -> Self {
+        self.write_normals = write_normals;
+        return self;
+    }
+
+    pub fn write_glb_to_file<TMesh: Mesh>(&self, mesh: &TMesh, path: &Path) -> Result<(), MeshIoError> {
+        let file = OpenOptions::new()
+            .write(true)
+            .truncate(true)
+            .create(true)
+            .open(path)?;
+        let mut writer = BufWriter::new(file);
+
+        self.write_glb(mesh, &mut writer)?;
+        writer.flush()?;
+
+        return Ok(());
+    }
+
+    pub fn write_glb<TBuffer, TMesh>(&self, mesh: &TMesh, writer: &mut BufWriter<TBuffer>) -> Result<(), MeshIoError>
+    where
+        TBuffer: Write,
+        TMesh: Mesh
+    {
+        // Accessors can't be empty, so mesh without faces is written as scene without nodes
+        if mesh.faces().next().is_none() {
+            return write_chunks(writer, &json!({ "asset": gltf_asset(), "scene": 0, "scenes": [{}] }), Vec::new());
+        }
+
+        let mut vertex_index_map = HashMap::new();
+        let mut bin = Vec::new();
+        let mut min = Point3::new(f32::MAX, f32::MAX, f32::MAX);
+        let mut max = Point3::new(f32::MIN, f32::MIN, f32::MIN);
+
+        // Positions
+        for vertex in mesh.vertices() {
+            vertex_index_map.insert(vertex, vertex_index_map.len() as u32);
+
+            let position: Point3<f32> = cast(&mesh.vertex_position(&vertex).coords).into();
+            min = cwise_min(&min, &position);
+            max = cwise_max(&max, &position);
+            write_vec3(&mut bin, &position.coords);
+        }
+
+        let vertices_count = vertex_index_map.len();
+        if vertices_count > u32::MAX as usize {
+            return Err(MeshIoError::InvalidData("Mesh is too big for glTF".to_string()));
+        }
+
+        let positions_length = bin.len();
+
+        // Normals
+        if self.write_normals {
+            for vertex in mesh.vertices() {
+                // Normal is undefined for isolated and degenerate vertices, but glTF requires unit vectors
+                let normal = mesh.vertex_normal(&vertex).unwrap_or_else(Vector3::z);
+                write_vec3(&mut bin, &cast(&normal));
+            }
+        }
+
+        let normals_length = bin.len() - positions_length;
+
+        // Indices
+        let mut indices_count = 0;
+        for face in mesh.faces() {
+            let (v1, v2, v3) = mesh.face_vertices(&face);
+
+            for vertex in [v1, v2, v3] {
+                bin.extend_from_slice(&vertex_index_map[&vertex].to_le_bytes());
+            }
+
+            indices_count += 3;
+        }
+
+        let indices_length = bin.len() - positions_length - normals_length;
+
+        // Document
+        let mut buffer_views = vec![
+            json!({ "buffer": 0, "byteOffset": 0, "byteLength": positions_length, "target": TARGET_ARRAY_BUFFER })
+        ];
+        let mut accessors = vec![
+            json!({
+                "bufferView": 0,
+                "componentType": COMPONENT_TYPE_FLOAT,
+                "count": vertices_count,
+                "type": "VEC3",
+                "min": [min.x, min.y, min.z],
+                "max": [max.x, max.y, max.z]
+            })
+        ];
+        let mut attributes = json!({ "POSITION": 0 });
+
+        if self.write_normals {
+            attributes["NORMAL"] = json!(accessors.len());
+            accessors.push(json!({ "bufferView": buffer_views.len(), "componentType": COMPONENT_TYPE_FLOAT, "count": vertices_count, "type": "VEC3" }));
+            buffer_views.push(json!({ "buffer": 0, "byteOffset": positions_length, "byteLength": normals_length, "target": TARGET_ARRAY_BUFFER }));
+        }
+
+        let indices_accessor = accessors.len();
+        accessors.push(json!({ "bufferView": buffer_views.len(), "componentType": COMPONENT_TYPE_UNSIGNED_INT, "count": indices_count, "type": "SCALAR" }));
+        buffer_views.push(json!({
+            "buffer": 0,
+            "byteOffset": positions_length + normals_length,
+            "byteLength": indices_length,
+            "target": TARGET_ELEMENT_ARRAY_BUFFER
+        }));
+
+        let document = json!({
+            "asset": gltf_asset(),
+            "scene": 0,
+            "scenes": [{ "nodes": [0] }],
+            "nodes": [{ "mesh": 0 }],
+            "meshes": [{
+                "primitives": [{ "attributes": attributes, "indices": indices_accessor, "mode": MODE_TRIANGLES }]
+            }],
+            "accessors": accessors,
+            "bufferViews": buffer_views,
+            "buffers": [{ "byteLength": bin.len() }]
+        });
+
+        return write_chunks(writer, &document, bin);
+    }
+}
+
+#[inline]
+fn gltf_asset() -> Value {
+    return json!({ "version": "2.0", "generator": "baby_shark" });
+}
+
+/// Writes GLB header, JSON chunk and BIN chunk (omitted when empty)
+fn write_chunks<TBuffer: Write>(writer: &mut BufWriter<TBuffer>, document: &Value, mut bin: Vec<u8>) -> Result<(), MeshIoError> {
+    // Chunks are 4-byte aligned, JSON is padded with spaces and binary data with zeros
+    let mut json = document.to_string().into_bytes();
+    pad_to_4_bytes(&mut json, b' ');
+    pad_to_4_bytes(&mut bin, 0);
+
+    let bin_chunk_length = if bin.is_empty() { 0 } else { GLB_CHUNK_HEADER_SIZE + bin.len() };
+    let total_length = GLB_HEADER_SIZE + GLB_CHUNK_HEADER_SIZE + json.len() + bin_chunk_length;
+    if total_length > u32::MAX as usize {
+        return Err(MeshIoError::InvalidData("Mesh is too big for glTF".to_string()));
+    }
+
+    writer.write_all(&GLB_MAGIC.to_le_bytes())?;
+    writer.write_all(&GLB_VERSION.to_le_bytes())?;
+    writer.write_all(&(total_length as u32).to_le_bytes())?;
+
+    writer.write_all(&(json.len() as u32).to_le_bytes())?;
+    writer.write_all(&CHUNK_TYPE_JSON.to_le_bytes())?;
+    writer.write_all(&json)?;
+
+    if !bin.is_empty() {
+        writer.write_all(&(bin.len() as u32).to_le_bytes())?;
+        writer.write_all(&CHUNK_TYPE_BIN.to_le_bytes())?;
+        writer.write_all(&bin)?;
+    }
+
+    return Ok(());
+}
+
+impl Default for GltfWriter {
+    #[inline]
+    fn default() -> Self {
+        return Self::new();
+    }
+}
+
+impl<TMesh: Mesh> MeshWriter<TMesh> for GltfWriter {
+    #[inline]
+    fn supports_extension(&self, extension: &str) -> bool {
+        return extension == "glb";
+    }
+
+    fn write(&self, mesh: &TMesh, writer: &mut dyn Write) -> Result<(), MeshIoError> {
+        let mut writer = BufWriter::new(writer);
+        self.write_glb(mesh, &mut writer)?;
+        writer.flush()?;
+
+        return Ok(());
+    }
+}
+
+#[inline]
+fn write_vec3(bin: &mut Vec<u8>, vector: &Vector3<f32>) {
+    bin.extend_from_slice(&vector.x.to_le_bytes());
+    bin.extend_from_slice(&vector.y.to_le_bytes());
+    bin.extend_from_slice(&vector.z.to_le_bytes());
+}
+
+#[inline]
+fn pad_to_4_bytes(data: &mut Vec<u8>, padding: u8) {
+    let padding_length = (4 - data.len() % 4) % 4;
+    data.resize(data.len() + padding_length, padding);
+}
+
+///
+/// Reader of binary glTF 2.0 (`.glb`) files.
+///
+/// Supports the subset written by [GltfWriter]: triangle primitives with float positions
+/// and optional unsigned byte/short/int indices stored in embedded binary buffer.
+/// All triangle primitives of all meshes are merged into single mesh, node transforms are ignored.
+///
+pub struct GltfReader {
+    vertices: Vec<Point3<f32>>,
+    indices: Vec<usize>,
+    strict_topology: bool
+}
+
+impl GltfReader {
+    pub fn new() -> Self {
+        return Self {
+            vertices: Vec::new(),
+            indices: Vec::new(),
+            strict_topology: false
+        };
+    }
+
+    ///
+    /// Set whether faces sharing directed edge with another face and faces referencing same vertex more than once
+    /// are rejected as [MeshIoError::NonManifoldFace] and [MeshIoError::DegenerateFace].
+    /// By default mesh is split along non-manifold edges and vertices and degenerate faces are skipped.
+    ///
+    #[inline]
+    pub fn with_strict_topology(mut self, strict_topology: bool) -> Self {
+        self.strict_topology = strict_topology;
+        return self;
+    }
+
+    /// Reads mesh from file
+    pub fn read_glb_from_file<TMesh>(&mut self, filepath: &Path) -> Result<TMesh, MeshIoError>
+    where
+        TMesh: Mesh,
+        TMesh::ScalarType: SupersetOf<f32>
+    {
+        let file = OpenOptions::new().read(true).open(filepath)?;
+        let mut reader = BufReader::new(file);
+
+        return self.read_glb::<File, TMesh>(&mut reader);
+    }
+
+    /// Reads mesh from buffer
+    pub fn read_glb<TBuffer, TMesh>(&mut self, reader: &mut BufReader<TBuffer>) -> Result<TMesh, MeshIoError>
+    where
+        TBuffer: Read,
+        TMesh: Mesh,
+        TMesh::ScalarType: SupersetOf<f32>
+    {
+        self.vertices.clear();
+        self.indices.clear();
+
+        let reader = &mut OffsetReader::new(reader, 0);
+
+        // Header
+        if read_u32(reader)? != GLB_MAGIC {
+            return Err(gltf_error("missing `glTF` magic number"));
+        }
+
+        let version = read_u32(reader)?;
+        if version != GLB_VERSION {
+            return Err(MeshIoError::UnsupportedEncoding(format!("glTF version {}", version)));
+        }
+
+        let total_length = read_u32(reader)? as u64;
+
+        // Chunks
+        let (json, chunk_type) = read_chunk(reader, total_length)?;
+        if chunk_type != CHUNK_TYPE_JSON {
+            return Err(gltf_error("first chunk is not JSON"));
+        }
+
+        let document: Value = serde_json::from_slice(&json).map_err(|err| gltf_error(&err.to_string()))?;
+
+        // Binary chunk is optional
+        let bin = if reader.is_at_end()? {
+            Vec::new()
+        } else {
+            match read_chunk(reader, total_length)? {
+                (bin, CHUNK_TYPE_BIN) => bin,
+                _ => return Err(gltf_error("second chunk is not BIN")),
+            }
+        };
+
+        let meshes = document["meshes"].as_array().map(Vec::as_slice).unwrap_or_default();
+
+        for primitive in meshes.iter().flat_map(|mesh| mesh["primitives"].as_array().map(Vec::as_slice).unwrap_or_default()) {
+            let mode = primitive.get("mode").and_then(Value::as_u64).unwrap_or(MODE_TRIANGLES);
+            if mode != MODE_TRIANGLES {
+                continue;
+            }
+
+            self.read_primitive(&document, &bin, primitive)?;
+        }
+
+        let vertices: Vec<_> = self.vertices
+            .iter()
+            .map(|point| point.cast::<TMesh::ScalarType>())
+            .collect();
+
+        return build_mesh(&vertices, &self.indices, self.strict_topology);
+    }
+
+    fn read_primitive(&mut self, document: &Value, bin: &[u8], primitive: &Value) -> Result<(), MeshIoError> {
+        let first_vertex = self.vertices.len();
+
+        // Positions
+        let position_accessor = primitive["attributes"]["POSITION"].as_u64()
+            .ok_or_else(|| gltf_error("primitive without positions"))?;
+        let positions = Accessor::new(document, bin, position_accessor as usize)?;
+
+        if positions.component_type != COMPONENT_TYPE_FLOAT || positions.components != 3 {
+            return Err(gltf_error("positions should be float VEC3"));
+        }
+
+        for i in 0..positions.count {
+            let x = positions.read_f32(i, 0);
+            let y = positions.read_f32(i, 1);
+            let z = positions.read_f32(i, 2);
+            self.vertices.push(Point3::new(x, y, z));
+        }
+
+        // Indices
+        match primitive.get("indices").and_then(Value::as_u64) {
+            Some(indices_accessor) => {
+                let indices = Accessor::new(document, bin, indices_accessor as usize)?;
+
+                if indices.components != 1 || indices.count % 3 != 0 {
+                    return Err(gltf_error("indices should be scalars, three per triangle"));
+                }
+
+                for i in 0..indices.count {
+                    let index = indices.read_index(i)?;
+
+                    if index >= positions.count {
+                        return Err(gltf_error("index is out of range"));
+                    }
+
+                    self.indices.push(first_vertex + index);
+                }
+            },
+            None => {
+                let vertices_count = positions.count - positions.count % 3;
+                self.indices.extend(first_vertex..first_vertex + vertices_count);
+            }
+        }
+
+        return Ok(());
+    }
+}
+
+impl Default for GltfReader {
+    #[inline]
+    fn default() -> Self {
+        return Self::new();
+    }
+}
+
+impl<TMesh> MeshReader<TMesh> for GltfReader
+where
+    TMesh: Mesh,
+    TMesh::ScalarType: SupersetOf<f32>
+{
+    #[inline]
+    fn supports_extension(&self, extension: &str) -> bool {
+        return extension == "glb";
+    }
+
+    #[inline]
+    fn can_read(&self, header: &[u8]) -> bool {
+        return header.starts_with(&GLB_MAGIC.to_le_bytes());
+    }
+
+    #[inline]
+    fn read(&mut self, reader: &mut dyn Read) -> Result<TMesh, MeshIoError> {
+        return self.read_glb(&mut BufReader::new(reader));
+    }
+}
+
+/// View of accessor data inside binary buffer
+struct Accessor<'a> {
+    data: &'a [u8],
+    component_type: u64,
+    components: usize,
+    count: usize,
+    stride: usize
+}
+
+impl<'a> Accessor<'a> {
+    fn new(document: &Value, bin: &'a [u8], index: usize) -> Result<Self, MeshIoError> {
+        let accessor = &document["accessors"][index];
+        let component_type = accessor["componentType"].as_u64().ok_or_else(|| gltf_error("accessor without component type"))?;
+        let count = accessor["count"].as_u64().ok_or_else(|| gltf_error("accessor without count"))? as usize;
+
+        let components = match accessor["type"].as_str() {
+            Some("SCALAR") => 1,
+            Some("VEC2") => 2,
+            Some("VEC3") => 3,
+            Some("VEC4") => 4,
+            _ => return Err(gltf_error("unsupported accessor type"))
+        };
+
+        let component_size = match component_type {
+            COMPONENT_TYPE_UNSIGNED_BYTE => size_of::<u8>(),
+            COMPONENT_TYPE_UNSIGNED_SHORT => size_of::<u16>(),
+            COMPONENT_TYPE_UNSIGNED_INT | COMPONENT_TYPE_FLOAT => size_of::<u32>(),
+            _ => return Err(gltf_error("unsupported accessor component type"))
+        };
+
+        let buffer_view_index = accessor["bufferView"].as_u64().ok_or_else(|| gltf_error("sparse accessors are not supported"))?;
+        let buffer_view = &document["bufferViews"][buffer_view_index as usize];
+
+        if buffer_view["buffer"].as_u64() != Some(0) || document["buffers"][0].get("uri").is_some() {
+            return Err(gltf_error("only embedded binary buffer is supported"));
+        }
+
+        let element_size = component_size * components;
+        let stride = buffer_view.get("byteStride").and_then(Value::as_u64).map(|stride| stride as usize).unwrap_or(element_size);
+        let view_offset = buffer_view.get("byteOffset").and_then(Value::as_u64).unwrap_or(0) as usize;
+        let accessor_offset = accessor.get("byteOffset").and_then(Value::as_u64).unwrap_or(0) as usize;
+
+        // Values come from file, so overflow is reported as invalid data
+        let out_of_bounds = || gltf_error("accessor is out of buffer bounds");
+        let offset = view_offset.checked_add(accessor_offset).ok_or_else(out_of_bounds)?;
+        let length = match count {
+            0 => 0,
+            _ => stride.checked_mul(count - 1).and_then(|length| length.checked_add(element_size)).ok_or_else(out_of_bounds)?
+        };
+        let end = offset.checked_add(length).ok_or_else(out_of_bounds)?;
+
+        if end > bin.len() {
+            return Err(out_of_bounds());
+        }
+
+        return Ok(Self {
+            data: &bin[offset..end],
+            component_type,
+            components,
+            count,
+            stride
+        });
+    }
+
+    #[inline]
+    fn read_f32(&self, element: usize, component: usize) -> f32 {
+        let offset = element * self.stride + component * size_of::<f32>();
+        return f32::from_le_bytes([self.data[offset], self.data[offset + 1], self.data[offset + 2], self.data[offset + 3]]);
+    }
+
+    #[inline]
+    fn read_index(&self, element: usize) -> Result<usize, MeshIoError> {
+        let offset = element * self.stride;
+        let d = self.data;
+
+        match self.component_type {
+            COMPONENT_TYPE_UNSIGNED_BYTE => return Ok(d[offset] as usize),
+            COMPONENT_TYPE_UNSIGNED_SHORT => return Ok(u16::from_le_bytes([d[offset], d[offset + 1]]) as usize),
+            COMPONENT_TYPE_UNSIGNED_INT => return Ok(u32::from_le_bytes([d[offset], d[offset + 1], d[offset + 2], d[offset + 3]]) as usize),
+            _ => return Err(gltf_error("indices should be unsigned integers")),
+        }
+    }
+}
+
+#[inline]
+fn gltf_error(message: &str) -> MeshIoError {
+    return MeshIoError::InvalidData(format!("glTF: {}", message));
+}
+
+#[inline]
+fn read_u32<TBuffer: BufRead>(reader: &mut OffsetReader<TBuffer>) -> Result<u32, MeshIoError> {
+    let mut buf = [0u8; size_of::<u32>()];
+    reader.read_exact(&mut buf)?;
+    return Ok(u32::from_le_bytes(buf));
+}
+
+/// Reads chunk data and type. Chunk should end within file length declared in header.
+fn read_chunk<TBuffer: BufRead>(reader: &mut OffsetReader<TBuffer>, total_length: u64) -> Result<(Vec<u8>, u32), MeshIoError> {
+    let length = read_u32(reader)? as u64;
+    let chunk_type = read_u32(reader)?;
+
+    let offset = reader.offset();
+    if offset + length > total_length {
+        return Err(gltf_error("chunk exceeds file length declared in header"));
+    }
+
+    // Buffer grows with data actually read, so truncated input can't force large allocation
+    let mut data = Vec::new();
+    reader.by_ref().take(length).read_to_end(&mut data)?;
+
+    if (data.len() as u64) < length {
+        return Err(MeshIoError::TruncatedData { offset });
+    }
+
+    return Ok((data, chunk_type));
+}
+
+#[cfg(test)]
+mod tests {
+    use std::io::{BufReader, BufWriter};
+
+    use serde_json::Value;
+
+    use crate::{
+        mesh::{corner_table::{prelude::CornerTableF, test_helpers::create_unit_cross_square_mesh}, traits::Mesh},
+        io::MeshIoError
+    };
+
+    use super::{Accessor, GltfReader, GltfWriter};
+
+    fn write(mesh: &CornerTableF, writer: GltfWriter) -> Vec<u8> {
+        let mut buffer = BufWriter::new(Vec::new());
+        writer.write_glb(mesh, &mut buffer).expect("Write GLB");
+        return buffer.into_inner().unwrap();
+    }
+
+    #[test]
+    fn write_glb() {
+        let mesh = create_unit_cross_square_mesh();
+        let glb = write(&mesh, GltfWriter::new().with_normals(true));
+
+        assert_eq!(&glb[0..4], b"glTF");
+        assert_eq!(u32::from_le_bytes([glb[8], glb[9], glb[10], glb[11]]) as usize, glb.len());
+        assert_eq!(glb.len() % 4, 0);
+
+        let json_length = u32::from_le_bytes([glb[12], glb[13], glb[14], glb[15]]) as usize;
+        let document: Value = serde_json::from_slice(&glb[20..20 + json_length]).unwrap();
+        let positions = &document["accessors"][0];
+
+        assert_eq!(positions["count"], 5);
+        assert_eq!(positions["min"], serde_json::json!([0.0, 0.0, 0.0]));
+        assert_eq!(positions["max"], serde_json::json!([1.0, 1.0, 0.0]));
+        assert_eq!(document["meshes"][0]["primitives"][0]["attributes"]["NORMAL"], 1);
+    }
+
+    #[test]
+    fn write_empty_glb() {
+        let glb = write(&CornerTableF::new(), GltfWriter::new().with_normals(true));
+
+        let json_length = u32::from_le_bytes([glb[12], glb[13], glb[14], glb[15]]) as usize;
+        assert_eq!(glb.len(), 20 + json_length);
+
+        let document: Value = serde_json::from_slice(&glb[20..]).unwrap();
+        assert!(document.get("meshes").is_none());
+        assert!(document.get("accessors").is_none());
+
+        let read_back: CornerTableF = GltfReader::new().read_glb(&mut BufReader::new(glb.as_slice())).expect("Read GLB");
+        assert_eq!(read_back.faces().count(), 0);
+    }
+
+    #[test]
+    fn reject_overflowing_accessor() {
+        let document = serde_json::json!({
+            "accessors": [
+                { "bufferView": 0, "componentType": 5126, "count": u64::MAX, "type": "VEC3" },
+                { "bufferView": 1, "byteOffset": 1, "componentType": 5126, "count": 1, "type": "VEC3" }
+            ],
+            "bufferViews": [
+                { "buffer": 0, "byteStride": 12 },
+                { "buffer": 0, "byteOffset": u64::MAX }
+            ],
+            "buffers": [{ "byteLength": 12 }]
+        });
+        let bin = [0; 12];
+
+        assert!(matches!(Accessor::new(&document, &bin, 0), Err(MeshIoError::InvalidData(_))));
+        assert!(matches!(Accessor::new(&document, &bin, 1), Err(MeshIoError::InvalidData(_))));
+    }
+
+    #[test]
+    fn round_trip() {
+        let mesh = create_unit_cross_square_mesh();
+        let glb = write(&mesh, GltfWriter::new());
+
+        let read_back: CornerTableF = GltfReader::new().read_glb(&mut BufReader::new(glb.as_slice())).expect("Read GLB");
+
+        assert_eq!(read_back.vertices().count(), 5);
+        assert_eq!(read_back.faces().count(), 4);
+
+        for face in mesh.faces() {
+            assert_eq!(read_back.face_vertices(&face), mesh.face_vertices(&face));
+            assert_eq!(read_back.face_positions(&face).p1(), mesh.face_positions(&face).p1());
+        }
+    }
+
+    #[test]
+    fn read_invalid() {
+        let mesh = create_unit_cross_square_mesh();
+        let glb = write(&mesh, GltfWriter::new());
+
+        let result: Result<CornerTableF, MeshIoError> = GltfReader::new().read_glb(&mut BufReader::new(&glb[..glb.len() / 2]));
+        assert!(matches!(result, Err(MeshIoError::TruncatedData { .. })));
+
+        let result: Result<CornerTableF, MeshIoError> = GltfReader::new().read_glb(&mut BufReader::new(&glb[4..]));
+        assert!(result.is_err());
+
+        // Chunk longer than declared file length
+        let mut huge_chunk = glb.clone();
+        huge_chunk[12..16].copy_from_slice(&u32::MAX.to_le_bytes());
+        let result: Result<CornerTableF, MeshIoError> = GltfReader::new().read_glb(&mut BufReader::new(huge_chunk.as_slice()));
+        assert!(matches!(result, Err(MeshIoError::InvalidData(_))));
+    }
+}
@@ -0,0 +1,291 @@
+use std::{
+    io::{Read, Write, Cursor, BufReader, BufWriter},
+    fs::OpenOptions, path::Path
+};
+use simba::scalar::SupersetOf;
+
+use crate::mesh::traits::{Mesh, VertexProperties};
+
+use super::{
+    traits::{MeshReader, MeshWriter},
+    error::MeshIoError,
+    stl::{StlReader, StlWriter},
+    obj::{ObjReader, ObjWriter},
+    ply::{PlyReader, PlyWriter},
+    vtk::{VtkWriter, VtkFormat}
+};
+#[cfg(feature = "gltf")]
+use super::gltf::{GltfReader, GltfWriter};
+
+/// Number of leading bytes passed to [MeshReader::can_read] for format detection
+const SNIFF_SIZE: usize = 512;
+
+///
+/// Registry of mesh codecs. Picks reader/writer by file extension or, for reading, by content of file.
+/// Codecs registered later take precedence over earlier ones, so built-in codecs can be overridden.
+///
+/// ## Example
+/// ```ignore
+/// let mut mesh_io = MeshIo::<CornerTableF>::new();
+/// mesh_io.register_reader(MyFormatReader::new());
+/// let mesh = mesh_io.read_from_file(Path::new("mesh.my"))?;
+/// mesh_io.write_to_file(&mesh, Path::new("mesh.ply"))?;
+/// ```
+///
+pub struct MeshIo<TMesh: Mesh> {
+    readers: Vec<Box<dyn MeshReader<TMesh>>>,
+    writers: Vec<Box<dyn MeshWriter<TMesh>>>
+}
+
+impl<TMesh: Mesh> MeshIo<TMesh> {
+    /// Creates registry without any codecs
+    pub fn empty() -> Self {
+        return Self {
+            readers: Vec::new(),
+            writers: Vec::new()
+        };
+    }
+
+    /// Registers reader
+    pub fn register_reader<TReader: MeshReader<TMesh> + 'static>(&mut self, reader: TReader) -> &mut Self {
+        self.readers.push(Box::new(reader));
+        return self;
+    }
+
+    /// Registers writer
+    pub fn register_writer<TWriter: MeshWriter<TMesh> + 'static>(&mut self, writer: TWriter) -> &mut Self {
+        self.writers.push(Box::new(writer));
+        return self;
+    }
+
+    ///
+    /// Reads mesh from file. Reader is selected by file extension,
+    /// when there is no reader for extension format is detected by file content.
+    ///
+    pub fn read_from_file(&mut self, path: &Path) -> Result<TMesh, MeshIoError> {
+        let file = OpenOptions::new().read(true).open(path)?;
+        let length = file.metadata()?.len();
+        let mut reader = BufReader::new(file);
+
+        return self.read_with_length(&mut reader, extension(path).as_deref(), Some(length));
+    }
+
+    ///
+    /// Reads mesh from stream. Reader is selected by `extension` if given,
+    /// otherwise (or when there is no reader for extension) format is detected by content.
+    ///
+    pub fn read(&mut self, reader: &mut dyn Read, extension: Option<&str>) -> Result<TMesh, MeshIoError> {
+        return self.read_with_length(reader, extension, None);
+    }
+
+    fn read_with_length(&mut self, reader: &mut dyn Read, extension: Option<&str>, length: Option<u64>) -> Result<TMesh, MeshIoError> {
+        if let Some(extension) = extension.map(str::to_lowercase) {
+            if let Some(mesh_reader) = self.readers.iter_mut().rev().find(|r| r.supports_extension(&extension)) {
+                return mesh_reader.read(reader);
+            }
+        }
+
+        // Sniff leading bytes and put them back in front of stream
+        let mut header = Vec::with_capacity(SNIFF_SIZE);
+        reader.take(SNIFF_SIZE as u64).read_to_end(&mut header)?;
+
+        // Short header means that whole stream is read
+        let length = length.or_else(|| (header.len() < SNIFF_SIZE).then_some(header.len() as u64));
+
+        let mesh_reader = self.readers.iter_mut().rev()
+            .find(|r| r.can_read_with_length(&header, length))
+            .ok_or_else(|| MeshIoError::UnsupportedFormat("content is not recognized by any reader".to_string()))?;
+
+        return mesh_reader.read(&mut Cursor::new(header).chain(reader));
+    }
+
+    /// Writes mesh to file. Writer is selected by file extension.
+    pub fn write_to_file(&self, mesh: &TMesh, path: &Path) -> Result<(), MeshIoError> {
+        let extension = extension(path).unwrap_or_default();
+        let mesh_writer = self.find_writer(&extension)?;
+
+        let file = OpenOptions::new()
+            .write(true)
+            .truncate(true)
+            .create(true)
+            .open(path)?;
+        let mut writer = BufWriter::new(file);
+
+        mesh_writer.write(mesh, &mut writer)?;
+        writer.flush()?;
+
+        return Ok(());
+    }
+
+    /// Writes mesh to stream using writer registered for given extension
+    pub fn write(&self, mesh: &TMesh, writer: &mut dyn Write, extension: &str) -> Result<(), MeshIoError> {
+        return self.find_writer(&extension.to_lowercase())?.write(mesh, writer);
+    }
+
+    fn find_writer(&self, extension: &str) -> Result<&dyn MeshWriter<TMesh>, MeshIoError> {
+        return self.writers.iter().rev()
+            .find(|w| w.supports_extension(extension))
+            .map(|w| w.as_ref())
+            .ok_or_else(|| MeshIoError::UnsupportedFormat(format!("no writer for extension `{}`", extension)));
+    }
+}
+
+impl<TMesh> MeshIo<TMesh>
+where
+    TMesh: VertexProperties + 'static,
+    TMesh::ScalarType: SupersetOf<f32> + SupersetOf<f64>
+{
+    /// Creates registry with built-in codecs: STL, OBJ, PLY, GLB (with `gltf` feature) and VTK (write only)
+    pub fn new() -> Self {
+        let mut mesh_io = Self::empty();
+
+        mesh_io
+            .register_reader(StlReader::new())
+            .register_reader(ObjReader::new())
+            .register_reader(PlyReader::new())
+            .register_writer(StlWriter::new())
+            .register_writer(ObjWriter::new())
+            .register_writer(PlyWriter::new())
+            .register_writer(VtkWriter::new())
+            .register_writer(VtkWriter::new().with_format(VtkFormat::Xml));
+
+        #[cfg(feature = "gltf")]
+        mesh_io
+            .register_reader(GltfReader::new())
+            .register_writer(GltfWriter::new());
+
+        return mesh_io;
+    }
+}
+
+impl<TMesh> Default for MeshIo<TMesh>
+where
+    TMesh: VertexProperties + 'static,
+    TMesh::ScalarType: SupersetOf<f32> + SupersetOf<f64>
+{
+    #[inline]
+    fn default() -> Self {
+        return Self::new();
+    }
+}
+
+///
+/// Reads mesh from file using default registry, same as `MeshIo::default().read_from_file(path)`.
+/// Every format registered by [MeshIo::new] is supported, register custom codecs in own [MeshIo] to extend it.
+///
+pub fn read_mesh<TMesh>(path: &Path) -> Result<TMesh, MeshIoError>
+where
+    TMesh: VertexProperties + 'static,
+    TMesh::ScalarType: SupersetOf<f32> + SupersetOf<f64>
+{
+    return MeshIo::default().read_from_file(path);
+}
+
+///
+/// Writes mesh to file using default registry, same as `MeshIo::default().write_to_file(mesh, path)`.
+/// Format is defined by file extension.
+///
+pub fn write_mesh<TMesh>(mesh: &TMesh, path: &Path) -> Result<(), MeshIoError>
+where
+    TMesh: VertexProperties + 'static,
+    TMesh::ScalarType: SupersetOf<f32> + SupersetOf<f64>
+{
+    return MeshIo::default().write_to_file(mesh, path);
+}
+
+#[inline]
+fn extension(path: &Path) -> Option<String> {
+    return path.extension()
+        .and_then(|extension| extension.to_str())
+        .map(str::to_lowercase);
+}
+
+#[cfg(test)]
+mod tests {
+    use std::{io::{self, Read, Write}, env, fs, process};
+    use test_case::test_case;
+
+    use crate::{
+        mesh::{corner_table::{prelude::CornerTableF, test_helpers::create_unit_cross_square_mesh}, traits::Mesh},
+        io::{traits::MeshReader, MeshIoError}
+    };
+
+    use super::{MeshIo, read_mesh, write_mesh};
+
+    struct EmptyMeshReader;
+
+    impl MeshReader<CornerTableF> for EmptyMeshReader {
+        fn supports_extension(&self, extension: &str) -> bool {
+            return extension == "empty";
+        }
+
+        fn can_read(&self, header: &[u8]) -> bool {
+            return header.starts_with(b"EMPTY");
+        }
+
+        fn read(&mut self, reader: &mut dyn Read) -> Result<CornerTableF, MeshIoError> {
+            io::copy(reader, &mut io::sink())?;
+            return Ok(CornerTableF::new());
+        }
+    }
+
+    #[test_case("stl"; "STL")]
+    #[test_case("obj"; "OBJ")]
+    #[test_case("ply"; "PLY")]
+    #[cfg_attr(feature = "gltf", test_case("glb"; "GLB"))]
+    fn detect_format_by_content(extension: &str) {
+        let mesh = create_unit_cross_square_mesh();
+        let mut mesh_io = MeshIo::new();
+
+        let mut data = Vec::new();
+        mesh_io.write(&mesh, &mut data, extension).expect("Write mesh");
+        data.flush().unwrap();
+
+        let read_back = mesh_io.read(&mut data.as_slice(), None).expect("Read mesh");
+
+        assert_eq!(read_back.faces().count(), 4);
+        assert_eq!(read_back.vertices().count(), 5);
+    }
+
+    #[test]
+    fn custom_reader() {
+        let mut mesh_io = MeshIo::<CornerTableF>::new();
+        mesh_io.register_reader(EmptyMeshReader);
+
+        let by_content = mesh_io.read(&mut b"EMPTY mesh".as_slice(), None).expect("Read by content");
+        let by_extension = mesh_io.read(&mut b"".as_slice(), Some("EMPTY")).expect("Read by extension");
+
+        assert_eq!(by_content.faces().count(), 0);
+        assert_eq!(by_extension.faces().count(), 0);
+        assert!(MeshIo::<CornerTableF>::empty().read(&mut b"EMPTY".as_slice(), None).is_err());
+    }
+
+    #[test]
+    fn read_and_write_file() {
+        let mesh = create_unit_cross_square_mesh();
+        let path = env::temp_dir().join(format!("baby_shark_mesh_io_{}.ply", process::id()));
+
+        write_mesh(&mesh, &path).expect("Write mesh");
+        let read_back: Result<CornerTableF, _> = read_mesh(&path);
+        fs::remove_file(&path).unwrap();
+
+        assert_eq!(read_back.expect("Read mesh").faces().count(), 4);
+    }
+
+    #[test]
+    fn unknown_content() {
+        let mut data = vec![0xAB; 1000];
+        data[80..84].copy_from_slice(&3u32.to_le_bytes());
+
+        assert!(matches!(MeshIo::<CornerTableF>::new().read(&mut data.as_slice(), None), Err(MeshIoError::UnsupportedFormat(_))));
+
+        // Length matches triangle count, so data is recognized as binary STL
+        assert!(MeshIo::<CornerTableF>::new().read(&mut &data[..84 + 3 * 50], None).is_ok());
+    }
+
+    #[test]
+    fn unknown_writer() {
+        let mesh = create_unit_cross_square_mesh();
+        assert!(MeshIo::new().write(&mesh, &mut Vec::new(), "xyz").is_err());
+    }
+}
@@ -1 +1,13 @@
+pub mod error;
+pub mod traits;
+pub mod mesh_io;
 pub mod stl;
+pub mod obj;
+pub mod ply;
+#[cfg(feature = "gltf")]
+pub mod gltf;
+pub mod edgebreaker;
+pub mod vtk;
+
+pub use error::MeshIoError;
+pub use mesh_io::{read_mesh, write_mesh};
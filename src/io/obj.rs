@@ -0,0 +1,634 @@
+use std::{
+    io::{Read, BufReader, BufRead, Write, BufWriter},
+    fs::{OpenOptions, File}, path::Path, ops::Range, collections::HashMap
+};
+use nalgebra::{Point2, Point3, Vector3};
+use simba::scalar::SupersetOf;
+
+use crate::{mesh::traits::Mesh, algo::orientation::{orient_faces, FaceOrientation}};
+
+use super::{traits::{MeshReader, MeshWriter}, error::{MeshIoError, build_mesh_with_duplicates, remove_degenerate_faces, remove_face_data, text_error}};
+
+///
+/// Named part of OBJ file (`o` and `g` statements).
+/// Faces are given as range of triangles in the order they were passed to [Mesh::from_vertices_and_indices].
+///
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ObjGroup {
+    /// Name of current object (`o`), empty when not set
+    pub object: String,
+    /// Name of current group (`g`), empty when not set
+    pub group: String,
+    /// Triangles of this group
+    pub faces: Range<usize>
+}
+
+///
+/// Wavefront OBJ reader.
+/// Faces with more than three vertices are triangulated as a fan on load.
+/// Vertex indexing of file is preserved, vertices are not merged. Non-manifold vertices are split by mesh builder,
+/// their copies are appended after vertices of file and have no data of their own, see [ObjReader::duplicated_vertices].
+/// Winding of faces can be repaired before mesh is built, see [ObjReader::with_orientation].
+///
+pub struct ObjReader {
+    positions: Vec<Point3<f64>>,
+    normals: Vec<Vector3<f32>>,
+    texture_coordinates: Vec<Point2<f32>>,
+    indices: Vec<usize>,
+    corner_normals: Vec<Option<usize>>,
+    corner_texture_coordinates: Vec<Option<usize>>,
+    groups: Vec<ObjGroup>,
+    duplicated_vertices: Vec<(usize, usize)>,
+    orientation: FaceOrientation,
+    strict_topology: bool,
+
+    // Buffer for face parsing
+    face: Vec<(usize, Option<usize>, Option<usize>)>
+}
+
+impl ObjReader {
+    pub fn new() -> Self {
+        return Self {
+            positions: Vec::new(),
+            normals: Vec::new(),
+            texture_coordinates: Vec::new(),
+            indices: Vec::new(),
+            corner_normals: Vec::new(),
+            corner_texture_coordinates: Vec::new(),
+            groups: Vec::new(),
+            duplicated_vertices: Vec::new(),
+            orientation: FaceOrientation::default(),
+            strict_topology: false,
+            face: Vec::new()
+        };
+    }
+
+    ///
+    /// Set orientation repair of read faces. Default is [FaceOrientation::Keep].
+    /// Inconsistently wound faces are otherwise split by boundaries or, with [ObjReader::with_strict_topology], reported as [MeshIoError::NonManifoldFace].
+    ///
+    #[inline]
+    pub fn with_orientation(mut self, orientation: FaceOrientation) -> Self {
+        self.orientation = orientation;
+        return self;
+    }
+
+    ///
+    /// Set whether faces sharing directed edge with another face and faces referencing same vertex more than once
+    /// are rejected as [MeshIoError::NonManifoldFace] and [MeshIoError::DegenerateFace].
+    /// By default mesh is split along non-manifold edges and vertices and degenerate faces are skipped.
+    ///
+    #[inline]
+    pub fn with_strict_topology(mut self, strict_topology: bool) -> Self {
+        self.strict_topology = strict_topology;
+        return self;
+    }
+
+    /// Reads mesh from file
+    pub fn read_obj_from_file<TMesh>(&mut self, filepath: &Path) -> Result<TMesh, MeshIoError>
+    where
+        TMesh: Mesh,
+        TMesh::ScalarType: SupersetOf<f64>
+    {
+        let file = OpenOptions::new().read(true).open(filepath)?;
+        let mut reader = BufReader::new(file);
+
+        return self.read_obj::<File, TMesh>(&mut reader);
+    }
+
+    /// Reads mesh from buffer
+    pub fn read_obj<TBuffer, TMesh>(&mut self, reader: &mut BufReader<TBuffer>) -> Result<TMesh, MeshIoError>
+    where
+        TBuffer: Read,
+        TMesh: Mesh,
+        TMesh::ScalarType: SupersetOf<f64>
+    {
+        self.clear();
+
+        let mut object = String::new();
+        let mut group = String::new();
+        let mut line = String::new();
+        let mut line_number = 0;
+
+        loop {
+            line.clear();
+            if reader.read_line(&mut line).map_err(|err| text_error(err, "OBJ"))? == 0 {
+                break;
+            }
+
+            line_number += 1;
+
+            let mut tokens = line.split_whitespace();
+            let keyword = match tokens.next() {
+                Some(keyword) => keyword,
+                None => continue,
+            };
+
+            match keyword {
+                "v" => {
+                    let [x, y, z] = parse_numbers::<_, f64, 3>(&mut tokens, line_number)?;
+                    self.positions.push(Point3::new(x, y, z));
+                },
+                "vn" => {
+                    let [x, y, z] = parse_numbers::<_, f32, 3>(&mut tokens, line_number)?;
+                    self.normals.push(Vector3::new(x, y, z));
+                },
+                "vt" => {
+                    // `v` is optional and defaults to zero, optional `w` is ignored
+                    let [u] = parse_numbers::<_, f32, 1>(&mut tokens, line_number)?;
+                    let v = match tokens.next() {
+                        Some(token) => token.parse().map_err(|_| obj_error(&format!("invalid texture coordinate `{}`", token), line_number))?,
+                        None => 0.0
+                    };
+
+                    self.texture_coordinates.push(Point2::new(u, v));
+                },
+                "f" => self.read_face(&mut tokens, line_number)?,
+                "o" => {
+                    self.end_group();
+                    object = tokens.collect::<Vec<_>>().join(" ");
+                    self.begin_group(&object, &group);
+                },
+                "g" => {
+                    self.end_group();
+                    group = tokens.collect::<Vec<_>>().join(" ");
+                    self.begin_group(&object, &group);
+                },
+                // Materials, smoothing groups, lines etc. are not supported
+                _ => continue
+            }
+        }
+
+        self.end_group();
+
+        // Degenerate faces are skipped by mesh builder, so data attached to them is dropped too
+        if !self.strict_topology {
+            self.remove_degenerate_faces();
+        }
+
+        // Cast points to scalar type used by mesh
+        let vertices: Vec<_> = self.positions
+            .iter()
+            .map(|point| point.cast::<TMesh::ScalarType>())
+            .collect();
+
+        // Corner attributes follow vertices of flipped faces. Missing vertices are reported by mesh builder.
+        if self.indices.iter().all(|index| *index < vertices.len()) {
+            for face in orient_faces(&vertices, &mut self.indices, self.orientation) {
+                self.corner_normals.swap(face * 3 + 1, face * 3 + 2);
+                self.corner_texture_coordinates.swap(face * 3 + 1, face * 3 + 2);
+            }
+        }
+
+        let (mesh, duplicated_vertices) = build_mesh_with_duplicates(&vertices, &self.indices, self.strict_topology)?;
+        self.duplicated_vertices = duplicated_vertices;
+
+        return Ok(mesh);
+    }
+
+    /// Returns parts of last read file. Faces that are not preceded by `o` or `g` statement are reported as unnamed group.
+    #[inline]
+    pub fn groups(&self) -> &[ObjGroup] {
+        return &self.groups;
+    }
+
+    /// Returns normals (`vn`) of last read file
+    #[inline]
+    pub fn normals(&self) -> &[Vector3<f32>] {
+        return &self.normals;
+    }
+
+    /// Returns texture coordinates (`vt`) of last read file
+    #[inline]
+    pub fn texture_coordinates(&self) -> &[Point2<f32>] {
+        return &self.texture_coordinates;
+    }
+
+    /// Returns index of normal for each corner of each triangle (three per triangle)
+    #[inline]
+    pub fn corner_normals(&self) -> &[Option<usize>] {
+        return &self.corner_normals;
+    }
+
+    /// Returns index of texture coordinate for each corner of each triangle (three per triangle)
+    #[inline]
+    pub fn corner_texture_coordinates(&self) -> &[Option<usize>] {
+        return &self.corner_texture_coordinates;
+    }
+
+    ///
+    /// Returns `(original, duplicate)` pairs of vertex indices of last read mesh.
+    /// Duplicates are added by mesh builder to split non-manifold vertices and are appended after vertices of file.
+    ///
+    #[inline]
+    pub fn duplicated_vertices(&self) -> &[(usize, usize)] {
+        return &self.duplicated_vertices;
+    }
+
+    fn clear(&mut self) {
+        self.positions.clear();
+        self.normals.clear();
+        self.texture_coordinates.clear();
+        self.indices.clear();
+        self.corner_normals.clear();
+        self.corner_texture_coordinates.clear();
+        self.groups.clear();
+        self.duplicated_vertices.clear();
+        self.begin_group("", "");
+    }
+
+    /// Parses face and triangulates it as a fan around first vertex
+    fn read_face<'a, TTokens: Iterator<Item = &'a str>>(&mut self, tokens: &mut TTokens, line_number: usize) -> Result<(), MeshIoError> {
+        self.face.clear();
+
+        for token in tokens {
+            let mut parts = token.split('/');
+            let vertex = parts.next().unwrap_or_default();
+            let texture_coordinate = parts.next().unwrap_or_default();
+            let normal = parts.next().unwrap_or_default();
+
+            let vertex = resolve_index(vertex, self.positions.len(), line_number)?
+                .ok_or_else(|| obj_error("face vertex without position index", line_number))?;
+            let texture_coordinate = resolve_index(texture_coordinate, self.texture_coordinates.len(), line_number)?;
+            let normal = resolve_index(normal, self.normals.len(), line_number)?;
+
+            self.face.push((vertex, texture_coordinate, normal));
+        }
+
+        if self.face.len() < 3 {
+            return Err(obj_error("face has less than 3 vertices", line_number));
+        }
+
+        for i in 1..self.face.len() - 1 {
+            for corner in [self.face[0], self.face[i], self.face[i + 1]] {
+                let (vertex, texture_coordinate, normal) = corner;
+                self.indices.push(vertex);
+                self.corner_texture_coordinates.push(texture_coordinate);
+                self.corner_normals.push(normal);
+            }
+        }
+
+        return Ok(());
+    }
+
+    #[inline]
+    fn faces_count(&self) -> usize {
+        return self.indices.len() / 3;
+    }
+
+    fn begin_group(&mut self, object: &str, group: &str) {
+        let first_face = self.faces_count();
+        self.groups.push(ObjGroup {
+            object: object.to_string(),
+            group: group.to_string(),
+            faces: first_face..first_face
+        });
+    }
+
+    fn remove_degenerate_faces(&mut self) {
+        let removed = remove_degenerate_faces(&mut self.indices);
+        if removed.is_empty() {
+            return;
+        }
+
+        remove_face_data(&mut self.corner_normals, &removed, 3);
+        remove_face_data(&mut self.corner_texture_coordinates, &removed, 3);
+
+        // Shift group ranges by number of removed faces before them
+        let shift = |face: usize| face - removed.partition_point(|removed| *removed < face);
+
+        for group in &mut self.groups {
+            group.faces = shift(group.faces.start)..shift(group.faces.end);
+        }
+
+        self.groups.retain(|group| !group.faces.is_empty());
+    }
+
+    /// Finishes current group. Groups without faces are removed.
+    fn end_group(&mut self) {
+        let faces_count = self.faces_count();
+
+        if let Some(group) = self.groups.last_mut() {
+            group.faces.end = faces_count;
+
+            if group.faces.is_empty() {
+                self.groups.pop();
+            }
+        }
+    }
+}
+
+impl Default for ObjReader {
+    #[inline]
+    fn default() -> Self {
+        return Self::new();
+    }
+}
+
+impl<TMesh> MeshReader<TMesh> for ObjReader
+where
+    TMesh: Mesh,
+    TMesh::ScalarType: SupersetOf<f64>
+{
+    #[inline]
+    fn supports_extension(&self, extension: &str) -> bool {
+        return extension == "obj";
+    }
+
+    /// Checks that first statement of text is known OBJ statement
+    fn can_read(&self, header: &[u8]) -> bool {
+        let text = String::from_utf8_lossy(header);
+        let keyword = text.lines()
+            .map(str::trim)
+            .find(|line| !line.is_empty() && !line.starts_with('#'))
+            .and_then(|line| line.split_whitespace().next());
+
+        return matches!(keyword, Some("v" | "vn" | "vt" | "f" | "o" | "g" | "s" | "l" | "p" | "mtllib" | "usemtl"));
+    }
+
+    #[inline]
+    fn read(&mut self, reader: &mut dyn Read) -> Result<TMesh, MeshIoError> {
+        return self.read_obj(&mut BufReader::new(reader));
+    }
+}
+
+#[inline]
+fn obj_error(message: &str, line_number: usize) -> MeshIoError {
+    return MeshIoError::InvalidData(format!("OBJ, line {}: {}", line_number, message));
+}
+
+fn parse_numbers<'a, TTokens, TNumber, const N: usize>(tokens: &mut TTokens, line_number: usize) -> Result<[TNumber; N], MeshIoError>
+where
+    TTokens: Iterator<Item = &'a str>,
+    TNumber: std::str::FromStr + Default + Copy
+{
+    let mut numbers = [TNumber::default(); N];
+
+    for number in numbers.iter_mut() {
+        *number = tokens.next()
+            .and_then(|token| token.parse::<TNumber>().ok())
+            .ok_or_else(|| obj_error(&format!("expected {} numbers", N), line_number))?;
+    }
+
+    return Ok(numbers);
+}
+
+///
+/// Converts OBJ index (1-based, or negative relative to the end of list) to 0-based index.
+/// Returns `None` for empty index.
+///
+fn resolve_index(index: &str, count: usize, line_number: usize) -> Result<Option<usize>, MeshIoError> {
+    if index.is_empty() {
+        return Ok(None);
+    }
+
+    let index: isize = index.parse().map_err(|_| obj_error(&format!("invalid index `{}`", index), line_number))?;
+
+    let resolved = match index {
+        i if i > 0 => i as usize - 1,
+        i if i < 0 && i.unsigned_abs() <= count => count - i.unsigned_abs(),
+        _ => return Err(obj_error(&format!("index {} is out of range", index), line_number)),
+    };
+
+    if resolved >= count {
+        return Err(obj_error(&format!("index {} is out of range", index), line_number));
+    }
+
+    return Ok(Some(resolved));
+}
+
+///
+/// Wavefront OBJ writer. Each mesh vertex is written once and referenced by faces, deleted elements are skipped.
+///
+pub struct ObjWriter {
+    write_normals: bool
+}
+
+impl ObjWriter {
+    pub fn new() -> Self {
+        return Self {
+            write_normals: false
+        };
+    }
+
+    /// Set flag indicating whether vertex normals should be written. Default is `false`
+    #[inline]
+    pub fn with_normals(mut self, write_normals: bool) -> Self {
+        self.write_normals = write_normals;
+        return self;
+    }
+
+    pub fn write_obj_to_file<TMesh: Mesh>(&self, mesh: &TMesh, path: &Path) -> Result<(), MeshIoError> {
+        let file = OpenOptions::new()
+            .write(true)
+            .truncate(true)
+            .create(true)
+            .open(path)?;
+        let mut writer = BufWriter::new(file);
+
+        self.write_obj(mesh, &mut writer)?;
+        writer.flush()?;
+
+        return Ok(());
+    }
+
+    pub fn write_obj<TBuffer, TMesh>(&self, mesh: &TMesh, writer: &mut BufWriter<TBuffer>) -> Result<(), MeshIoError>
+    where
+        TBuffer: Write,
+        TMesh: Mesh
+    {
+        let mut vertex_index_map = HashMap::new();
+
+        for vertex in mesh.vertices() {
+            vertex_index_map.insert(vertex, vertex_index_map.len() + 1);
+
+            let position = mesh.vertex_position(&vertex);
+            writeln!(writer, "v {} {} {}", position.x, position.y, position.z)?;
+        }
+
+        if self.write_normals {
+            for vertex in mesh.vertices() {
+                let normal = mesh.vertex_normal(&vertex).unwrap_or_else(Vector3::zeros);
+                writeln!(writer, "vn {} {} {}", normal.x, normal.y, normal.z)?;
+            }
+        }
+
+        for face in mesh.faces() {
+            let (v1, v2, v3) = mesh.face_vertices(&face);
+            let i1 = vertex_index_map[&v1];
+            let i2 = vertex_index_map[&v2];
+            let i3 = vertex_index_map[&v3];
+
+            if self.write_normals {
+                writeln!(writer, "f {0}//{0} {1}//{1} {2}//{2}", i1, i2, i3)?;
+            } else {
+                writeln!(writer, "f {} {} {}", i1, i2, i3)?;
+            }
+        }
+
+        return Ok(());
+    }
+}
+
+impl Default for ObjWriter {
+    #[inline]
+    fn default() -> Self {
+        return Self::new();
+    }
+}
+
+impl<TMesh: Mesh> MeshWriter<TMesh> for ObjWriter {
+    #[inline]
+    fn supports_extension(&self, extension: &str) -> bool {
+        return extension == "obj";
+    }
+
+    fn write(&self, mesh: &TMesh, writer: &mut dyn Write) -> Result<(), MeshIoError> {
+        let mut writer = BufWriter::new(writer);
+        self.write_obj(mesh, &mut writer)?;
+        writer.flush()?;
+
+        return Ok(());
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::io::{BufReader, BufWriter};
+    use nalgebra::Point2;
+
+    use crate::{
+        mesh::{corner_table::{prelude::CornerTableD, descriptors::{VertexId, FaceId}}, traits::{Mesh, TopologicalMesh}},
+        io::MeshIoError,
+        algo::orientation::FaceOrientation
+    };
+
+    use super::{ObjReader, ObjWriter, ObjGroup};
+
+    const TWO_PARTS: &str = "# two parts
+v 0 0 0
+v 1 0 0
+v 1 1 0
+v 0 1 0
+vt 0 0
+vt 1 0
+vt 1 1
+vn 0 0 1
+o first
+f 1/1/1 2/2/1 3/3/1 4//1
+v 5 0 0
+v 6 0 0
+v 6 1 0
+o second
+g part
+f -3 -2 -1
+";
+
+    fn read(data: &str) -> (ObjReader, Result<CornerTableD, MeshIoError>) {
+        let mut reader = ObjReader::new();
+        let mesh = reader.read_obj(&mut BufReader::new(data.as_bytes()));
+        return (reader, mesh);
+    }
+
+    #[test]
+    fn read_obj() {
+        let (reader, mesh) = read(TWO_PARTS);
+        let mesh = mesh.expect("Read OBJ");
+
+        assert_eq!(mesh.vertices().count(), 7);
+        assert_eq!(mesh.faces().count(), 3);
+        assert_eq!(mesh.face_vertices(&FaceId::new(2)), (VertexId::new(4), VertexId::new(5), VertexId::new(6)));
+
+        assert_eq!(reader.groups(), &[
+            ObjGroup { object: "first".into(), group: "".into(), faces: 0..2 },
+            ObjGroup { object: "second".into(), group: "part".into(), faces: 2..3 },
+        ]);
+
+        assert_eq!(reader.corner_texture_coordinates()[..6], [Some(0), Some(1), Some(2), Some(0), Some(2), None]);
+        assert_eq!(reader.corner_normals()[..6], [Some(0); 6]);
+        assert_eq!(reader.corner_normals()[6..], [None; 3]);
+    }
+
+    #[test]
+    fn read_invalid_obj() {
+        assert!(read("v 0 0 0\nv 1 0 0\nf 1 2 3\n").1.is_err());
+        assert!(read("v 0 0 0\nv 1 0 0\nv 1 1 0\nf 1 2\n").1.is_err());
+        assert!(read("v 0 0 0\nv 1 0 0\nv 1 1 0\nf 1 2 -4\n").1.is_err());
+        assert!(read("v 0 0\n").1.is_err());
+        assert!(read("vt 0 x\n").1.is_err());
+        assert!(read("vt\n").1.is_err());
+    }
+
+    #[test]
+    fn read_texture_coordinates() {
+        let (reader, mesh) = read("v 0 0 0\nv 1 0 0\nv 1 1 0\nvt 0.5\nvt 0.25 0.75\nvt 1 1 0.5\nf 1/1 2/2 3/3\n");
+        mesh.expect("Read OBJ");
+
+        assert_eq!(reader.texture_coordinates(), &[Point2::new(0.5, 0.0), Point2::new(0.25, 0.75), Point2::new(1.0, 1.0)]);
+    }
+
+    #[test]
+    fn read_invalid_topology() {
+        // Degenerate faces are skipped together with their corner data and groups
+        let data = "v 0 0 0\nv 1 0 0\nv 1 1 0\nvt 0 0\ng skipped\nf 1 1/1 2\ng kept\nf 1 2 3/1\n";
+        let (reader, mesh) = read(data);
+        assert_eq!(mesh.expect("Read OBJ").faces().count(), 1);
+        assert_eq!(reader.corner_texture_coordinates(), &[None, None, Some(0)]);
+        assert_eq!(reader.groups(), &[ObjGroup { object: "".into(), group: "kept".into(), faces: 0..1 }]);
+
+        let mut reader = ObjReader::new().with_strict_topology(true);
+        let degenerate = reader.read_obj::<_, CornerTableD>(&mut BufReader::new(data.as_bytes()));
+        assert!(matches!(degenerate, Err(MeshIoError::DegenerateFace { face: 0 })));
+
+        // Faces sharing directed edge are kept and split by boundary unless strict topology is requested
+        let data = "v 0 0 0\nv 1 0 0\nv 1 1 0\nv 0 1 0\nf 1 2 3\nf 1 2 4\n";
+        let mesh = read(data).1.expect("Read OBJ");
+        assert_eq!(mesh.faces().count(), 2);
+        assert_eq!(mesh.edges().filter(|edge| mesh.is_edge_on_boundary(edge)).count(), 6);
+
+        let mut reader = ObjReader::new().with_strict_topology(true);
+        let non_manifold = reader.read_obj::<_, CornerTableD>(&mut BufReader::new(data.as_bytes()));
+        assert!(matches!(non_manifold, Err(MeshIoError::NonManifoldFace { face: 1 })));
+
+        let not_finite = read("v 0 0 0\nv 1 inf 0\nv 1 1 0\nf 1 2 3\n").1;
+        assert!(matches!(not_finite, Err(MeshIoError::NonFiniteCoordinate { vertex: 1 })));
+    }
+
+    #[test]
+    fn read_duplicated_vertices() {
+        // Vertex 1 joins two fans and is split, its copy goes after vertices of file
+        let data = "v 0 0 0\nv 1 0 0\nv 0 1 0\nv -1 0 0\nv 0 -1 0\nf 1 2 3\nf 1 4 5\n";
+        let (reader, mesh) = read(data);
+        let mesh = mesh.expect("Read OBJ");
+        assert_eq!(mesh.vertices().count(), 6);
+        assert_eq!(reader.duplicated_vertices(), &[(0, 5)]);
+    }
+
+    #[test]
+    fn repair_orientation() {
+        let data = "v 0 0 0\nv 1 0 0\nv 1 1 0\nv 0 -1 0\nvt 0 0\nvt 1 0\nf 1 2 3\nf 1/1 2/2 4\n";
+        let mut reader = ObjReader::new().with_orientation(FaceOrientation::Consistent);
+        let mesh: CornerTableD = reader.read_obj(&mut BufReader::new(data.as_bytes())).expect("Read OBJ");
+
+        assert_eq!(mesh.face_vertices(&FaceId::new(1)), (VertexId::new(0), VertexId::new(3), VertexId::new(1)));
+        assert_eq!(reader.corner_texture_coordinates()[3..], [Some(0), None, Some(1)]);
+        assert_eq!(mesh.edges().filter(|edge| mesh.is_edge_on_boundary(edge)).count(), 4);
+    }
+
+    #[test]
+    fn write_obj() {
+        let (_, mesh) = read(TWO_PARTS);
+        let mesh = mesh.unwrap();
+
+        let mut writer = BufWriter::new(Vec::new());
+        ObjWriter::new().write_obj(&mesh, &mut writer).expect("Write OBJ");
+        let data = String::from_utf8(writer.into_inner().unwrap()).unwrap();
+
+        let (_, read_back) = read(&data);
+        let read_back = read_back.expect("Read written OBJ");
+
+        assert_eq!(read_back.vertices().count(), 7);
+        assert_eq!(read_back.faces().count(), 3);
+        assert_eq!(read_back.face_vertices(&FaceId::new(2)), (VertexId::new(4), VertexId::new(5), VertexId::new(6)));
+    }
+}
@@ -0,0 +1,800 @@
+use std::{
+    io::{Read, BufReader, BufRead, self, Write, BufWriter},
+    fs::{OpenOptions, File}, path::Path, collections::HashMap
+};
+use nalgebra::Point3;
+use num_traits::ToPrimitive;
+use simba::scalar::SupersetOf;
+
+use crate::mesh::traits::{Mesh, VertexProperties};
+
+use super::{traits::{MeshReader, MeshWriter}, error::{MeshIoError, OffsetReader, build_mesh_with_duplicates, text_error}};
+
+/// Maximal number of elements memory is reserved for before reading them, element counts of header are not trusted
+const PLY_MAX_RESERVED_ELEMENTS: usize = 1 << 16;
+
+/// Encoding of PLY file body
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum PlyFormat {
+    Ascii,
+    BinaryLittleEndian,
+    BinaryBigEndian
+}
+
+impl Default for PlyFormat {
+    #[inline]
+    fn default() -> Self {
+        return PlyFormat::BinaryLittleEndian;
+    }
+}
+
+/// Scalar type of PLY property
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum PlyScalarType {
+    Char,
+    UChar,
+    Short,
+    UShort,
+    Int,
+    UInt,
+    Float,
+    Double
+}
+
+impl PlyScalarType {
+    fn parse(name: &str) -> Option<Self> {
+        let scalar_type = match name {
+            "char" | "int8" => PlyScalarType::Char,
+            "uchar" | "uint8" => PlyScalarType::UChar,
+            "short" | "int16" => PlyScalarType::Short,
+            "ushort" | "uint16" => PlyScalarType::UShort,
+            "int" | "int32" => PlyScalarType::Int,
+            "uint" | "uint32" => PlyScalarType::UInt,
+            "float" | "float32" => PlyScalarType::Float,
+            "double" | "float64" => PlyScalarType::Double,
+            _ => return None
+        };
+
+        return Some(scalar_type);
+    }
+
+    fn name(&self) -> &'static str {
+        match self {
+            PlyScalarType::Char => return "char",
+            PlyScalarType::UChar => return "uchar",
+            PlyScalarType::Short => return "short",
+            PlyScalarType::UShort => return "ushort",
+            PlyScalarType::Int => return "int",
+            PlyScalarType::UInt => return "uint",
+            PlyScalarType::Float => return "float",
+            PlyScalarType::Double => return "double",
+        }
+    }
+
+    fn size(&self) -> usize {
+        match self {
+            PlyScalarType::Char | PlyScalarType::UChar => return 1,
+            PlyScalarType::Short | PlyScalarType::UShort => return 2,
+            PlyScalarType::Int | PlyScalarType::UInt | PlyScalarType::Float => return 4,
+            PlyScalarType::Double => return 8,
+        }
+    }
+
+    #[inline]
+    fn is_integer(&self) -> bool {
+        return !matches!(self, PlyScalarType::Float | PlyScalarType::Double);
+    }
+}
+
+/// Property of PLY element as declared in header
+enum PlyPropertyKind {
+    Scalar(PlyScalarType),
+    List(PlyScalarType, PlyScalarType)
+}
+
+struct PlyPropertyDefinition {
+    name: String,
+    kind: PlyPropertyKind
+}
+
+struct PlyElementDefinition {
+    name: String,
+    count: usize,
+    properties: Vec<PlyPropertyDefinition>
+}
+
+/// Values of per-vertex property that is not a position
+struct PlyVertexProperty {
+    name: String,
+    scalar_type: PlyScalarType,
+    values: Vec<f64>
+}
+
+///
+/// PLY reader. Supports ASCII and binary (little and big endian) encodings.
+///
+/// Faces are triangulated as a fan on load, file without faces is read as a point set (mesh with isolated vertices).
+/// Vertex properties other than position (colors, normals, confidence etc.) can be retrieved as vertex property maps
+/// using [PlyReader::vertex_property] after reading.
+/// Elements other than `vertex` and `face` are skipped.
+///
+pub struct PlyReader {
+    format: PlyFormat,
+    elements: Vec<PlyElementDefinition>,
+    positions: Vec<Point3<f64>>,
+    indices: Vec<usize>,
+    vertex_properties: Vec<PlyVertexProperty>,
+    duplicated_vertices: Vec<(usize, usize)>,
+    strict_topology: bool,
+
+    // Buffer for face parsing
+    face: Vec<usize>
+}
+
+impl PlyReader {
+    pub fn new() -> Self {
+        return Self {
+            format: PlyFormat::default(),
+            elements: Vec::new(),
+            positions: Vec::new(),
+            indices: Vec::new(),
+            vertex_properties: Vec::new(),
+            duplicated_vertices: Vec::new(),
+            strict_topology: false,
+            face: Vec::new()
+        };
+    }
+
+    ///
+    /// Set whether faces sharing directed edge with another face and faces referencing same vertex more than once
+    /// are rejected as [MeshIoError::NonManifoldFace] and [MeshIoError::DegenerateFace].
+    /// By default mesh is split along non-manifold edges and vertices and degenerate faces are skipped.
+    ///
+    #[inline]
+    pub fn with_strict_topology(mut self, strict_topology: bool) -> Self {
+        self.strict_topology = strict_topology;
+        return self;
+    }
+
+    /// Reads mesh from file
+    pub fn read_ply_from_file<TMesh>(&mut self, filepath: &Path) -> Result<TMesh, MeshIoError>
+    where
+        TMesh: Mesh,
+        TMesh::ScalarType: SupersetOf<f64>
+    {
+        let file = OpenOptions::new().read(true).open(filepath)?;
+        let mut reader = BufReader::new(file);
+
+        return self.read_ply::<File, TMesh>(&mut reader);
+    }
+
+    /// Reads mesh from buffer
+    pub fn read_ply<TBuffer, TMesh>(&mut self, reader: &mut BufReader<TBuffer>) -> Result<TMesh, MeshIoError>
+    where
+        TBuffer: Read,
+        TMesh: Mesh,
+        TMesh::ScalarType: SupersetOf<f64>
+    {
+        self.positions.clear();
+        self.indices.clear();
+        self.vertex_properties.clear();
+        self.duplicated_vertices.clear();
+
+        let mut reader = OffsetReader::new(reader, 0);
+        self.read_header(&mut reader)?;
+
+        let elements = std::mem::take(&mut self.elements);
+        let result = elements.iter().try_for_each(|element| self.read_element(&mut reader, element));
+        self.elements = elements;
+        result?;
+
+        let vertices: Vec<_> = self.positions
+            .iter()
+            .map(|point| point.cast::<TMesh::ScalarType>())
+            .collect();
+
+        let (mesh, duplicated_vertices) = build_mesh_with_duplicates(&vertices, &self.indices, self.strict_topology)?;
+        self.duplicated_vertices = duplicated_vertices;
+
+        return Ok(mesh);
+    }
+
+    /// Returns names and types of per-vertex properties (except position) of last read file
+    pub fn vertex_properties(&self) -> impl Iterator<Item = (&str, PlyScalarType)> {
+        return self.vertex_properties.iter().map(|property| (property.name.as_str(), property.scalar_type));
+    }
+
+    ///
+    /// Returns property map filled with values of given vertex property of last read file, `None` if there is no such property.
+    /// `mesh` should be the one returned by last read, vertices of it are expected to be in file order.
+    /// Vertices split to keep mesh manifold get value of vertex they were copied from.
+    ///
+    pub fn vertex_property<TMesh: VertexProperties>(&self, mesh: &TMesh, name: &str) -> Option<TMesh::VertexPropertyMap<f64>> {
+        let property = self.vertex_properties.iter().find(|property| property.name == name)?;
+        let mut map = mesh.create_vertex_properties_map();
+        let vertices: Vec<_> = mesh.vertices().collect();
+
+        for (vertex, value) in vertices.iter().zip(property.values.iter()) {
+            map[*vertex] = *value;
+        }
+
+        for &(original, duplicate) in &self.duplicated_vertices {
+            if let (Some(vertex), Some(value)) = (vertices.get(duplicate), property.values.get(original)) {
+                map[*vertex] = *value;
+            }
+        }
+
+        return Some(map);
+    }
+
+    ///
+    /// Returns `(original, duplicate)` pairs of vertex indices of last read mesh.
+    /// Duplicates are added by mesh builder to split non-manifold vertices and are appended after vertices of file.
+    ///
+    #[inline]
+    pub fn duplicated_vertices(&self) -> &[(usize, usize)] {
+        return &self.duplicated_vertices;
+    }
+
+    fn read_header<TBuffer: BufRead>(&mut self, reader: &mut OffsetReader<TBuffer>) -> Result<(), MeshIoError> {
+        self.elements.clear();
+
+        let mut line = String::new();
+        reader.read_line(&mut line).map_err(|err| text_error(err, "PLY"))?;
+
+        if line.trim_end() != "ply" {
+            return Err(ply_error("missing `ply` magic number"));
+        }
+
+        let mut format = None;
+
+        loop {
+            line.clear();
+            if reader.read_line(&mut line).map_err(|err| text_error(err, "PLY"))? == 0 {
+                return Err(MeshIoError::TruncatedData { offset: reader.offset() });
+            }
+
+            let tokens: Vec<_> = line.split_whitespace().collect();
+
+            match tokens.as_slice() {
+                ["format", encoding, version] => {
+                    if *version != "1.0" {
+                        return Err(MeshIoError::UnsupportedEncoding(format!("PLY version `{}`", version)));
+                    }
+
+                    format = Some(match *encoding {
+                        "ascii" => PlyFormat::Ascii,
+                        "binary_little_endian" => PlyFormat::BinaryLittleEndian,
+                        "binary_big_endian" => PlyFormat::BinaryBigEndian,
+                        _ => return Err(MeshIoError::UnsupportedEncoding(format!("PLY format `{}`", encoding)))
+                    });
+                },
+                ["element", name, count] => {
+                    let count = count.parse().map_err(|_| ply_error(&format!("invalid count of `{}` elements", name)))?;
+                    self.elements.push(PlyElementDefinition {
+                        name: name.to_string(),
+                        count,
+                        properties: Vec::new()
+                    });
+                },
+                ["property", "list", count_type, item_type, name] => {
+                    let kind = PlyPropertyKind::List(parse_scalar_type(count_type)?, parse_scalar_type(item_type)?);
+                    self.push_property(name, kind)?;
+                },
+                ["property", scalar_type, name] => {
+                    let kind = PlyPropertyKind::Scalar(parse_scalar_type(scalar_type)?);
+                    self.push_property(name, kind)?;
+                },
+                ["end_header"] => break,
+                ["comment", ..] | ["obj_info", ..] | [] => continue,
+                _ => return Err(ply_error(&format!("invalid header line `{}`", line.trim_end())))
+            }
+        }
+
+        self.format = format.ok_or_else(|| ply_error("missing format"))?;
+
+        return Ok(());
+    }
+
+    fn push_property(&mut self, name: &str, kind: PlyPropertyKind) -> Result<(), MeshIoError> {
+        let element = self.elements.last_mut().ok_or_else(|| ply_error("property declared before element"))?;
+        element.properties.push(PlyPropertyDefinition { name: name.to_string(), kind });
+
+        return Ok(());
+    }
+
+    fn read_element<TBuffer: BufRead>(&mut self, reader: &mut OffsetReader<TBuffer>, element: &PlyElementDefinition) -> Result<(), MeshIoError> {
+        let is_vertex = element.name == "vertex";
+        let is_face = element.name == "face";
+
+        if is_vertex {
+            self.positions.reserve(element.count.min(PLY_MAX_RESERVED_ELEMENTS));
+
+            for property in &element.properties {
+                if let PlyPropertyKind::Scalar(scalar_type) = property.kind {
+                    if !matches!(property.name.as_str(), "x" | "y" | "z") {
+                        self.vertex_properties.push(PlyVertexProperty {
+                            name: property.name.clone(),
+                            scalar_type,
+                            values: Vec::with_capacity(element.count.min(PLY_MAX_RESERVED_ELEMENTS))
+                        });
+                    }
+                }
+            }
+        }
+
+        let mut line = String::new();
+        let mut extra_values = Vec::with_capacity(self.vertex_properties.len());
+
+        for _ in 0..element.count {
+            let mut tokens = PlyTokens::new(self.format);
+
+            if self.format == PlyFormat::Ascii {
+                line.clear();
+                if reader.read_line(&mut line).map_err(|err| text_error(err, "PLY"))? == 0 {
+                    return Err(MeshIoError::TruncatedData { offset: reader.offset() });
+                }
+
+                tokens.set_line(&line);
+            }
+
+            let mut position = Point3::origin();
+            extra_values.clear();
+            self.face.clear();
+
+            for property in &element.properties {
+                match property.kind {
+                    PlyPropertyKind::Scalar(scalar_type) => {
+                        let value = tokens.read_scalar(reader, scalar_type)?;
+
+                        if is_vertex {
+                            match property.name.as_str() {
+                                "x" => position.x = value,
+                                "y" => position.y = value,
+                                "z" => position.z = value,
+                                _ => extra_values.push(value)
+                            }
+                        }
+                    },
+                    PlyPropertyKind::List(count_type, item_type) => {
+                        let count = tokens.read_scalar(reader, count_type)?.to_usize()
+                            .ok_or_else(|| ply_error("invalid list size"))?;
+                        let is_face_indices = is_face && matches!(property.name.as_str(), "vertex_indices" | "vertex_index");
+
+                        for _ in 0..count {
+                            let item = tokens.read_scalar(reader, item_type)?;
+
+                            if is_face_indices {
+                                let index = item.to_usize().ok_or_else(|| ply_error("invalid face index"))?;
+                                self.face.push(index);
+                            }
+                        }
+                    },
+                }
+            }
+
+            if is_vertex {
+                self.positions.push(position);
+
+                for (property, value) in self.vertex_properties.iter_mut().zip(extra_values.iter()) {
+                    property.values.push(*value);
+                }
+            }
+
+            if is_face {
+                self.push_face()?;
+            }
+        }
+
+        return Ok(());
+    }
+
+    /// Triangulates face stored in buffer as a fan around first vertex
+    fn push_face(&mut self) -> Result<(), MeshIoError> {
+        if self.face.len() < 3 {
+            return Err(ply_error("face has less than 3 vertices"));
+        }
+
+        if self.face.iter().any(|index| *index >= self.positions.len()) {
+            return Err(ply_error("face index is out of range"));
+        }
+
+        for i in 1..self.face.len() - 1 {
+            self.indices.push(self.face[0]);
+            self.indices.push(self.face[i]);
+            self.indices.push(self.face[i + 1]);
+        }
+
+        return Ok(());
+    }
+}
+
+impl Default for PlyReader {
+    #[inline]
+    fn default() -> Self {
+        return Self::new();
+    }
+}
+
+impl<TMesh> MeshReader<TMesh> for PlyReader
+where
+    TMesh: Mesh,
+    TMesh::ScalarType: SupersetOf<f64>
+{
+    #[inline]
+    fn supports_extension(&self, extension: &str) -> bool {
+        return extension == "ply";
+    }
+
+    #[inline]
+    fn can_read(&self, header: &[u8]) -> bool {
+        return header.starts_with(b"ply");
+    }
+
+    #[inline]
+    fn read(&mut self, reader: &mut dyn Read) -> Result<TMesh, MeshIoError> {
+        return self.read_ply(&mut BufReader::new(reader));
+    }
+}
+
+/// Reads scalar values from ASCII line or binary stream
+struct PlyTokens<'a> {
+    format: PlyFormat,
+    tokens: Option<std::str::SplitWhitespace<'a>>,
+    buf: [u8; 8]
+}
+
+impl<'a> PlyTokens<'a> {
+    fn new(format: PlyFormat) -> Self {
+        return Self {
+            format,
+            tokens: None,
+            buf: [0; 8]
+        };
+    }
+
+    fn set_line(&mut self, line: &'a str) {
+        self.tokens = Some(line.split_whitespace());
+    }
+
+    fn read_scalar<TBuffer: BufRead>(&mut self, reader: &mut OffsetReader<TBuffer>, scalar_type: PlyScalarType) -> Result<f64, MeshIoError> {
+        if let Some(tokens) = self.tokens.as_mut() {
+            return tokens.next()
+                .and_then(|token| token.parse::<f64>().ok())
+                .ok_or_else(|| ply_error("expected number"));
+        }
+
+        let bytes = &mut self.buf[..scalar_type.size()];
+        reader.read_exact(bytes)?;
+
+        if self.format == PlyFormat::BinaryBigEndian {
+            bytes.reverse();
+        }
+
+        let b = &self.buf;
+        let value = match scalar_type {
+            PlyScalarType::Char => i8::from_le_bytes([b[0]]) as f64,
+            PlyScalarType::UChar => b[0] as f64,
+            PlyScalarType::Short => i16::from_le_bytes([b[0], b[1]]) as f64,
+            PlyScalarType::UShort => u16::from_le_bytes([b[0], b[1]]) as f64,
+            PlyScalarType::Int => i32::from_le_bytes([b[0], b[1], b[2], b[3]]) as f64,
+            PlyScalarType::UInt => u32::from_le_bytes([b[0], b[1], b[2], b[3]]) as f64,
+            PlyScalarType::Float => f32::from_le_bytes([b[0], b[1], b[2], b[3]]) as f64,
+            PlyScalarType::Double => f64::from_le_bytes(*b),
+        };
+
+        return Ok(value);
+    }
+}
+
+#[inline]
+fn ply_error(message: &str) -> MeshIoError {
+    return MeshIoError::InvalidData(format!("PLY: {}", message));
+}
+
+#[inline]
+fn parse_scalar_type(name: &str) -> Result<PlyScalarType, MeshIoError> {
+    return PlyScalarType::parse(name).ok_or_else(|| ply_error(&format!("unknown property type `{}`", name)));
+}
+
+///
+/// Per-vertex property written by [PlyWriter]
+///
+pub struct PlyProperty<'a, TMap> {
+    pub name: &'a str,
+    pub scalar_type: PlyScalarType,
+    pub map: &'a TMap
+}
+
+///
+/// PLY writer. Writes positions, triangles and given vertex property maps.
+/// Each mesh vertex is written once and referenced by faces, deleted elements are skipped.
+///
+pub struct PlyWriter {
+    format: PlyFormat
+}
+
+impl PlyWriter {
+    pub fn new() -> Self {
+        return Self {
+            format: PlyFormat::default()
+        };
+    }
+
+    /// Set encoding of written files. Default is [PlyFormat::BinaryLittleEndian]
+    #[inline]
+    pub fn with_format(mut self, format: PlyFormat) -> Self {
+        self.format = format;
+        return self;
+    }
+
+    pub fn write_ply_to_file<TMesh: VertexProperties>(
+        &self,
+        mesh: &TMesh,
+        properties: &[PlyProperty<'_, TMesh::VertexPropertyMap<f64>>],
+        path: &Path
+    ) -> Result<(), MeshIoError> {
+        let file = OpenOptions::new()
+            .write(true)
+            .truncate(true)
+            .create(true)
+            .open(path)?;
+        let mut writer = BufWriter::new(file);
+
+        self.write_ply(mesh, properties, &mut writer)?;
+        writer.flush()?;
+
+        return Ok(());
+    }
+
+    pub fn write_ply<TBuffer, TMesh>(
+        &self,
+        mesh: &TMesh,
+        properties: &[PlyProperty<'_, TMesh::VertexPropertyMap<f64>>],
+        writer: &mut BufWriter<TBuffer>
+    ) -> Result<(), MeshIoError>
+    where
+        TBuffer: Write,
+        TMesh: VertexProperties
+    {
+        let mut vertex_index_map = HashMap::new();
+        for vertex in mesh.vertices() {
+            vertex_index_map.insert(vertex, vertex_index_map.len());
+        }
+
+        let faces_count = mesh.faces().count();
+        let position_type = if std::mem::size_of::<TMesh::ScalarType>() > 4 { PlyScalarType::Double } else { PlyScalarType::Float };
+
+        // Header
+        let format = match self.format {
+            PlyFormat::Ascii => "ascii",
+            PlyFormat::BinaryLittleEndian => "binary_little_endian",
+            PlyFormat::BinaryBigEndian => "binary_big_endian",
+        };
+
+        writeln!(writer, "ply")?;
+        writeln!(writer, "format {} 1.0", format)?;
+        writeln!(writer, "element vertex {}", vertex_index_map.len())?;
+
+        for axis in ["x", "y", "z"] {
+            writeln!(writer, "property {} {}", position_type.name(), axis)?;
+        }
+
+        for property in properties {
+            writeln!(writer, "property {} {}", property.scalar_type.name(), property.name)?;
+        }
+
+        writeln!(writer, "element face {}", faces_count)?;
+        writeln!(writer, "property list uchar int vertex_indices")?;
+        writeln!(writer, "end_header")?;
+
+        // Vertices
+        for vertex in mesh.vertices() {
+            let position = mesh.vertex_position(&vertex);
+
+            for i in 0..3 {
+                let coordinate = position[i].to_f64().unwrap();
+                self.write_scalar(writer, coordinate, position_type, i == 0)?;
+            }
+
+            for property in properties {
+                self.write_scalar(writer, property.map[vertex], property.scalar_type, false)?;
+            }
+
+            self.end_element(writer)?;
+        }
+
+        // Faces
+        for face in mesh.faces() {
+            let (v1, v2, v3) = mesh.face_vertices(&face);
+
+            self.write_scalar(writer, 3.0, PlyScalarType::UChar, true)?;
+
+            for vertex in [v1, v2, v3] {
+                self.write_scalar(writer, vertex_index_map[&vertex] as f64, PlyScalarType::Int, false)?;
+            }
+
+            self.end_element(writer)?;
+        }
+
+        return Ok(());
+    }
+
+    fn write_scalar<TBuffer: Write>(&self, writer: &mut BufWriter<TBuffer>, value: f64, scalar_type: PlyScalarType, first: bool) -> io::Result<()> {
+        if self.format == PlyFormat::Ascii {
+            if !first {
+                write!(writer, " ")?;
+            }
+
+            if scalar_type.is_integer() {
+                return write!(writer, "{}", value.round() as i64);
+            }
+
+            return write!(writer, "{}", value);
+        }
+
+        let mut bytes = [0u8; 8];
+        let size = scalar_type.size();
+
+        match scalar_type {
+            PlyScalarType::Char => bytes[..size].copy_from_slice(&(value as i8).to_le_bytes()),
+            PlyScalarType::UChar => bytes[..size].copy_from_slice(&(value as u8).to_le_bytes()),
+            PlyScalarType::Short => bytes[..size].copy_from_slice(&(value as i16).to_le_bytes()),
+            PlyScalarType::UShort => bytes[..size].copy_from_slice(&(value as u16).to_le_bytes()),
+            PlyScalarType::Int => bytes[..size].copy_from_slice(&(value as i32).to_le_bytes()),
+            PlyScalarType::UInt => bytes[..size].copy_from_slice(&(value as u32).to_le_bytes()),
+            PlyScalarType::Float => bytes[..size].copy_from_slice(&(value as f32).to_le_bytes()),
+            PlyScalarType::Double => bytes[..size].copy_from_slice(&value.to_le_bytes()),
+        }
+
+        if self.format == PlyFormat::BinaryBigEndian {
+            bytes[..size].reverse();
+        }
+
+        return writer.write_all(&bytes[..size]);
+    }
+
+    #[inline]
+    fn end_element<TBuffer: Write>(&self, writer: &mut BufWriter<TBuffer>) -> io::Result<()> {
+        if self.format == PlyFormat::Ascii {
+            writeln!(writer)?;
+        }
+
+        return Ok(());
+    }
+}
+
+impl Default for PlyWriter {
+    #[inline]
+    fn default() -> Self {
+        return Self::new();
+    }
+}
+
+/// Writes mesh without custom vertex properties
+impl<TMesh: VertexProperties> MeshWriter<TMesh> for PlyWriter {
+    #[inline]
+    fn supports_extension(&self, extension: &str) -> bool {
+        return extension == "ply";
+    }
+
+    fn write(&self, mesh: &TMesh, writer: &mut dyn Write) -> Result<(), MeshIoError> {
+        let mut writer = BufWriter::new(writer);
+        self.write_ply(mesh, &[], &mut writer)?;
+        writer.flush()?;
+
+        return Ok(());
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::io::{BufReader, BufWriter};
+    use test_case::test_case;
+
+    use crate::{mesh::{corner_table::{prelude::CornerTableF, descriptors::VertexId}, traits::Mesh}, io::MeshIoError};
+
+    use super::{PlyReader, PlyWriter, PlyFormat, PlyProperty, PlyScalarType};
+
+    const COLORED_QUAD: &str = "ply
+format ascii 1.0
+comment scanner output
+element vertex 4
+property float x
+property float y
+property float z
+property uchar red
+property float confidence
+element face 1
+property list uchar int vertex_indices
+end_header
+0 0 0 255 0.5
+1 0 0 128 1
+1 1 0 0 0.25
+0 1 0 10 0
+4 0 1 2 3
+";
+
+    fn read(data: &[u8]) -> (PlyReader, Result<CornerTableF, MeshIoError>) {
+        let mut reader = PlyReader::new();
+        let mesh = reader.read_ply(&mut BufReader::new(data));
+        return (reader, mesh);
+    }
+
+    #[test]
+    fn read_ascii_with_properties() {
+        let (reader, mesh) = read(COLORED_QUAD.as_bytes());
+        let mesh = mesh.expect("Read PLY");
+
+        assert_eq!(mesh.faces().count(), 2);
+        assert_eq!(mesh.vertices().count(), 4);
+        assert_eq!(reader.vertex_properties().collect::<Vec<_>>(), vec![("red", PlyScalarType::UChar), ("confidence", PlyScalarType::Float)]);
+
+        let red = reader.vertex_property(&mesh, "red").unwrap();
+        assert_eq!(red[VertexId::new(1)], 128.0);
+        assert!(reader.vertex_property(&mesh, "green").is_none());
+    }
+
+    #[test]
+    fn read_non_manifold_with_properties() {
+        // Vertex 0 joins two fans and is split, its copy keeps property value of the original
+        let data = "ply\nformat ascii 1.0\nelement vertex 5\nproperty float x\nproperty float y\nproperty float z\nproperty uchar red\n\
+            element face 2\nproperty list uchar int vertex_indices\nend_header\n\
+            0 0 0 200\n1 0 0 1\n0 1 0 2\n-1 0 0 3\n0 -1 0 4\n3 0 1 2\n3 0 3 4\n";
+        let (reader, mesh) = read(data.as_bytes());
+        let mesh = mesh.expect("Read PLY");
+
+        assert_eq!(mesh.vertices().count(), 6);
+        assert_eq!(reader.duplicated_vertices(), &[(0, 5)]);
+
+        let red = reader.vertex_property(&mesh, "red").unwrap();
+        assert_eq!(red[VertexId::new(0)], 200.0);
+        assert_eq!(red[VertexId::new(4)], 4.0);
+        assert_eq!(red[VertexId::new(5)], 200.0);
+    }
+
+    #[test]
+    fn read_point_set() {
+        let data = "ply\nformat ascii 1.0\nelement vertex 2\nproperty double x\nproperty double y\nproperty double z\nend_header\n0 0 0\n1 2 3\n";
+        let (_, mesh) = read(data.as_bytes());
+        let mesh = mesh.expect("Read PLY point set");
+
+        assert_eq!(mesh.faces().count(), 0);
+        assert_eq!(mesh.vertices().count(), 2);
+    }
+
+    #[test]
+    fn read_invalid() {
+        assert!(read(COLORED_QUAD.replace("4 0 1 2 3", "4 0 1 2 7").as_bytes()).1.is_err());
+        assert!(matches!(read(COLORED_QUAD.replace("format ascii", "format binary").as_bytes()).1, Err(MeshIoError::UnsupportedEncoding(_))));
+        assert!(matches!(read(&COLORED_QUAD.as_bytes()[..COLORED_QUAD.len() - 10]).1, Err(MeshIoError::TruncatedData { .. })));
+
+        // Huge element count is not trusted for memory allocation
+        let huge = COLORED_QUAD.replace("element vertex 4", &format!("element vertex {}", usize::MAX));
+        assert!(matches!(read(huge.as_bytes()).1, Err(MeshIoError::TruncatedData { .. })));
+    }
+
+    #[test_case(PlyFormat::Ascii; "ASCII")]
+    #[test_case(PlyFormat::BinaryLittleEndian; "Binary little endian")]
+    #[test_case(PlyFormat::BinaryBigEndian; "Binary big endian")]
+    fn round_trip(format: PlyFormat) {
+        let (reader, mesh) = read(COLORED_QUAD.as_bytes());
+        let mesh = mesh.unwrap();
+        let red = reader.vertex_property(&mesh, "red").unwrap();
+        let confidence = reader.vertex_property(&mesh, "confidence").unwrap();
+
+        let mut writer = BufWriter::new(Vec::new());
+        PlyWriter::new().with_format(format).write_ply(&mesh, &[
+            PlyProperty { name: "red", scalar_type: PlyScalarType::UChar, map: &red },
+            PlyProperty { name: "confidence", scalar_type: PlyScalarType::Float, map: &confidence }
+        ], &mut writer).expect("Write PLY");
+
+        let (reader, read_back) = read(&writer.into_inner().unwrap());
+        let read_back = read_back.expect("Read written PLY");
+
+        assert_eq!(read_back.faces().count(), 2);
+        assert_eq!(read_back.vertex_position(&VertexId::new(2)), mesh.vertex_position(&VertexId::new(2)));
+        assert_eq!(reader.vertex_property(&read_back, "red").unwrap()[VertexId::new(0)], 255.0);
+        assert_eq!(reader.vertex_property(&read_back, "confidence").unwrap()[VertexId::new(2)], 0.25);
+    }
+}
@@ -1,17 +1,90 @@
 use std::{
     mem::size_of, 
-    io::{ErrorKind, Read, Error, BufReader, self, Write, BufWriter}, 
+    io::{Read, BufReader, BufRead, self, Write, BufWriter}, 
     fs::{OpenOptions, File}, path::Path, ops::Index
 };
 use nalgebra::{Point3, Vector3};
 use simba::scalar::SupersetOf;
+#[cfg(feature = "rayon")]
+use rayon::iter::{IntoParallelRefIterator, ParallelIterator};
 
-use crate::{algo::{merge_points::merge_points, utils::cast}, mesh::traits::Mesh};
+use crate::{algo::{merge_points::merge_points, utils::cast}, mesh::traits::{FaceProperties, Mesh}};
+#[cfg(feature = "rayon")]
+use crate::mesh::traits::ParallelMesh;
+
+use super::{traits::{MeshReader, MeshWriter}, error::{MeshIoError, OffsetReader, build_mesh, remove_degenerate_faces, remove_face_data}};
 
 const STL_HEADER_SIZE: usize = 80;
+const STL_TRIANGLE_SIZE: usize = 50;
+const STL_ASCII_PREFIX: &[u8] = b"solid";
+/// Number of faces converted in parallel before being written
+#[cfg(feature = "rayon")]
+const STL_WRITE_BATCH_SIZE: usize = 1 << 16;
+
+/// Encoding of STL file
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum StlFormat {
+    Binary,
+    Ascii
+}
+
+impl Default for StlFormat {
+    #[inline]
+    fn default() -> Self {
+        return StlFormat::Binary;
+    }
+}
+
+///
+/// Attribute word stored after each triangle of binary STL. Usually zero, but some software
+/// (VisCAM, SolidView) uses it to store face color in RGB555 format: blue in bits 0-4,
+/// green in bits 5-9, red in bits 10-14 and bit 15 set when color is valid.
+///
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub struct StlFaceAttribute(pub u16);
+
+/// Face color with 5-bit components (0..=31)
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct StlColor {
+    pub red: u8,
+    pub green: u8,
+    pub blue: u8
+}
+
+impl StlFaceAttribute {
+    const COLOR_VALID_BIT: u16 = 1 << 15;
+    const COMPONENT_MASK: u16 = 0b11111;
+
+    /// Creates attribute holding given color. Components are truncated to 5 bits.
+    pub fn from_color(color: StlColor) -> Self {
+        let mask = Self::COMPONENT_MASK;
+        let word = Self::COLOR_VALID_BIT |
+            (color.red as u16 & mask) << 10 |
+            (color.green as u16 & mask) << 5 |
+            (color.blue as u16 & mask);
+
+        return Self(word);
+    }
+
+    /// Returns face color, `None` when attribute doesn't hold valid color
+    pub fn color(&self) -> Option<StlColor> {
+        if self.0 & Self::COLOR_VALID_BIT == 0 {
+            return None;
+        }
+
+        let mask = Self::COMPONENT_MASK;
+        return Some(StlColor {
+            red: (self.0 >> 10 & mask) as u8,
+            green: (self.0 >> 5 & mask) as u8,
+            blue: (self.0 & mask) as u8
+        });
+    }
+}
 
 pub struct StlReader {
     vertices: Vec<Point3<f32>>,
+    face_attributes: Vec<StlFaceAttribute>,
+    strict_topology: bool,
 
     // Buffers for reading
     buf32: [u8; size_of::<u32>()],
@@ -19,19 +92,32 @@ pub struct StlReader {
 }
 
 ///
-/// Binary STL reader
+/// STL reader. Supports both binary and ASCII files, encoding is detected automatically.
 /// 
 impl StlReader {
     pub fn new() -> Self {
         return Self {
             vertices: Vec::new(),
+            face_attributes: Vec::new(),
+            strict_topology: false,
             buf16: [0; size_of::<u16>()],
             buf32: [0; size_of::<u32>()]
         };
     }
 
+    ///
+    /// Set whether faces sharing directed edge with another face and faces referencing same vertex more than once
+    /// are rejected as [MeshIoError::NonManifoldFace] and [MeshIoError::DegenerateFace].
+    /// By default mesh is split along non-manifold edges and vertices and degenerate faces are skipped.
+    ///
+    #[inline]
+    pub fn with_strict_topology(mut self, strict_topology: bool) -> Self {
+        self.strict_topology = strict_topology;
+        return self;
+    }
+
     /// Reads mesh from file
-    pub fn read_stl_from_file<TMesh>(&mut self, filepath: &Path) -> std::io::Result<TMesh> 
+    pub fn read_stl_from_file<TMesh>(&mut self, filepath: &Path) -> Result<TMesh, MeshIoError> 
     where 
         TMesh: Mesh,
         TMesh::ScalarType: SupersetOf<f32>
@@ -42,26 +128,55 @@ impl StlReader {
         return self.read_stl::<File, TMesh>(&mut reader);
     }
 
-    /// Reads mesh from buffer
-    pub fn read_stl<TBuffer, TMesh>(&mut self, reader: &mut BufReader<TBuffer>) -> std::io::Result<TMesh> 
+    ///
+    /// Reads mesh from buffer.
+    ///
+    /// Header starting with `solid` is not enough to tell ASCII file from binary one
+    /// (some exporters write it into binary header too), so in that case whole input is read
+    /// and treated as binary when its size matches triangle count stored in header.
+    /// Otherwise it is parsed as ASCII, falling back to binary when first facet can't be parsed.
+    ///
+    pub fn read_stl<TBuffer, TMesh>(&mut self, reader: &mut BufReader<TBuffer>) -> Result<TMesh, MeshIoError> 
     where 
         TBuffer: Read, 
         TMesh: Mesh,
         TMesh::ScalarType: SupersetOf<f32>
     {
         self.vertices.clear();
+        self.face_attributes.clear();
 
         // Read header
-        let mut header = [0u8; STL_HEADER_SIZE];
-        reader.read_exact(&mut header)?;
-
-        // Read number of triangle
-        reader.read_exact(&mut self.buf32)?;
-        let number_of_triangles: u32 = u32::from_le_bytes(self.buf32);
+        let mut header = Vec::with_capacity(STL_HEADER_SIZE);
+        reader.by_ref().take(STL_HEADER_SIZE as u64).read_to_end(&mut header)?;
+
+        if starts_with_solid(&header) {
+            let mut data = header;
+            reader.read_to_end(&mut data)?;
+
+            if is_binary_stl_size(&data) {
+                self.read_binary_body(&mut OffsetReader::new(&data[STL_HEADER_SIZE..], STL_HEADER_SIZE as u64))?;
+            } else if let Err(ascii_error) = self.read_ascii(&data) {
+                // Binary file with `solid` in header and extra data after last triangle
+                if !self.face_attributes.is_empty() || data.len() < STL_HEADER_SIZE {
+                    return Err(ascii_error);
+                }
+
+                self.vertices.clear();
+
+                if self.read_binary_body(&mut OffsetReader::new(&data[STL_HEADER_SIZE..], STL_HEADER_SIZE as u64)).is_err() {
+                    return Err(ascii_error);
+                }
+            }
+        } else {
+            if header.len() < STL_HEADER_SIZE {
+                return Err(MeshIoError::TruncatedData { offset: 0 });
+            }
+
+            self.read_binary_body(&mut OffsetReader::new(reader, STL_HEADER_SIZE as u64))?;
+        }
 
-        // Faces
-        for _ in 0..number_of_triangles {
-            self.read_face(reader)?;
+        if let Some(vertex) = self.vertices.iter().position(|v| v.iter().any(|coordinate| !coordinate.is_finite())) {
+            return Err(MeshIoError::NonFiniteCoordinate { vertex });
         }
 
         // Merge face vertices
@@ -73,11 +188,73 @@ impl StlReader {
                 .map(|point| point.cast::<TMesh::ScalarType>())
                 .collect();
         
+        let mut indices = merged_vertices.indices;
+
+        // Degenerate faces are skipped by mesh builder, so their attributes are dropped too
+        if !self.strict_topology {
+            let removed = remove_degenerate_faces(&mut indices);
+            remove_face_data(&mut self.face_attributes, &removed, 1);
+        }
+
         // Create mesh
-        return Ok(TMesh::from_vertices_and_indices(&vertices, &merged_vertices.indices));
+        return build_mesh(&vertices, &indices, self.strict_topology);
+    }
+
+    /// Reads mesh and attribute words of its faces from file, see [StlReader::read_stl_with_attributes]
+    pub fn read_stl_with_attributes_from_file<TMesh>(&mut self, filepath: &Path) -> Result<(TMesh, TMesh::FacePropertyMap<StlFaceAttribute>), MeshIoError> 
+    where 
+        TMesh: FaceProperties,
+        TMesh::ScalarType: SupersetOf<f32>
+    {
+        let file = OpenOptions::new().read(true).open(filepath)?;
+        let mut reader = BufReader::new(file);
+
+        return self.read_stl_with_attributes::<File, TMesh>(&mut reader);
     }
 
-    fn read_face<TBuffer: Read>(&mut self, reader: &mut BufReader<TBuffer>) -> io::Result<()> {
+    ///
+    /// Reads mesh from buffer together with attribute words of its faces stored in face property map.
+    /// Faces of ASCII files have zero attribute.
+    ///
+    pub fn read_stl_with_attributes<TBuffer, TMesh>(&mut self, reader: &mut BufReader<TBuffer>) -> Result<(TMesh, TMesh::FacePropertyMap<StlFaceAttribute>), MeshIoError> 
+    where 
+        TBuffer: Read, 
+        TMesh: FaceProperties,
+        TMesh::ScalarType: SupersetOf<f32>
+    {
+        let mesh: TMesh = self.read_stl(reader)?;
+        let mut attributes = mesh.create_face_properties_map();
+
+        // Mesh builder keeps order of faces
+        for (face, attribute) in mesh.faces().zip(self.face_attributes.drain(..)) {
+            attributes[face] = attribute;
+        }
+
+        return Ok((mesh, attributes));
+    }
+
+    ///
+    /// Reads triangles count and triangles of binary STL. Header should be already consumed.
+    /// Input ending right after some triangle is reported as count mismatch, ending inside triangle as truncation.
+    ///
+    fn read_binary_body<TBuffer: BufRead>(&mut self, reader: &mut OffsetReader<TBuffer>) -> Result<(), MeshIoError> {
+        // Read number of triangle
+        reader.read_exact(&mut self.buf32)?;
+        let number_of_triangles = u32::from_le_bytes(self.buf32) as usize;
+
+        // Faces
+        for face_index in 0..number_of_triangles {
+            if reader.is_at_end()? {
+                return Err(MeshIoError::TriangleCountMismatch { expected: number_of_triangles, actual: face_index });
+            }
+
+            self.read_face(reader)?;
+        }
+
+        return Ok(());
+    }
+
+    fn read_face<TBuffer: BufRead>(&mut self, reader: &mut OffsetReader<TBuffer>) -> Result<(), MeshIoError> {
         // Normal
         self.read_vec3(reader)?;
 
@@ -92,11 +269,12 @@ impl StlReader {
 
         // Attribute
         reader.read_exact(&mut self.buf16)?;
+        self.face_attributes.push(StlFaceAttribute(u16::from_le_bytes(self.buf16)));
 
         return Ok(());
     }
 
-    fn read_vec3<TBuffer: Read>(&mut self, reader: &mut BufReader<TBuffer>) -> io::Result<Point3<f32>> {
+    fn read_vec3<TBuffer: BufRead>(&mut self, reader: &mut OffsetReader<TBuffer>) -> Result<Point3<f32>, MeshIoError> {
         reader.read_exact(&mut self.buf32)?;
         let x = f32::from_le_bytes(self.buf32);
 
@@ -108,6 +286,70 @@ impl StlReader {
 
         return Ok(Point3::new(x, y, z));
     }
+
+    ///
+    /// Parses ASCII STL. File can contain several solids, all of them are merged into single mesh.
+    /// Normals are ignored, they are recomputed from vertices anyway.
+    ///
+    fn read_ascii(&mut self, data: &[u8]) -> Result<(), MeshIoError> {
+        let text = String::from_utf8_lossy(data);
+        let mut state = AsciiState::OutsideSolid;
+        let mut facet_vertices = 0;
+
+        for (line_index, line) in text.lines().enumerate() {
+            let line_number = line_index + 1;
+            let mut tokens = line.split_whitespace();
+
+            let keyword = match tokens.next() {
+                Some(keyword) => keyword,
+                None => continue,
+            };
+
+            state = match (state, keyword) {
+                (AsciiState::OutsideSolid, "solid") => AsciiState::InsideSolid,
+                (AsciiState::InsideSolid, "endsolid") => AsciiState::OutsideSolid,
+                (AsciiState::InsideSolid, "facet") => {
+                    expect_token(&mut tokens, "normal", line_number)?;
+                    parse_vec3(&mut tokens, line_number)?;
+                    AsciiState::InsideFacet
+                },
+                (AsciiState::InsideFacet, "outer") => {
+                    expect_token(&mut tokens, "loop", line_number)?;
+                    facet_vertices = 0;
+                    AsciiState::InsideLoop
+                },
+                (AsciiState::InsideLoop, "vertex") => {
+                    if facet_vertices == 3 {
+                        return Err(ascii_error("facet has more than 3 vertices", line_number));
+                    }
+
+                    self.vertices.push(parse_vec3(&mut tokens, line_number)?);
+                    facet_vertices += 1;
+                    AsciiState::InsideLoop
+                },
+                (AsciiState::InsideLoop, "endloop") => {
+                    if facet_vertices != 3 {
+                        return Err(ascii_error("facet has less than 3 vertices", line_number));
+                    }
+
+                    AsciiState::EndOfLoop
+                },
+                (AsciiState::EndOfLoop, "endfacet") => {
+                    self.face_attributes.push(StlFaceAttribute::default());
+                    AsciiState::InsideSolid
+                },
+                (_, keyword) => {
+                    return Err(ascii_error(&format!("unexpected keyword `{}`", keyword), line_number));
+                }
+            };
+        }
+
+        if state != AsciiState::OutsideSolid {
+            return Err(MeshIoError::TruncatedData { offset: data.len() as u64 });
+        }
+
+        return Ok(());
+    }
 }
 
 impl Default for StlReader {
@@ -117,61 +359,261 @@ impl Default for StlReader {
     }
 }
 
-pub struct StlWriter;
+impl<TMesh> MeshReader<TMesh> for StlReader
+where
+    TMesh: Mesh,
+    TMesh::ScalarType: SupersetOf<f32>
+{
+    #[inline]
+    fn supports_extension(&self, extension: &str) -> bool {
+        return extension == "stl";
+    }
+
+    ///
+    /// STL has no magic number, so ASCII solid or binary data with triangle count consistent with its length is accepted.
+    /// When only header is known, binary file is accepted if it is not longer than size stored in it.
+    ///
+    #[inline]
+    fn can_read(&self, header: &[u8]) -> bool {
+        return MeshReader::<TMesh>::can_read_with_length(self, header, None);
+    }
+
+    fn can_read_with_length(&self, header: &[u8], length: Option<u64>) -> bool {
+        if starts_with_solid(header) {
+            return true;
+        }
+
+        return match (binary_stl_size(header), length) {
+            (Some(size), Some(length)) => size == length,
+            (Some(size), None) => size >= header.len() as u64,
+            (None, _) => false
+        };
+    }
+
+    #[inline]
+    fn read(&mut self, reader: &mut dyn Read) -> Result<TMesh, MeshIoError> {
+        return self.read_stl(&mut BufReader::new(reader));
+    }
+}
+
+/// Position of ASCII STL parser in `solid/facet/outer loop` hierarchy
+#[derive(PartialEq, Clone, Copy)]
+enum AsciiState {
+    OutsideSolid,
+    InsideSolid,
+    InsideFacet,
+    InsideLoop,
+    EndOfLoop
+}
+
+#[inline]
+fn starts_with_solid(header: &[u8]) -> bool {
+    let first_non_space = header.iter().position(|byte| !byte.is_ascii_whitespace()).unwrap_or(header.len());
+    return header[first_non_space..].starts_with(STL_ASCII_PREFIX);
+}
+
+/// Returns size of binary STL with number of triangles stored in header, `None` when header is too short
+fn binary_stl_size(header: &[u8]) -> Option<u64> {
+    if header.len() < STL_HEADER_SIZE + size_of::<u32>() {
+        return None;
+    }
+
+    let mut count_bytes = [0u8; size_of::<u32>()];
+    count_bytes.copy_from_slice(&header[STL_HEADER_SIZE..STL_HEADER_SIZE + size_of::<u32>()]);
+    let number_of_triangles = u32::from_le_bytes(count_bytes) as u64;
+
+    return Some((STL_HEADER_SIZE + size_of::<u32>()) as u64 + number_of_triangles * STL_TRIANGLE_SIZE as u64);
+}
+
+/// Returns `true` when size of data matches size of binary STL with number of triangles stored in header
+#[inline]
+fn is_binary_stl_size(data: &[u8]) -> bool {
+    return binary_stl_size(data) == Some(data.len() as u64);
+}
+
+#[inline]
+fn ascii_error(message: &str, line_number: usize) -> MeshIoError {
+    return MeshIoError::InvalidData(format!("ASCII STL, line {}: {}", line_number, message));
+}
+
+fn expect_token<'a, TTokens: Iterator<Item = &'a str>>(tokens: &mut TTokens, expected: &str, line_number: usize) -> Result<(), MeshIoError> {
+    match tokens.next() {
+        Some(token) if token == expected => return Ok(()),
+        _ => return Err(ascii_error(&format!("expected `{}`", expected), line_number)),
+    }
+}
+
+fn parse_vec3<'a, TTokens: Iterator<Item = &'a str>>(tokens: &mut TTokens, line_number: usize) -> Result<Point3<f32>, MeshIoError> {
+    let mut point = Point3::origin();
+
+    for i in 0..3 {
+        point[i] = tokens.next()
+            .and_then(|token| token.parse::<f32>().ok())
+            .ok_or_else(|| ascii_error("expected 3 numbers", line_number))?;
+    }
+
+    return Ok(point);
+}
+
+///
+/// STL writer. Writes binary files by default, use [StlWriter::with_format] to write ASCII.
+///
+pub struct StlWriter {
+    format: StlFormat
+}
 
 impl StlWriter {
     pub fn new() -> Self {
-        return StlWriter {};
+        return StlWriter {
+            format: StlFormat::default()
+        };
     }
 
-    pub fn write_stl_to_file<TMesh: Mesh>(&self, mesh: &TMesh, path: &Path) -> io::Result<()> {
+    /// Set encoding of written files. Default is [StlFormat::Binary]
+    #[inline]
+    pub fn with_format(mut self, format: StlFormat) -> Self {
+        self.format = format;
+        return self;
+    }
+
+    pub fn write_stl_to_file<TMesh: Mesh>(&self, mesh: &TMesh, path: &Path) -> Result<(), MeshIoError> {
+        return self.write_to_file(mesh, path);
+    }
+
+    /// Writes mesh with attribute words of faces, see [StlWriter::write_stl_with_attributes]
+    pub fn write_stl_with_attributes_to_file<TMesh>(&self, mesh: &TMesh, attributes: &TMesh::FacePropertyMap<StlFaceAttribute>, path: &Path) -> Result<(), MeshIoError> 
+    where 
+        TMesh: FaceProperties
+    {
         let file = OpenOptions::new()
             .write(true)
             .truncate(true)
             .create(true)
-            .open(path)
-            .unwrap();
+            .open(path)?;
         let mut writer = BufWriter::new(file);
 
-        return self.write_stl(mesh, &mut writer);
+        self.write_stl_with_attributes(mesh, attributes, &mut writer)?;
+        writer.flush()?;
+
+        return Ok(());
     }
-    
-    pub fn write_stl<TBuffer, TMesh>(&self, mesh: &TMesh, writer: &mut BufWriter<TBuffer>) -> io::Result<()> 
+
+    pub fn write_stl<TBuffer, TMesh>(&self, mesh: &TMesh, writer: &mut BufWriter<TBuffer>) -> Result<(), MeshIoError> 
     where 
         TBuffer: Write, 
         TMesh: Mesh
+    {
+        return self.write_faces(mesh, faces_f32(mesh), writer);
+    }
+
+    ///
+    /// Same as [StlWriter::write_stl], but faces are converted to `f32` in parallel.
+    /// Faces are converted by batches to limit memory usage, output is the same.
+    ///
+    #[cfg(feature = "rayon")]
+    pub fn par_write_stl<TBuffer, TMesh>(&self, mesh: &TMesh, writer: &mut BufWriter<TBuffer>) -> Result<(), MeshIoError> 
+    where 
+        TBuffer: Write, 
+        TMesh: ParallelMesh
+    {
+        return self.write_faces(mesh, par_faces_f32(mesh), writer);
+    }
+
+    ///
+    /// Writes mesh with attribute words of faces taken from face property map, e.g. one returned by [StlReader::read_stl_with_attributes].
+    /// ASCII STL has no place for attributes, so writing them in [StlFormat::Ascii] is reported as [MeshIoError::UnsupportedEncoding].
+    ///
+    pub fn write_stl_with_attributes<TBuffer, TMesh>(&self, mesh: &TMesh, attributes: &TMesh::FacePropertyMap<StlFaceAttribute>, writer: &mut BufWriter<TBuffer>) -> Result<(), MeshIoError> 
+    where 
+        TBuffer: Write, 
+        TMesh: FaceProperties
+    {
+        match self.format {
+            StlFormat::Binary => return self.write_binary_stl(mesh, faces_f32(mesh), |face| attributes[*face], writer),
+            StlFormat::Ascii => return Err(MeshIoError::UnsupportedEncoding("face attributes can't be written to ASCII STL".to_string())),
+        }
+    }
+
+    fn write_to_file<TMesh: Mesh>(&self, mesh: &TMesh, path: &Path) -> Result<(), MeshIoError> {
+        let file = OpenOptions::new()
+            .write(true)
+            .truncate(true)
+            .create(true)
+            .open(path)?;
+        let mut writer = BufWriter::new(file);
+
+        self.write_stl(mesh, &mut writer)?;
+        writer.flush()?;
+
+        return Ok(());
+    }
+
+    fn write_faces<TBuffer, TMesh, TFaces>(&self, mesh: &TMesh, faces: TFaces, writer: &mut BufWriter<TBuffer>) -> Result<(), MeshIoError>
+    where
+        TBuffer: Write,
+        TMesh: Mesh,
+        TFaces: Iterator<Item = (TMesh::FaceDescriptor, FaceF32)>
+    {
+        match self.format {
+            StlFormat::Binary => return self.write_binary_stl(mesh, faces, |_| StlFaceAttribute::default(), writer),
+            StlFormat::Ascii => return self.write_ascii_stl(faces, writer),
+        }
+    }
+
+    fn write_binary_stl<TBuffer, TMesh, TFaces, TAttribute>(&self, mesh: &TMesh, faces: TFaces, attribute: TAttribute, writer: &mut BufWriter<TBuffer>) -> Result<(), MeshIoError>
+    where
+        TBuffer: Write,
+        TMesh: Mesh,
+        TFaces: Iterator<Item = (TMesh::FaceDescriptor, FaceF32)>,
+        TAttribute: Fn(&TMesh::FaceDescriptor) -> StlFaceAttribute
     {
         let header = [0u8; STL_HEADER_SIZE];
         writer.write_all(&header)?;
 
         let faces_count = mesh.faces().count();
-        if faces_count > u32::max_value() as usize {
-            return Err(Error::new(ErrorKind::Other, "Mesh is too big for STL"));
+        if faces_count > u32::MAX as usize {
+            return Err(MeshIoError::InvalidData("Mesh is too big for STL".to_string()));
         } 
 
         writer.write_all(&(faces_count as u32).to_le_bytes())?;
     
-        for face in mesh.faces() {
-            let triangle = mesh.face_positions(&face);
-            let normal = triangle.get_normal();
-            
-            let p1 = cast(&triangle.p1().coords).into();
-            let p2 = cast(&triangle.p2().coords).into();
-            let p3 = cast(&triangle.p3().coords).into();
-            let n = cast(&normal);
-
-            self.write_face(writer, &p1, &p2, &p3, &n)?;
+        for (face, (p1, p2, p3, n)) in faces {
+            self.write_face(writer, &p1, &p2, &p3, &n, attribute(&face))?;
         }
 
         return Ok(());
     }
 
-    fn write_face<TBuffer: Write>(&self, writer: &mut BufWriter<TBuffer>, v1: &Point3<f32>, v2: &Point3<f32>, v3: &Point3<f32>, normal: &Vector3<f32>) -> io::Result<()> {
+    fn write_ascii_stl<TBuffer, TFace, TFaces>(&self, faces: TFaces, writer: &mut BufWriter<TBuffer>) -> Result<(), MeshIoError>
+    where
+        TBuffer: Write,
+        TFaces: Iterator<Item = (TFace, FaceF32)>
+    {
+        writeln!(writer, "solid mesh")?;
+
+        for (_, (p1, p2, p3, n)) in faces {
+            writeln!(writer, "  facet normal {:e} {:e} {:e}", n.x, n.y, n.z)?;
+            writeln!(writer, "    outer loop")?;
+
+            for p in [p1, p2, p3] {
+                writeln!(writer, "      vertex {:e} {:e} {:e}", p.x, p.y, p.z)?;
+            }
+
+            writeln!(writer, "    endloop")?;
+            writeln!(writer, "  endfacet")?;
+        }
+
+        writeln!(writer, "endsolid mesh")?;
+
+        return Ok(());
+    }
+
+    fn write_face<TBuffer: Write>(&self, writer: &mut BufWriter<TBuffer>, v1: &Point3<f32>, v2: &Point3<f32>, v3: &Point3<f32>, normal: &Vector3<f32>, attribute: StlFaceAttribute) -> io::Result<()> {
         self.write_point(writer, normal)?;
         self.write_point(writer, v1)?;
         self.write_point(writer, v2)?;
         self.write_point(writer, v3)?;
-        writer.write_all(&[0; 2])?;
+        writer.write_all(&attribute.0.to_le_bytes())?;
 
         return Ok(());
     }
@@ -191,3 +633,233 @@ impl Default for StlWriter {
         return Self::new();
     }
 }
+
+impl<TMesh: Mesh> MeshWriter<TMesh> for StlWriter {
+    #[inline]
+    fn supports_extension(&self, extension: &str) -> bool {
+        return extension == "stl";
+    }
+
+    fn write(&self, mesh: &TMesh, writer: &mut dyn Write) -> Result<(), MeshIoError> {
+        let mut writer = BufWriter::new(writer);
+        self.write_stl(mesh, &mut writer)?;
+        writer.flush()?;
+
+        return Ok(());
+    }
+}
+
+type FaceF32 = (Point3<f32>, Point3<f32>, Point3<f32>, Vector3<f32>);
+
+/// Faces with their f32 positions and normal in order of [Mesh::faces]
+fn faces_f32<TMesh: Mesh>(mesh: &TMesh) -> impl Iterator<Item = (TMesh::FaceDescriptor, FaceF32)> + '_ {
+    return mesh.faces().map(|face| (face, face_to_f32(mesh, &face)));
+}
+
+/// Faces with their f32 positions and normal in order of [Mesh::faces].
+/// Faces are converted in parallel by batches when iterator reaches them.
+#[cfg(feature = "rayon")]
+fn par_faces_f32<TMesh: ParallelMesh>(mesh: &TMesh) -> impl Iterator<Item = (TMesh::FaceDescriptor, FaceF32)> + '_ {
+    let faces: Vec<_> = mesh.par_faces().collect();
+    let batches: Vec<Vec<_>> = faces.chunks(STL_WRITE_BATCH_SIZE).map(<[_]>::to_vec).collect();
+
+    return batches.into_iter().flat_map(move |batch| {
+        let converted: Vec<_> = batch.par_iter().map(|face| face_to_f32(mesh, face)).collect();
+        return batch.into_iter().zip(converted);
+    });
+}
+
+/// Returns face vertices and normal casted to `f32`
+#[inline]
+fn face_to_f32<TMesh: Mesh>(mesh: &TMesh, face: &TMesh::FaceDescriptor) -> FaceF32 {
+    let triangle = mesh.face_positions(face);
+    let normal = triangle.get_normal();
+
+    return (
+        cast(&triangle.p1().coords).into(),
+        cast(&triangle.p2().coords).into(),
+        cast(&triangle.p3().coords).into(),
+        cast(&normal)
+    );
+}
+
+#[cfg(test)]
+mod tests {
+    use std::io::{BufReader, BufWriter};
+
+    use crate::{
+        mesh::{corner_table::{prelude::CornerTableF, descriptors::FaceId}, traits::{Mesh, FaceProperties}},
+        io::{stl::StlFormat, traits::MeshReader, MeshIoError}
+    };
+
+    use super::{StlReader, StlWriter, StlFaceAttribute, StlColor};
+
+    const TWO_SOLIDS: &str = "solid first
+  facet normal 0 0 1
+    outer loop
+      vertex 0 0 0
+      vertex 1.0e+000 0 0
+      vertex 0 1 0
+    endloop
+  endfacet
+endsolid first
+solid second
+  facet normal 0 0 1
+    outer loop
+      vertex 1 0 0
+      vertex 1 1 0
+      vertex 0 1 0
+    endloop
+  endfacet
+endsolid second
+";
+
+    fn read(data: &[u8]) -> Result<CornerTableF, MeshIoError> {
+        return StlReader::new().read_stl(&mut BufReader::new(data));
+    }
+
+    fn write(mesh: &CornerTableF, writer: StlWriter) -> Vec<u8> {
+        let mut buffer = BufWriter::new(Vec::new());
+        writer.write_stl(mesh, &mut buffer).expect("Write STL");
+        return buffer.into_inner().unwrap();
+    }
+
+    #[test]
+    fn read_ascii_with_multiple_solids() {
+        let mesh = read(TWO_SOLIDS.as_bytes()).expect("Read ASCII STL");
+
+        assert_eq!(mesh.faces().count(), 2);
+        assert_eq!(mesh.vertices().count(), 4);
+    }
+
+    #[test]
+    fn read_invalid_ascii() {
+        let truncated = &TWO_SOLIDS[..TWO_SOLIDS.len() / 3];
+        assert!(read(truncated.as_bytes()).is_err());
+
+        let two_vertices = "solid a\nfacet normal 0 0 1\nouter loop\nvertex 0 0 0\nvertex 1 0 0\nendloop\nendfacet\nendsolid a\n";
+        assert!(read(two_vertices.as_bytes()).is_err());
+    }
+
+    #[test]
+    fn ascii_round_trip() {
+        let mesh = read(TWO_SOLIDS.as_bytes()).unwrap();
+        let data = write(&mesh, StlWriter::new().with_format(StlFormat::Ascii));
+        let read_back = read(&data).expect("Read written ASCII STL");
+
+        assert!(data.starts_with(b"solid"));
+        assert_eq!(read_back.faces().count(), 2);
+        assert_eq!(read_back.vertices().count(), 4);
+    }
+
+    #[test]
+    fn binary_with_solid_in_header() {
+        let mesh = read(TWO_SOLIDS.as_bytes()).unwrap();
+        let mut data = write(&mesh, StlWriter::new());
+        data[..5].copy_from_slice(b"solid");
+
+        let read_back = read(&data).expect("Read binary STL");
+
+        assert_eq!(read_back.faces().count(), 2);
+        assert_eq!(read_back.vertices().count(), 4);
+    }
+
+    #[test]
+    fn binary_with_solid_in_header_and_trailing_data() {
+        let mesh = read(TWO_SOLIDS.as_bytes()).unwrap();
+        let mut data = write(&mesh, StlWriter::new());
+        data[..6].copy_from_slice(b"solid ");
+        data.extend_from_slice(&[0; 7]);
+
+        let read_back = read(&data).expect("Read binary STL");
+        assert_eq!(read_back.faces().count(), 2);
+
+        // Broken ASCII file still reports ASCII error
+        let broken = TWO_SOLIDS.replace("outer loop", "outer");
+        assert!(matches!(read(broken.as_bytes()), Err(MeshIoError::InvalidData(message)) if message.contains("ASCII")));
+    }
+
+    #[test]
+    fn detect_binary_by_size() {
+        let mesh = read(TWO_SOLIDS.as_bytes()).unwrap();
+        let data = write(&mesh, StlWriter::new());
+        let reader = StlReader::new();
+        let can_read = |header: &[u8], length| MeshReader::<CornerTableF>::can_read_with_length(&reader, header, length);
+
+        assert!(can_read(&data, Some(data.len() as u64)));
+        assert!(can_read(&data[..100], None));
+        assert!(!can_read(&data, Some(data.len() as u64 + 1)));
+        assert!(!can_read(&data[..80], None));
+
+        // Triangle count of arbitrary binary data doesn't match its length
+        let mut other = vec![0xAB; 512];
+        other[80..84].copy_from_slice(&1u32.to_le_bytes());
+        assert!(!can_read(&other, None));
+        assert!(!can_read(&other, Some(1000)));
+
+        // ASCII solid may be preceded by whitespace
+        assert!(can_read(b"  \nsolid cube\n", None));
+    }
+
+    #[test]
+    fn binary_errors() {
+        let mesh = read(TWO_SOLIDS.as_bytes()).unwrap();
+        let data = write(&mesh, StlWriter::new());
+
+        // Ends inside of second triangle
+        let truncated = read(&data[..data.len() - 10]);
+        assert!(matches!(truncated, Err(MeshIoError::TruncatedData { offset }) if offset == 84 + 50 + 40));
+
+        // Ends right after first triangle
+        let missing_triangle = read(&data[..data.len() - 50]);
+        assert!(matches!(missing_triangle, Err(MeshIoError::TriangleCountMismatch { expected: 2, actual: 1 })));
+
+        let mut nan = data.clone();
+        nan[84 + 50 + 12..84 + 50 + 16].copy_from_slice(&f32::NAN.to_le_bytes());
+        assert!(matches!(read(&nan), Err(MeshIoError::NonFiniteCoordinate { vertex: 3 })));
+    }
+
+    #[test]
+    fn face_attributes_round_trip() {
+        let red = StlColor { red: 31, green: 0, blue: 0 };
+
+        let mesh = read(TWO_SOLIDS.as_bytes()).unwrap();
+        let mut attributes = mesh.create_face_properties_map();
+        attributes[FaceId::new(0)] = StlFaceAttribute::from_color(red);
+        attributes[FaceId::new(1)] = StlFaceAttribute(7);
+
+        let mut buffer = BufWriter::new(Vec::new());
+        StlWriter::new().write_stl_with_attributes(&mesh, &attributes, &mut buffer).expect("Write STL");
+        let data = buffer.into_inner().unwrap();
+
+        let (read_back, read_attributes): (CornerTableF, _) = StlReader::new()
+            .read_stl_with_attributes(&mut BufReader::new(data.as_slice()))
+            .expect("Read STL");
+
+        for face in read_back.faces() {
+            assert_eq!(read_attributes[face], attributes[face]);
+        }
+
+        assert_eq!(read_attributes[FaceId::new(0)].color(), Some(red));
+        assert_eq!(read_attributes[FaceId::new(1)].color(), None);
+
+        let mut buffer = BufWriter::new(Vec::new());
+        let ascii = StlWriter::new().with_format(StlFormat::Ascii).write_stl_with_attributes(&mesh, &attributes, &mut buffer);
+        assert!(matches!(ascii, Err(MeshIoError::UnsupportedEncoding(_))));
+    }
+
+    #[cfg(feature = "rayon")]
+    #[test]
+    fn parallel_write_should_match_sequential() {
+        let mesh = crate::mesh::corner_table::test_helpers::create_uv_sphere_mesh(20, 40);
+
+        for format in [StlFormat::Binary, StlFormat::Ascii] {
+            let writer = StlWriter::new().with_format(format);
+
+            let mut parallel = BufWriter::new(Vec::new());
+            writer.par_write_stl(&mesh, &mut parallel).expect("Write STL");
+
+            assert_eq!(parallel.into_inner().unwrap(), write(&mesh, writer));
+        }
+    }
+}
@@ -0,0 +1,39 @@
+use std::io::{Read, Write};
+
+use crate::mesh::traits::Mesh;
+
+use super::error::MeshIoError;
+
+///
+/// Mesh decoder that can be registered in [MeshIo](super::mesh_io::MeshIo)
+///
+pub trait MeshReader<TMesh: Mesh> {
+    /// Returns `true` when reader supports files with given extension (lowercase, without dot)
+    fn supports_extension(&self, extension: &str) -> bool;
+
+    /// Returns `true` when reader recognizes format by first bytes of data. `header` can be shorter than whole file.
+    fn can_read(&self, header: &[u8]) -> bool;
+
+    ///
+    /// Same as [Self::can_read], but also receives total length of data when it is known.
+    /// Should be overridden by formats without magic number that can be recognized by their size.
+    ///
+    #[inline]
+    fn can_read_with_length(&self, header: &[u8], _length: Option<u64>) -> bool {
+        return self.can_read(header);
+    }
+
+    /// Reads mesh from stream
+    fn read(&mut self, reader: &mut dyn Read) -> Result<TMesh, MeshIoError>;
+}
+
+///
+/// Mesh encoder that can be registered in [MeshIo](super::mesh_io::MeshIo)
+///
+pub trait MeshWriter<TMesh: Mesh> {
+    /// Returns `true` when writer produces files with given extension (lowercase, without dot)
+    fn supports_extension(&self, extension: &str) -> bool;
+
+    /// Writes mesh to stream
+    fn write(&self, mesh: &TMesh, writer: &mut dyn Write) -> Result<(), MeshIoError>;
+}
@@ -0,0 +1,351 @@
+use std::{
+    io::{self, Write, BufWriter},
+    fs::OpenOptions, path::Path, collections::HashMap, ops::Index
+};
+use num_traits::ToPrimitive;
+
+use crate::mesh::traits::Mesh;
+
+use super::{traits::MeshWriter, error::MeshIoError};
+
+/// VTK file flavour
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum VtkFormat {
+    /// ASCII legacy `.vtk` file with polygonal dataset
+    Legacy,
+    /// XML `.vtu` file with unstructured grid
+    Xml
+}
+
+impl VtkFormat {
+    /// Extension of files of this format (without dot)
+    #[inline]
+    pub fn extension(&self) -> &'static str {
+        match self {
+            VtkFormat::Legacy => return "vtk",
+            VtkFormat::Xml => return "vtu",
+        }
+    }
+}
+
+impl Default for VtkFormat {
+    #[inline]
+    fn default() -> Self {
+        return VtkFormat::Legacy;
+    }
+}
+
+///
+/// Named scalar field written by [VtkWriter]. `values` is indexed by vertex or face descriptor,
+/// so vertex/face property maps and any other map indexable by descriptor can be used.
+///
+pub struct VtkField<'a, TKey> {
+    pub name: &'a str,
+    pub values: &'a dyn Index<TKey, Output = f64>
+}
+
+///
+/// VTK writer. Writes mesh together with named per-vertex (point data) and per-face (cell data) scalar fields,
+/// e.g. for inspection in ParaView. Deleted elements are skipped.
+///
+/// ## Example
+/// ```ignore
+/// let mut curvature = mesh.create_vertex_properties_map();
+/// // ...
+/// VtkWriter::new()
+///     .with_format(VtkFormat::Xml)
+///     .write_vtk_to_file(&mesh, &[VtkField { name: "curvature", values: &curvature }], &[], Path::new("mesh.vtu"))?;
+/// ```
+///
+pub struct VtkWriter {
+    format: VtkFormat
+}
+
+impl VtkWriter {
+    pub fn new() -> Self {
+        return Self {
+            format: VtkFormat::default()
+        };
+    }
+
+    /// Set flavour of written files. Default is [VtkFormat::Legacy]
+    #[inline]
+    pub fn with_format(mut self, format: VtkFormat) -> Self {
+        self.format = format;
+        return self;
+    }
+
+    pub fn write_vtk_to_file<TMesh: Mesh>(
+        &self,
+        mesh: &TMesh,
+        point_data: &[VtkField<'_, TMesh::VertexDescriptor>],
+        cell_data: &[VtkField<'_, TMesh::FaceDescriptor>],
+        path: &Path
+    ) -> Result<(), MeshIoError> {
+        let file = OpenOptions::new()
+            .write(true)
+            .truncate(true)
+            .create(true)
+            .open(path)?;
+        let mut writer = BufWriter::new(file);
+
+        self.write_vtk(mesh, point_data, cell_data, &mut writer)?;
+        writer.flush()?;
+
+        return Ok(());
+    }
+
+    pub fn write_vtk<TBuffer, TMesh>(
+        &self,
+        mesh: &TMesh,
+        point_data: &[VtkField<'_, TMesh::VertexDescriptor>],
+        cell_data: &[VtkField<'_, TMesh::FaceDescriptor>],
+        writer: &mut BufWriter<TBuffer>
+    ) -> Result<(), MeshIoError>
+    where
+        TBuffer: Write,
+        TMesh: Mesh
+    {
+        let vertices: Vec<_> = mesh.vertices().collect();
+        let faces: Vec<_> = mesh.faces().collect();
+
+        let vertex_index_map: HashMap<_, _> = vertices.iter().enumerate().map(|(index, vertex)| (*vertex, index)).collect();
+        let triangles: Vec<_> = faces.iter()
+            .map(|face| {
+                let (v1, v2, v3) = mesh.face_vertices(face);
+                [v1, v2, v3].map(|vertex| vertex_index_map[&vertex])
+            })
+            .collect();
+
+        let positions: Vec<_> = vertices.iter()
+            .map(|vertex| {
+                let position = mesh.vertex_position(vertex);
+                [position.x, position.y, position.z].map(|coordinate| coordinate.to_f64().unwrap())
+            })
+            .collect();
+
+        let point_values: Vec<_> = point_data.iter().map(|field| (field.name, vertices.iter().map(|v| field.values[*v]).collect())).collect();
+        let cell_values: Vec<_> = cell_data.iter().map(|field| (field.name, faces.iter().map(|f| field.values[*f]).collect())).collect();
+
+        match self.format {
+            VtkFormat::Legacy => write_legacy(writer, &positions, &triangles, &point_values, &cell_values)?,
+            VtkFormat::Xml => write_xml(writer, &positions, &triangles, &point_values, &cell_values)?,
+        }
+
+        return Ok(());
+    }
+}
+
+impl Default for VtkWriter {
+    #[inline]
+    fn default() -> Self {
+        return Self::new();
+    }
+}
+
+/// Writes mesh without fields, in format selected by [VtkWriter::with_format]
+impl<TMesh: Mesh> MeshWriter<TMesh> for VtkWriter {
+    #[inline]
+    fn supports_extension(&self, extension: &str) -> bool {
+        return extension == self.format.extension();
+    }
+
+    fn write(&self, mesh: &TMesh, writer: &mut dyn Write) -> Result<(), MeshIoError> {
+        let mut writer = BufWriter::new(writer);
+        self.write_vtk(mesh, &[], &[], &mut writer)?;
+        writer.flush()?;
+
+        return Ok(());
+    }
+}
+
+/// Named values of field
+type FieldValues<'a> = (&'a str, Vec<f64>);
+
+fn write_legacy<TBuffer: Write>(
+    writer: &mut BufWriter<TBuffer>,
+    positions: &[[f64; 3]],
+    triangles: &[[usize; 3]],
+    point_data: &[FieldValues],
+    cell_data: &[FieldValues]
+) -> io::Result<()> {
+    writeln!(writer, "# vtk DataFile Version 3.0")?;
+    writeln!(writer, "baby_shark mesh")?;
+    writeln!(writer, "ASCII")?;
+    writeln!(writer, "DATASET POLYDATA")?;
+
+    writeln!(writer, "POINTS {} double", positions.len())?;
+    for [x, y, z] in positions {
+        writeln!(writer, "{} {} {}", x, y, z)?;
+    }
+
+    writeln!(writer, "POLYGONS {} {}", triangles.len(), triangles.len() * 4)?;
+    for [v1, v2, v3] in triangles {
+        writeln!(writer, "3 {} {} {}", v1, v2, v3)?;
+    }
+
+    for (header, count, fields) in [("POINT_DATA", positions.len(), point_data), ("CELL_DATA", triangles.len(), cell_data)] {
+        if fields.is_empty() {
+            continue;
+        }
+
+        writeln!(writer, "{} {}", header, count)?;
+
+        for (name, values) in fields {
+            // Legacy format doesn't allow whitespaces in names
+            let name: String = name.chars().map(|c| if c.is_whitespace() { '_' } else { c }).collect();
+
+            writeln!(writer, "SCALARS {} double 1", name)?;
+            writeln!(writer, "LOOKUP_TABLE default")?;
+
+            for value in values {
+                writeln!(writer, "{}", value)?;
+            }
+        }
+    }
+
+    return Ok(());
+}
+
+fn write_xml<TBuffer: Write>(
+    writer: &mut BufWriter<TBuffer>,
+    positions: &[[f64; 3]],
+    triangles: &[[usize; 3]],
+    point_data: &[FieldValues],
+    cell_data: &[FieldValues]
+) -> io::Result<()> {
+    writeln!(writer, "<?xml version=\"1.0\"?>")?;
+    writeln!(writer, "<VTKFile type=\"UnstructuredGrid\" version=\"0.1\" byte_order=\"LittleEndian\">")?;
+    writeln!(writer, "  <UnstructuredGrid>")?;
+    writeln!(writer, "    <Piece NumberOfPoints=\"{}\" NumberOfCells=\"{}\">", positions.len(), triangles.len())?;
+
+    for (tag, fields) in [("PointData", point_data), ("CellData", cell_data)] {
+        match fields.first() {
+            Some((name, _)) => writeln!(writer, "      <{} Scalars=\"{}\">", tag, escape_xml(name))?,
+            None => writeln!(writer, "      <{}>", tag)?,
+        }
+
+        for (name, values) in fields {
+            writeln!(writer, "        <DataArray type=\"Float64\" Name=\"{}\" format=\"ascii\">", escape_xml(name))?;
+            write_values(writer, values.iter())?;
+            writeln!(writer, "        </DataArray>")?;
+        }
+
+        writeln!(writer, "      </{}>", tag)?;
+    }
+
+    writeln!(writer, "      <Points>")?;
+    writeln!(writer, "        <DataArray type=\"Float64\" NumberOfComponents=\"3\" format=\"ascii\">")?;
+    write_values(writer, positions.iter().flatten())?;
+    writeln!(writer, "        </DataArray>")?;
+    writeln!(writer, "      </Points>")?;
+
+    // All cells are triangles (VTK_TRIANGLE = 5)
+    writeln!(writer, "      <Cells>")?;
+    writeln!(writer, "        <DataArray type=\"Int64\" Name=\"connectivity\" format=\"ascii\">")?;
+    write_values(writer, triangles.iter().flatten())?;
+    writeln!(writer, "        </DataArray>")?;
+    writeln!(writer, "        <DataArray type=\"Int64\" Name=\"offsets\" format=\"ascii\">")?;
+    write_values(writer, (1..=triangles.len()).map(|face| face * 3))?;
+    writeln!(writer, "        </DataArray>")?;
+    writeln!(writer, "        <DataArray type=\"UInt8\" Name=\"types\" format=\"ascii\">")?;
+    write_values(writer, triangles.iter().map(|_| 5))?;
+    writeln!(writer, "        </DataArray>")?;
+    writeln!(writer, "      </Cells>")?;
+
+    writeln!(writer, "    </Piece>")?;
+    writeln!(writer, "  </UnstructuredGrid>")?;
+    writeln!(writer, "</VTKFile>")?;
+
+    return Ok(());
+}
+
+/// Writes values of data array, several per line
+fn write_values<TBuffer: Write, TValue: std::fmt::Display, TValues: Iterator<Item = TValue>>(writer: &mut BufWriter<TBuffer>, values: TValues) -> io::Result<()> {
+    const VALUES_PER_LINE: usize = 9;
+
+    for (index, value) in values.enumerate() {
+        if index % VALUES_PER_LINE == 0 {
+            if index > 0 {
+                writeln!(writer)?;
+            }
+
+            write!(writer, "          {}", value)?;
+        } else {
+            write!(writer, " {}", value)?;
+        }
+    }
+
+    return writeln!(writer);
+}
+
+fn escape_xml(text: &str) -> String {
+    return text
+        .replace('&', "&amp;")
+        .replace('<', "&lt;")
+        .replace('>', "&gt;")
+        .replace('"', "&quot;");
+}
+
+#[cfg(test)]
+mod tests {
+    use std::io::BufWriter;
+
+    use crate::mesh::{corner_table::test_helpers::create_unit_cross_square_mesh, traits::{Mesh, VertexProperties, FaceProperties}};
+
+    use super::{VtkWriter, VtkFormat, VtkField};
+
+    fn write(format: VtkFormat) -> String {
+        let mesh = create_unit_cross_square_mesh();
+
+        let mut height = mesh.create_vertex_properties_map();
+        for vertex in mesh.vertices() {
+            height[vertex] = mesh.vertex_position(&vertex).y as f64;
+        }
+
+        let mut area = mesh.create_face_properties_map();
+        for (index, face) in mesh.faces().enumerate() {
+            area[face] = index as f64 + 0.5;
+        }
+
+        let mut buffer = BufWriter::new(Vec::new());
+        VtkWriter::new()
+            .with_format(format)
+            .write_vtk(
+                &mesh,
+                &[VtkField { name: "height", values: &height }],
+                &[VtkField { name: "face area", values: &area }],
+                &mut buffer
+            )
+            .expect("Write VTK");
+
+        return String::from_utf8(buffer.into_inner().unwrap()).unwrap();
+    }
+
+    #[test]
+    fn legacy() {
+        let vtk = write(VtkFormat::Legacy);
+        let lines: Vec<_> = vtk.lines().collect();
+
+        assert_eq!(lines[0], "# vtk DataFile Version 3.0");
+        assert!(lines.contains(&"POINTS 5 double"));
+        assert!(lines.contains(&"POLYGONS 4 16"));
+        assert!(lines.contains(&"POINT_DATA 5"));
+        assert!(lines.contains(&"SCALARS height double 1"));
+        assert!(lines.contains(&"CELL_DATA 4"));
+        assert!(lines.contains(&"SCALARS face_area double 1"));
+        assert_eq!(lines.iter().filter(|line| line.starts_with("3 ")).count(), 4);
+        assert_eq!(lines.last(), Some(&"3.5"));
+    }
+
+    #[test]
+    fn xml() {
+        let vtu = write(VtkFormat::Xml);
+
+        assert!(vtu.contains("<Piece NumberOfPoints=\"5\" NumberOfCells=\"4\">"));
+        assert!(vtu.contains("<PointData Scalars=\"height\">"));
+        assert!(vtu.contains("<DataArray type=\"Float64\" Name=\"face area\" format=\"ascii\">\n          0.5 1.5 2.5 3.5\n"));
+        assert!(vtu.contains("<DataArray type=\"Int64\" Name=\"offsets\" format=\"ascii\">\n          3 6 9 12\n"));
+        assert!(vtu.trim_end().ends_with("</VTKFile>"));
+    }
+}
@@ -0,0 +1,729 @@
+use nalgebra::Point3;
+use num_traits::Float;
+
+use crate::{geometry::{traits::RealNumber, primitives::triangle3::Triangle3}, mesh::traits::Mesh};
+use super::{
+    table::CornerTable,
+    traversal::{CornerWalker, collect_corners_around_vertex},
+    connectivity::corner::{next, previous, face_corners},
+    descriptors::{ElementIndex, VertexId, FaceId, CornerId}
+};
+
+///
+/// Custom interpolation rule. Receives source values with their weights and writes interpolated value to output slice.
+/// Weights always sum up to one, but they form affine rather than convex combination:
+/// * vertices, and corners created by split and flip operations, get non-negative weights;
+/// * corners around edge collapsed by [collapse_edge](crate::mesh::traits::EditableMesh::collapse_edge) get
+///   `[(corner, 1), (other_end, t), (own_end, -t)]` with `t` in `[0, 1]`, i.e. own value shifted by difference
+///   of values across collapsed edge within its face, so attribute seams are preserved.
+///
+/// Callbacks that can't handle negative weights should ignore sources with non-positive weight.
+///
+pub type InterpolationCallback<TScalar> = Box<dyn Fn(&[(&[TScalar], TScalar)], &mut [TScalar]) + Send + Sync>;
+
+///
+/// Defines how attribute values of vertices/corners created or modified by mesh editing are computed from old ones
+///
+pub enum Interpolation<TScalar> {
+    /// Weighted sum of source values
+    Linear,
+    /// Value of source with largest weight, e.g. for material or segment ids
+    Nearest,
+    /// Custom rule, see [InterpolationCallback] for weights passed to it.
+    /// Closure can't be serialized, so channels using it are skipped when corner table is serialized.
+    Custom(InterpolationCallback<TScalar>)
+}
+
+/// Handle of per-vertex attribute channel
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct VertexAttribute(usize);
+
+/// Handle of per-corner attribute channel
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct CornerAttribute(usize);
+
+/// Values of one attribute for all vertices or corners
+pub(super) struct AttributeChannel<TScalar> {
+    name: String,
+    dimension: usize,
+    interpolation: Interpolation<TScalar>,
+    values: Vec<TScalar>
+}
+
+impl<TScalar: RealNumber> AttributeChannel<TScalar> {
+    #[inline]
+    fn get(&self, index: usize) -> &[TScalar] {
+        return &self.values[index * self.dimension..(index + 1) * self.dimension];
+    }
+
+    #[inline]
+    fn get_mut(&mut self, index: usize) -> &mut [TScalar] {
+        return &mut self.values[index * self.dimension..(index + 1) * self.dimension];
+    }
+
+    fn interpolate(&self, sources: &[(usize, TScalar)], output: &mut [TScalar]) {
+        match &self.interpolation {
+            Interpolation::Linear => {
+                output.fill(TScalar::zero());
+
+                for (source, weight) in sources {
+                    for (out, value) in output.iter_mut().zip(self.get(*source)) {
+                        *out += *value * *weight;
+                    }
+                }
+            },
+            Interpolation::Nearest => {
+                let nearest = sources.iter()
+                    .fold(None, |nearest: Option<&(usize, TScalar)>, source| {
+                        match nearest {
+                            Some(current) if current.1 >= source.1 => return Some(current),
+                            _ => return Some(source),
+                        }
+                    });
+
+                if let Some((source, _)) = nearest {
+                    output.copy_from_slice(self.get(*source));
+                }
+            },
+            Interpolation::Custom(callback) => {
+                let sources: Vec<_> = sources.iter().map(|(source, weight)| (self.get(*source), *weight)).collect();
+                callback(&sources, output);
+            },
+        }
+    }
+
+    /// Computes all targets from current values first, so targets can be sources as well
+    fn apply(&mut self, rules: &[(usize, Vec<(usize, TScalar)>)]) {
+        let mut results = vec![TScalar::zero(); rules.len() * self.dimension];
+
+        for (result, (_, sources)) in results.chunks_exact_mut(self.dimension).zip(rules) {
+            self.interpolate(sources, result);
+        }
+
+        for (result, (target, _)) in results.chunks_exact(self.dimension).zip(rules) {
+            self.get_mut(*target).copy_from_slice(result);
+        }
+    }
+}
+
+/// Attribute channels registered on corner table
+pub(super) struct Attributes<TScalar> {
+    vertex: Vec<AttributeChannel<TScalar>>,
+    corner: Vec<AttributeChannel<TScalar>>
+}
+
+impl<TScalar: RealNumber> Attributes<TScalar> {
+    #[inline]
+    pub fn is_empty(&self) -> bool {
+        return self.vertex.is_empty() && self.corner.is_empty();
+    }
+
+    #[inline]
+    pub fn push_vertex(&mut self) {
+        for channel in &mut self.vertex {
+            channel.values.resize(channel.values.len() + channel.dimension, TScalar::zero());
+        }
+    }
+
+    #[inline]
+    pub fn push_corner(&mut self) {
+        for channel in &mut self.corner {
+            channel.values.resize(channel.values.len() + channel.dimension, TScalar::zero());
+        }
+    }
+
+    /// Keeps values of vertices and corners for which predicate returns `true`
+    pub fn retain<TVertex: Fn(usize) -> bool, TCorner: Fn(usize) -> bool>(&mut self, keep_vertex: TVertex, keep_corner: TCorner) {
+        for (channels, keep) in [(&mut self.vertex, &keep_vertex as &dyn Fn(usize) -> bool), (&mut self.corner, &keep_corner)] {
+            for channel in channels.iter_mut() {
+                let dimension = channel.dimension;
+                let mut index = 0;
+
+                channel.values.retain(|_| {
+                    index += 1;
+                    return keep((index - 1) / dimension);
+                });
+            }
+        }
+    }
+
+    /// Returns copy of channels that can be serialized, i.e. without ones with [Interpolation::Custom]
+    #[cfg(feature = "serde")]
+    pub fn serializable(&self) -> Self {
+        let copy = |channels: &[AttributeChannel<TScalar>]| {
+            return channels.iter()
+                .filter_map(|channel| {
+                    let interpolation = match channel.interpolation {
+                        Interpolation::Linear => Interpolation::Linear,
+                        Interpolation::Nearest => Interpolation::Nearest,
+                        Interpolation::Custom(_) => return None,
+                    };
+
+                    return Some(AttributeChannel { name: channel.name.clone(), dimension: channel.dimension, interpolation, values: channel.values.clone() });
+                })
+                .collect();
+        };
+
+        return Self {
+            vertex: copy(&self.vertex),
+            corner: copy(&self.corner)
+        };
+    }
+
+    /// Resizes channels to given number of vertices and corners, new values are zero
+    pub fn resize(&mut self, vertices_count: usize, corners_count: usize) {
+        for (channels, count) in [(&mut self.vertex, vertices_count), (&mut self.corner, corners_count)] {
+            for channel in channels.iter_mut() {
+                channel.values.resize(count * channel.dimension, TScalar::zero());
+            }
+        }
+    }
+
+    /// Returns values of all vertex channels for given vertex
+    pub fn vertex_values(&self, vertex: usize) -> Vec<TScalar> {
+        return self.vertex.iter().flat_map(|channel| channel.get(vertex).iter().copied()).collect();
+    }
+
+    /// Sets values of all vertex channels, inverse of [Attributes::vertex_values]
+    pub fn set_vertex_values(&mut self, vertex: usize, values: &[TScalar]) {
+        set_values(&mut self.vertex, vertex, values);
+    }
+
+    /// Returns values of all corner channels for given corner
+    pub fn corner_values(&self, corner: usize) -> Vec<TScalar> {
+        return self.corner.iter().flat_map(|channel| channel.get(corner).iter().copied()).collect();
+    }
+
+    /// Sets values of all corner channels, inverse of [Attributes::corner_values]
+    pub fn set_corner_values(&mut self, corner: usize, values: &[TScalar]) {
+        set_values(&mut self.corner, corner, values);
+    }
+
+    fn apply(&mut self, rules: &InterpolationRules<TScalar>) {
+        for channel in &mut self.vertex {
+            channel.apply(&rules.vertices);
+        }
+
+        for channel in &mut self.corner {
+            channel.apply(&rules.corners);
+        }
+    }
+}
+
+fn set_values<TScalar: RealNumber>(channels: &mut [AttributeChannel<TScalar>], index: usize, values: &[TScalar]) {
+    let mut offset = 0;
+
+    for channel in channels {
+        let dimension = channel.dimension;
+        channel.get_mut(index).copy_from_slice(&values[offset..offset + dimension]);
+        offset += dimension;
+    }
+}
+
+impl<TScalar> Default for Attributes<TScalar> {
+    #[inline]
+    fn default() -> Self {
+        return Self {
+            vertex: Vec::new(),
+            corner: Vec::new()
+        };
+    }
+}
+
+#[cfg(feature = "serde")]
+mod serialization {
+    use std::borrow::Cow;
+    use serde::{Serialize, Deserialize, Serializer, Deserializer};
+
+    use crate::geometry::traits::RealNumber;
+    use super::{Attributes, AttributeChannel, Interpolation};
+
+    /// Interpolation of serialized channel
+    #[derive(Serialize, Deserialize)]
+    enum ChannelInterpolation {
+        Linear,
+        Nearest
+    }
+
+    #[derive(Serialize, Deserialize)]
+    struct Channel<'a, TScalar: Clone> {
+        name: Cow<'a, str>,
+        dimension: usize,
+        interpolation: ChannelInterpolation,
+        values: Cow<'a, [TScalar]>
+    }
+
+    #[derive(Serialize, Deserialize)]
+    #[serde(bound(serialize = "TScalar: Serialize", deserialize = "TScalar: Deserialize<'de>"))]
+    struct Channels<'a, TScalar: Clone> {
+        vertex: Vec<Channel<'a, TScalar>>,
+        corner: Vec<Channel<'a, TScalar>>
+    }
+
+    /// Channels with custom interpolation are skipped
+    fn to_serialized<TScalar: RealNumber>(channels: &[AttributeChannel<TScalar>]) -> Vec<Channel<'_, TScalar>> {
+        return channels.iter()
+            .filter_map(|channel| {
+                let interpolation = match channel.interpolation {
+                    Interpolation::Linear => ChannelInterpolation::Linear,
+                    Interpolation::Nearest => ChannelInterpolation::Nearest,
+                    Interpolation::Custom(_) => return None,
+                };
+
+                return Some(Channel {
+                    name: Cow::Borrowed(&channel.name),
+                    dimension: channel.dimension,
+                    interpolation,
+                    values: Cow::Borrowed(&channel.values)
+                });
+            })
+            .collect();
+    }
+
+    fn from_serialized<TScalar: RealNumber>(channels: Vec<Channel<'_, TScalar>>) -> Vec<AttributeChannel<TScalar>> {
+        return channels.into_iter()
+            .map(|channel| AttributeChannel {
+                name: channel.name.into_owned(),
+                dimension: channel.dimension,
+                interpolation: match channel.interpolation {
+                    ChannelInterpolation::Linear => Interpolation::Linear,
+                    ChannelInterpolation::Nearest => Interpolation::Nearest,
+                },
+                values: channel.values.into_owned()
+            })
+            .collect();
+    }
+
+    impl<TScalar: RealNumber + Serialize> Serialize for Attributes<TScalar> {
+        fn serialize<TSerializer: Serializer>(&self, serializer: TSerializer) -> Result<TSerializer::Ok, TSerializer::Error> {
+            let channels = Channels {
+                vertex: to_serialized(&self.vertex),
+                corner: to_serialized(&self.corner)
+            };
+
+            return channels.serialize(serializer);
+        }
+    }
+
+    impl<'de, TScalar: RealNumber + Deserialize<'de>> Deserialize<'de> for Attributes<TScalar> {
+        fn deserialize<TDeserializer: Deserializer<'de>>(deserializer: TDeserializer) -> Result<Self, TDeserializer::Error> {
+            let channels = Channels::<TScalar>::deserialize(deserializer)?;
+
+            return Ok(Self {
+                vertex: from_serialized(channels.vertex),
+                corner: from_serialized(channels.corner)
+            });
+        }
+    }
+}
+
+/// Targets of interpolation with their sources and weights
+pub(super) struct InterpolationRules<TScalar> {
+    vertices: Vec<(usize, Vec<(usize, TScalar)>)>,
+    corners: Vec<(usize, Vec<(usize, TScalar)>)>
+}
+
+impl<TScalar: RealNumber, TIndex: ElementIndex> CornerTable<TScalar, TIndex> {
+    ///
+    /// Registers per-vertex attribute channel with `dimension` components per vertex (e.g. 3 for color).
+    /// Values are initialized with zeros and are updated by mesh editing operations according to `interpolation`.
+    /// Channels are serialized together with corner table, except ones with [Interpolation::Custom].
+    /// Skipping shifts handles of channels registered after them, use [Self::find_vertex_attribute] after deserialization.
+    ///
+    pub fn add_vertex_attribute(&mut self, name: &str, dimension: usize, interpolation: Interpolation<TScalar>) -> VertexAttribute {
+        let channel = AttributeChannel {
+            name: name.to_string(),
+            dimension,
+            interpolation,
+            values: vec![TScalar::zero(); self.vertices.len() * dimension]
+        };
+        self.attributes.vertex.push(channel);
+
+        return VertexAttribute(self.attributes.vertex.len() - 1);
+    }
+
+    ///
+    /// Registers per-corner attribute channel with `dimension` components per corner.
+    /// Unlike per-vertex attributes, corners of same vertex can have different values, e.g. UVs on seams.
+    ///
+    pub fn add_corner_attribute(&mut self, name: &str, dimension: usize, interpolation: Interpolation<TScalar>) -> CornerAttribute {
+        let channel = AttributeChannel {
+            name: name.to_string(),
+            dimension,
+            interpolation,
+            values: vec![TScalar::zero(); self.corners.len() * dimension]
+        };
+        self.attributes.corner.push(channel);
+
+        return CornerAttribute(self.attributes.corner.len() - 1);
+    }
+
+    /// Returns handle of per-vertex attribute with given name
+    pub fn find_vertex_attribute(&self, name: &str) -> Option<VertexAttribute> {
+        return self.attributes.vertex.iter().position(|channel| channel.name == name).map(VertexAttribute);
+    }
+
+    /// Returns handle of per-corner attribute with given name
+    pub fn find_corner_attribute(&self, name: &str) -> Option<CornerAttribute> {
+        return self.attributes.corner.iter().position(|channel| channel.name == name).map(CornerAttribute);
+    }
+
+    #[inline]
+    pub fn vertex_attribute(&self, attribute: VertexAttribute, vertex: VertexId<TIndex>) -> &[TScalar] {
+        return self.attributes.vertex[attribute.0].get(vertex.index());
+    }
+
+    #[inline]
+    pub fn vertex_attribute_mut(&mut self, attribute: VertexAttribute, vertex: VertexId<TIndex>) -> &mut [TScalar] {
+        return self.attributes.vertex[attribute.0].get_mut(vertex.index());
+    }
+
+    #[inline]
+    pub fn corner_attribute(&self, attribute: CornerAttribute, corner: CornerId<TIndex>) -> &[TScalar] {
+        return self.attributes.corner[attribute.0].get(corner.index());
+    }
+
+    #[inline]
+    pub fn corner_attribute_mut(&mut self, attribute: CornerAttribute, corner: CornerId<TIndex>) -> &mut [TScalar] {
+        return self.attributes.corner[attribute.0].get_mut(corner.index());
+    }
+
+    /// Interpolates attributes after editing operation
+    #[inline]
+    pub(super) fn interpolate_attributes(&mut self, rules: Option<InterpolationRules<TScalar>>) {
+        if let Some(rules) = rules {
+            self.attributes.apply(&rules);
+        }
+    }
+
+    ///
+    /// Interpolation rules for [split_edge](crate::mesh::traits::EditableMesh::split_edge).
+    /// Second vertex of split face becomes split point, new vertex takes its old place (see `split_inner_edge`).
+    ///
+    pub(super) fn split_edge_rules(&self, corner_index: CornerId<TIndex>, at: &Point3<TScalar>) -> Option<InterpolationRules<TScalar>> {
+        if self.attributes.is_empty() {
+            return None;
+        }
+
+        let c1 = corner_index;
+        let c2 = next(c1);
+        let c0 = previous(c1);
+        let v2 = self.corners[c2].get_vertex_index();
+        let v0 = self.corners[c0].get_vertex_index();
+        let t = edge_parameter(self.vertices[v2].get_position(), self.vertices[v0].get_position(), at);
+        let s = TScalar::one() - t;
+
+        let opposite = self.corners[c1].get_opposite_corner_index();
+        let (c0, c1, c2, v0, v2) = (c0.index(), c1.index(), c2.index(), v0.index(), v2.index());
+
+        let new_corner = self.corners.len();
+        let new_vertex = self.vertices.len();
+
+        let mut corners = vec![
+            (c2, vec![(c2, s), (c0, t)]),
+            (new_corner, vec![(c1, TScalar::one())]),
+            (new_corner + 1, vec![(c2, TScalar::one())]),
+            (new_corner + 2, vec![(c2, s), (c0, t)])
+        ];
+
+        if let Some(opposite) = opposite {
+            // Corners of other face at ends of split edge and its tip
+            let c3 = previous(opposite).index();
+            let c5 = next(opposite).index();
+            let opposite = opposite.index();
+
+            corners.extend([
+                (c3, vec![(c3, s), (c5, t)]),
+                (new_corner + 3, vec![(c3, TScalar::one())]),
+                (new_corner + 4, vec![(opposite, TScalar::one())]),
+                (new_corner + 5, vec![(c3, s), (c5, t)])
+            ]);
+        }
+
+        return Some(InterpolationRules {
+            vertices: vec![
+                (new_vertex, vec![(v2, TScalar::one())]),
+                (v2, vec![(v2, s), (v0, t)])
+            ],
+            corners
+        });
+    }
+
+    ///
+    /// Interpolation rules for [collapse_edge](crate::mesh::traits::EditableMesh::collapse_edge).
+    /// Corners of both edge ends are shifted by difference of values across collapsed edge,
+    /// so continuous attributes are interpolated linearly and nearest rule keeps value of corner.
+    ///
+    pub(super) fn collapse_edge_rules(&self, corner_index: CornerId<TIndex>, at: &Point3<TScalar>) -> Option<InterpolationRules<TScalar>> {
+        if self.attributes.is_empty() {
+            return None;
+        }
+
+        let c8 = next(corner_index);
+        let c9 = previous(corner_index);
+        let v8 = self.corners[c8].get_vertex_index();
+        let v9 = self.corners[c9].get_vertex_index();
+        let t = edge_parameter(self.vertices[v8].get_position(), self.vertices[v9].get_position(), at);
+        let s = TScalar::one() - t;
+
+        let (c8, c9) = (c8.index(), c9.index());
+        let mut corners = Vec::new();
+
+        for corner in collect_corners_around_vertex(self, v8) {
+            corners.push((corner.index(), vec![(corner.index(), TScalar::one()), (c9, t), (c8, -t)]));
+        }
+
+        for corner in collect_corners_around_vertex(self, v9) {
+            corners.push((corner.index(), vec![(corner.index(), TScalar::one()), (c8, s), (c9, -s)]));
+        }
+
+        return Some(InterpolationRules {
+            vertices: vec![(v8.index(), vec![(v8.index(), s), (v9.index(), t)])],
+            corners
+        });
+    }
+
+    /// Interpolation rules for [flip_edge](crate::mesh::traits::EditableMesh::flip_edge). Corners take values of corners of same vertices.
+    pub(super) fn flip_edge_rules(&self, corner_index: CornerId<TIndex>) -> Option<InterpolationRules<TScalar>> {
+        if self.attributes.is_empty() {
+            return None;
+        }
+
+        let mut walker = CornerWalker::from_corner(self, corner_index);
+        let c1 = walker.get_corner_index();
+        let c2 = walker.next().get_corner_index();
+        let c0 = walker.next().get_corner_index();
+        let c4 = walker.next().opposite().get_corner_index();
+        let c5 = walker.next().get_corner_index();
+        let c3 = walker.next().get_corner_index();
+
+        let [c0, c1, c2, c3, c4, c5] = [c0, c1, c2, c3, c4, c5].map(|corner| corner.index());
+        let one = TScalar::one();
+
+        return Some(InterpolationRules {
+            vertices: Vec::new(),
+            corners: vec![
+                (c0, vec![(c1, one)]),
+                (c1, vec![(c2, one)]),
+                (c2, vec![(c4, one)]),
+                (c3, vec![(c4, one)]),
+                (c4, vec![(c5, one)]),
+                (c5, vec![(c1, one)])
+            ]
+        });
+    }
+
+    /// Interpolation rules copying values from source to target vertices and corners, given as `(target, source)` pairs
+    pub(super) fn copy_rules(&self, vertices: Vec<(usize, usize)>, corners: Vec<(usize, usize)>) -> Option<InterpolationRules<TScalar>> {
+        if self.attributes.is_empty() {
+            return None;
+        }
+
+        let copy = |(target, source)| (target, vec![(source, TScalar::one())]);
+
+        return Some(InterpolationRules {
+            vertices: vertices.into_iter().map(copy).collect(),
+            corners: corners.into_iter().map(copy).collect()
+        });
+    }
+
+    /// Interpolation rules for [split_face](crate::mesh::traits::SplitFaceAtPoint::split_face). Values at split point are barycentric interpolation.
+    pub(super) fn split_face_rules(&self, face: FaceId<TIndex>, point: &Point3<TScalar>) -> Option<InterpolationRules<TScalar>> {
+        if self.attributes.is_empty() {
+            return None;
+        }
+
+        let [c0, c1, c2] = face_corners(face).map(|corner| corner.index());
+        let (v0, v1, v2) = self.face_vertices(&face);
+        let (v0, v1, v2) = (v0.index(), v1.index(), v2.index());
+
+        let triangle = self.face_positions(&face);
+        let third = TScalar::one() / TScalar::from_f64(3.0).unwrap();
+        let weights = if Triangle3::is_degenerate(triangle.p1(), triangle.p2(), triangle.p3()) {
+            [third; 3]
+        } else {
+            let barycentric = triangle.barycentric(point);
+            [barycentric.u(), barycentric.v(), barycentric.w()]
+        };
+
+        let weights = weights.map(|weight| if Float::is_finite(weight) { weight } else { third });
+        let at_point = vec![(c0, weights[0]), (c1, weights[1]), (c2, weights[2])];
+        let new_corner = self.corners.len();
+        let one = TScalar::one();
+
+        return Some(InterpolationRules {
+            vertices: vec![(self.vertices.len(), vec![(v0, weights[0]), (v1, weights[1]), (v2, weights[2])])],
+            corners: vec![
+                (c2, at_point.clone()),
+                (new_corner, vec![(c1, one)]),
+                (new_corner + 1, vec![(c2, one)]),
+                (new_corner + 2, at_point.clone()),
+                (new_corner + 3, vec![(c2, one)]),
+                (new_corner + 4, vec![(c0, one)]),
+                (new_corner + 5, at_point)
+            ]
+        });
+    }
+}
+
+/// Returns parameter of projection of `point` on segment from `start` to `end`, clamped to [0, 1]
+fn edge_parameter<TScalar: RealNumber>(start: &Point3<TScalar>, end: &Point3<TScalar>, point: &Point3<TScalar>) -> TScalar {
+    let direction = end - start;
+    let length_squared = direction.norm_squared();
+
+    if length_squared == TScalar::zero() {
+        return TScalar::from_f64(0.5).unwrap();
+    }
+
+    let t = (point - start).dot(&direction) / length_squared;
+    return Float::min(Float::max(t, TScalar::zero()), TScalar::one());
+}
+
+#[cfg(test)]
+mod tests {
+    use std::sync::{Arc, Mutex};
+    use nalgebra::Point3;
+
+    use crate::mesh::{
+        corner_table::{
+            prelude::CornerTableF,
+            test_helpers::{create_unit_square_mesh, create_collapse_edge_sample_mesh1, create_flip_edge_sample_mesh},
+            descriptors::{EdgeRef, CornerId, VertexId, FaceId}
+        },
+        traits::{EditableMesh, Mesh, SplitFaceAtPoint}
+    };
+
+    use super::{Interpolation, VertexAttribute, CornerAttribute, face_corners};
+
+    /// Adds vertex and corner attributes equal to x and y coordinates of vertex
+    fn add_coordinate_attributes(mesh: &mut CornerTableF, interpolation: fn() -> Interpolation<f32>) -> (VertexAttribute, CornerAttribute) {
+        let vertex_attribute = mesh.add_vertex_attribute("xy", 2, interpolation());
+        let corner_attribute = mesh.add_corner_attribute("uv", 2, interpolation());
+
+        for vertex in mesh.vertices().collect::<Vec<_>>() {
+            let position = *mesh.vertex_position(&vertex);
+            mesh.vertex_attribute_mut(vertex_attribute, vertex).copy_from_slice(&[position.x, position.y]);
+        }
+
+        for corner in (0..mesh.corners.len()).map(CornerId::new) {
+            let position = *mesh.vertex_position(&mesh.corners[corner].get_vertex_index());
+            mesh.corner_attribute_mut(corner_attribute, corner).copy_from_slice(&[position.x, position.y]);
+        }
+
+        return (vertex_attribute, corner_attribute);
+    }
+
+    /// Checks that attributes of all vertices and corners are equal to coordinates
+    fn assert_attributes_match_positions(mesh: &CornerTableF, vertex_attribute: VertexAttribute, corner_attribute: CornerAttribute) {
+        for vertex in mesh.vertices() {
+            let position = mesh.vertex_position(&vertex);
+            let value = mesh.vertex_attribute(vertex_attribute, vertex);
+            assert!((value[0] - position.x).abs() < 1e-6 && (value[1] - position.y).abs() < 1e-6, "vertex {}: {:?}", vertex, value);
+        }
+
+        for face in mesh.faces() {
+            for corner in face_corners(face) {
+                let position = mesh.vertex_position(&mesh.corners[corner].get_vertex_index());
+                let value = mesh.corner_attribute(corner_attribute, corner);
+                assert!((value[0] - position.x).abs() < 1e-6 && (value[1] - position.y).abs() < 1e-6, "corner {}: {:?}", corner, value);
+            }
+        }
+    }
+
+    #[test]
+    fn split_edge() {
+        for corner in [0, 1, 2, 3] {
+            let mut mesh = create_unit_square_mesh();
+            let (vertex_attribute, corner_attribute) = add_coordinate_attributes(&mut mesh, || Interpolation::Linear);
+
+            let edge = EdgeRef::new(CornerId::new(corner), &mesh);
+            let (start, end) = mesh.edge_positions(&edge);
+            mesh.split_edge(&edge, &Point3::from((start.coords + end.coords * 3.0) / 4.0));
+
+            assert_attributes_match_positions(&mesh, vertex_attribute, corner_attribute);
+        }
+    }
+
+    #[test]
+    fn collapse_edge() {
+        let mut mesh = create_collapse_edge_sample_mesh1();
+        let (vertex_attribute, corner_attribute) = add_coordinate_attributes(&mut mesh, || Interpolation::Linear);
+
+        mesh.collapse_edge(&EdgeRef::new(CornerId::new(9), &mesh), &Point3::new(0.5, 0.5, 0.0));
+
+        assert_attributes_match_positions(&mesh, vertex_attribute, corner_attribute);
+    }
+
+    #[test]
+    fn flip_edge() {
+        let mut mesh = create_flip_edge_sample_mesh();
+        let (vertex_attribute, corner_attribute) = add_coordinate_attributes(&mut mesh, || Interpolation::Nearest);
+
+        mesh.flip_edge(&EdgeRef::new(CornerId::new(1), &mesh));
+
+        assert_attributes_match_positions(&mesh, vertex_attribute, corner_attribute);
+    }
+
+    #[test]
+    fn split_face() {
+        let mut mesh = create_unit_square_mesh();
+        let (vertex_attribute, corner_attribute) = add_coordinate_attributes(&mut mesh, || Interpolation::Linear);
+
+        mesh.split_face(&FaceId::new(0), Point3::new(0.2, 0.6, 0.0));
+
+        assert_attributes_match_positions(&mesh, vertex_attribute, corner_attribute);
+    }
+
+    #[test]
+    fn custom_interpolation_weights() {
+        let calls = Arc::new(Mutex::new(Vec::new()));
+        let recorded = calls.clone();
+
+        let mut mesh = create_collapse_edge_sample_mesh1();
+        mesh.add_corner_attribute("weights", 1, Interpolation::Custom(Box::new(move |sources, output| {
+            recorded.lock().unwrap().push(sources.iter().map(|(_, weight)| *weight).collect::<Vec<_>>());
+            output[0] = 0.0;
+        })));
+
+        mesh.collapse_edge(&EdgeRef::new(CornerId::new(9), &mesh), &Point3::new(0.25, 0.5, 0.0));
+
+        let calls = calls.lock().unwrap();
+        assert!(!calls.is_empty());
+
+        for weights in calls.iter() {
+            assert!((weights.iter().sum::<f32>() - 1.0).abs() < 1e-6);
+
+            // Own corner plus difference across collapsed edge
+            let [own, other_end, own_end] = weights[..] else { panic!("unexpected weights {:?}", weights) };
+            assert_eq!(own, 1.0);
+            assert_eq!(other_end, -own_end);
+            assert!((0.0..=1.0).contains(&other_end));
+        }
+    }
+
+    #[test]
+    fn nearest_and_custom_interpolation() {
+        let mut mesh = create_unit_square_mesh();
+        let nearest = mesh.add_vertex_attribute("id", 1, Interpolation::Nearest);
+        let max = mesh.add_vertex_attribute("max", 1, Interpolation::Custom(Box::new(|sources, output| {
+            output[0] = sources.iter().map(|(value, _)| value[0]).fold(f32::MIN, f32::max);
+        })));
+
+        for index in 0..4 {
+            mesh.vertex_attribute_mut(nearest, VertexId::new(index))[0] = index as f32;
+            mesh.vertex_attribute_mut(max, VertexId::new(index))[0] = index as f32 * 10.0;
+        }
+
+        let new_vertex = VertexId::new(mesh.vertices.len());
+        mesh.split_face(&FaceId::new(0), Point3::new(0.1, 0.8, 0.0));
+
+        assert_eq!(mesh.find_vertex_attribute("max"), Some(max));
+        assert_eq!(mesh.find_corner_attribute("max"), None);
+        // Max over vertices of split face
+        assert_eq!(mesh.vertex_attribute(max, new_vertex), &[20.0]);
+
+        // Split point is closest to first vertex of face
+        let nearest_vertex = mesh.corners[0].get_vertex_index().index() as f32;
+        assert_eq!(mesh.vertex_attribute(nearest, new_vertex), &[nearest_vertex]);
+    }
+}
@@ -0,0 +1,194 @@
+use crate::geometry::traits::RealNumber;
+use super::{
+    table::CornerTable,
+    connectivity::{corner::{face, first_corner}, traits::Flags},
+    descriptors::{ElementIndex, VertexId, FaceId, CornerId}
+};
+
+///
+/// Old to new index maps produced by [CornerTable::compact].
+/// Can be used to remap property maps and other data keyed by vertex or face descriptors.
+///
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct CompactionMap<TIndex: ElementIndex = usize> {
+    vertices: Vec<Option<VertexId<TIndex>>>,
+    faces: Vec<Option<FaceId<TIndex>>>
+}
+
+impl<TIndex: ElementIndex> CompactionMap<TIndex> {
+    /// Returns new index of vertex, `None` if vertex was deleted
+    #[inline]
+    pub fn vertex(&self, old_vertex: VertexId<TIndex>) -> Option<VertexId<TIndex>> {
+        return self.vertices.get(old_vertex.index()).copied().flatten();
+    }
+
+    /// Returns new index of face, `None` if face was deleted
+    #[inline]
+    pub fn face(&self, old_face: FaceId<TIndex>) -> Option<FaceId<TIndex>> {
+        return self.faces.get(old_face.index()).copied().flatten();
+    }
+
+    /// Returns new index of corner, `None` if corner was deleted
+    #[inline]
+    pub fn corner(&self, old_corner: CornerId<TIndex>) -> Option<CornerId<TIndex>> {
+        return self.face(face(old_corner))
+            .map(|new_face| CornerId::new(first_corner(new_face).index() + old_corner.index() % 3));
+    }
+
+    /// Number of vertices left after compaction
+    #[inline]
+    pub fn vertices_count(&self) -> usize {
+        return self.vertices.iter().flatten().count();
+    }
+
+    /// Number of faces left after compaction
+    #[inline]
+    pub fn faces_count(&self) -> usize {
+        return self.faces.iter().flatten().count();
+    }
+}
+
+impl<TScalar: RealNumber, TIndex: ElementIndex> CornerTable<TScalar, TIndex> {
+    /// Returns maps from current indices to indices without deleted vertices and faces
+    pub fn compaction_map(&self) -> CompactionMap<TIndex> {
+        let mut vertices_count = 0;
+        let vertices = self.vertices.iter()
+            .map(|vertex| {
+                if vertex.is_deleted() {
+                    return None;
+                }
+
+                vertices_count += 1;
+                return Some(VertexId::new(vertices_count - 1));
+            })
+            .collect();
+
+        // Faces are deleted as a whole, so it is enough to check first corner
+        let mut faces_count = 0;
+        let faces = self.corners.iter()
+            .step_by(3)
+            .map(|corner| {
+                if corner.is_deleted() {
+                    return None;
+                }
+
+                faces_count += 1;
+                return Some(FaceId::new(faces_count - 1));
+            })
+            .collect();
+
+        return CompactionMap { vertices, faces };
+    }
+
+    ///
+    /// Removes deleted vertices and faces, renumbers remaining ones preserving their order.
+    /// Returns old to new index maps.
+    ///
+    /// ## Example
+    /// ```ignore
+    /// let map = mesh.compact();
+    /// let mut new_props = mesh.create_vertex_properties_map();
+    /// for (old, value) in old_props {
+    ///     if let Some(new) = map.vertex(old) { new_props[new] = value; }
+    /// }
+    /// ```
+    ///
+    pub fn compact(&mut self) -> CompactionMap<TIndex> {
+        let map = self.compaction_map();
+
+        self.vertices.retain(|vertex| !vertex.is_deleted());
+        self.attributes.retain(
+            |vertex| map.vertex(VertexId::new(vertex)).is_some(),
+            |corner| map.corner(CornerId::new(corner)).is_some()
+        );
+
+        let mut corner_index = 0;
+        self.corners.retain(|_| {
+            corner_index += 1;
+            return map.corner(CornerId::new(corner_index - 1)).is_some();
+        });
+
+        for vertex in &mut self.vertices {
+            let corner = map.corner(vertex.get_corner_index()).unwrap_or(CornerId::INVALID);
+            vertex.set_corner_index(corner);
+        }
+
+        for corner in &mut self.corners {
+            let opposite = corner.get_opposite_corner_index().and_then(|opposite| map.corner(opposite));
+            let vertex = map.vertex(corner.get_vertex_index()).unwrap_or(VertexId::INVALID);
+
+            corner.set_opposite_corner_index(opposite);
+            corner.set_vertex_index(vertex);
+        }
+
+        return map;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use nalgebra::Point3;
+
+    use crate::mesh::{
+        corner_table::{
+            test_helpers::{create_collapse_edge_sample_mesh1, create_unit_square_mesh},
+            descriptors::{EdgeRef, CornerId, VertexId, FaceId},
+            connectivity::traits::Flags
+        },
+        traits::{EditableMesh, Mesh}
+    };
+
+    #[test]
+    fn compact_after_collapse() {
+        let mut mesh = create_collapse_edge_sample_mesh1();
+        mesh.collapse_edge(&EdgeRef::new(CornerId::new(9), &mesh), &Point3::new(0.5, 0.5, 0.0));
+
+        let faces_before: Vec<_> = mesh.faces().map(|face| mesh.face_vertices(&face)).collect();
+        let vertices_before = mesh.vertices().count();
+
+        let map = mesh.compact();
+
+        assert_eq!(mesh.vertices.len(), vertices_before);
+        assert_eq!(mesh.corners.len(), faces_before.len() * 3);
+        assert!(mesh.vertices.iter().all(|vertex| !vertex.is_deleted()));
+        assert!(mesh.corners.iter().all(|corner| !corner.is_deleted()));
+
+        // Deleted vertex and faces of collapsed edge
+        assert_eq!(map.vertex(VertexId::new(8)), None);
+        assert_eq!(map.vertex(VertexId::new(9)), Some(VertexId::new(8)));
+        assert_eq!(map.face(FaceId::new(3)), None);
+        assert_eq!(map.face(FaceId::new(8)), None);
+        assert_eq!(map.face(FaceId::new(9)), Some(FaceId::new(7)));
+        assert_eq!(map.corner(CornerId::new(29)), Some(CornerId::new(23)));
+        assert_eq!(map.vertices_count(), mesh.vertices.len());
+        assert_eq!(map.faces_count(), faces_before.len());
+
+        let faces_after: Vec<_> = mesh.faces().map(|face| mesh.face_vertices(&face)).collect();
+        let faces_remapped: Vec<_> = faces_before.iter()
+            .map(|(v1, v2, v3)| (map.vertex(*v1).unwrap(), map.vertex(*v2).unwrap(), map.vertex(*v3).unwrap()))
+            .collect();
+        assert_eq!(faces_after, faces_remapped);
+
+        for (index, corner) in mesh.corners.iter().enumerate() {
+            if let Some(opposite) = corner.get_opposite_corner_index() {
+                assert_eq!(mesh.corners[opposite].get_opposite_corner_index(), Some(CornerId::new(index)));
+            }
+        }
+
+        for vertex in mesh.vertices() {
+            let corner = mesh.get_vertex(vertex).unwrap().get_corner_index();
+            assert_eq!(mesh.corners[corner].get_vertex_index(), vertex);
+        }
+    }
+
+    #[test]
+    fn compact_without_deleted_elements() {
+        let mut mesh = create_unit_square_mesh();
+        let map = mesh.compact();
+
+        assert_eq!(map.vertex(VertexId::new(3)), Some(VertexId::new(3)));
+        assert_eq!(map.face(FaceId::new(1)), Some(FaceId::new(1)));
+        assert_eq!(mesh.vertices.len(), 4);
+        assert_eq!(mesh.corners.len(), 6);
+    }
+}
@@ -0,0 +1,264 @@
+use crate::{
+    geometry::{primitives::{box3::Box3, triangle3::Triangle3}, traits::{RealNumber, HasBBox3}},
+    mesh::traits::{Mesh, MeshMarker, Marker}
+};
+use super::{
+    table::CornerTable,
+    traversal::CornerWalker,
+    connectivity::{traits::Flags, corner::{first_corner, face_corners, face}},
+    descriptors::{ElementIndex, FaceId}
+};
+
+///
+/// Set of faces connected through shared edges, see [CornerTable::connected_components]
+///
+#[derive(Clone)]
+pub struct Component<TScalar: RealNumber, TIndex: ElementIndex = usize> {
+    faces: Vec<FaceId<TIndex>>,
+    area: TScalar,
+    bbox: Box3<TScalar>
+}
+
+impl<TScalar: RealNumber, TIndex: ElementIndex> Component<TScalar, TIndex> {
+    /// Faces of component
+    #[inline]
+    pub fn faces(&self) -> &[FaceId<TIndex>] {
+        return &self.faces;
+    }
+
+    #[inline]
+    pub fn faces_count(&self) -> usize {
+        return self.faces.len();
+    }
+
+    /// Total area of component faces
+    #[inline]
+    pub fn area(&self) -> TScalar {
+        return self.area;
+    }
+
+    /// Bounding box of component faces
+    #[inline]
+    pub fn bbox(&self) -> &Box3<TScalar> {
+        return &self.bbox;
+    }
+}
+
+///
+/// Labeling of mesh faces by connected components
+///
+pub struct ConnectedComponents<TScalar: RealNumber, TIndex: ElementIndex = usize> {
+    /// Component index of every face, `usize::MAX` for deleted faces
+    labels: Vec<usize>,
+    components: Vec<Component<TScalar, TIndex>>
+}
+
+impl<TScalar: RealNumber, TIndex: ElementIndex> ConnectedComponents<TScalar, TIndex> {
+    /// Returns index of component that contains given face
+    #[inline]
+    pub fn component_of(&self, face: FaceId<TIndex>) -> Option<usize> {
+        return self.labels.get(face.index())
+            .copied()
+            .filter(|label| *label != usize::MAX);
+    }
+
+    #[inline]
+    pub fn components(&self) -> &[Component<TScalar, TIndex>] {
+        return &self.components;
+    }
+
+    /// Number of components
+    #[inline]
+    pub fn len(&self) -> usize {
+        return self.components.len();
+    }
+
+    #[inline]
+    pub fn is_empty(&self) -> bool {
+        return self.components.is_empty();
+    }
+}
+
+impl<TScalar: RealNumber, TIndex: ElementIndex> CornerTable<TScalar, TIndex> {
+    ///
+    /// Labels faces by connected components. Two faces belong to same component when they are connected by chain of faces sharing edges.
+    /// Components are ordered by their first face.
+    ///
+    pub fn connected_components(&self) -> ConnectedComponents<TScalar, TIndex> {
+        let mut labels = vec![usize::MAX; self.corners.len() / 3];
+        let mut components = Vec::new();
+        let mut marker = self.marker();
+        let mut stack = Vec::new();
+
+        for seed in self.faces() {
+            if marker.is_face_marked(&seed) {
+                continue;
+            }
+
+            let mut component = Component {
+                faces: Vec::new(),
+                area: TScalar::zero(),
+                bbox: self.face_positions(&seed).bbox()
+            };
+
+            marker.mark_face(&seed, true);
+            stack.push(seed);
+
+            // Flood fill across edges
+            while let Some(current) = stack.pop() {
+                let triangle = self.face_positions(&current);
+                component.area += Triangle3::area(triangle.p1(), triangle.p2(), triangle.p3());
+                component.bbox.add_box3(&triangle.bbox());
+                component.faces.push(current);
+                labels[current.index()] = components.len();
+
+                let mut walker = CornerWalker::from_corner(self, first_corner(current));
+
+                for _ in 0..3 {
+                    if let Some(opposite) = walker.get_corner().get_opposite_corner_index() {
+                        let neighbor = face(opposite);
+
+                        if !marker.is_face_marked(&neighbor) {
+                            marker.mark_face(&neighbor, true);
+                            stack.push(neighbor);
+                        }
+                    }
+
+                    walker.next();
+                }
+            }
+
+            component.faces.sort_unstable();
+            components.push(component);
+        }
+
+        for face in self.faces() {
+            marker.mark_face(&face, false);
+        }
+
+        return ConnectedComponents { labels, components };
+    }
+
+    ///
+    /// Creates new corner table from faces of component. Attributes are not copied.
+    ///
+    #[inline]
+    pub fn extract_component(&self, component: &Component<TScalar, TIndex>) -> Self {
+        return self.extract_faces(component.faces()).0;
+    }
+
+    /// Splits mesh into separate corner tables, one per connected component
+    pub fn split_components(&self) -> Vec<Self> {
+        return self.connected_components().components()
+            .iter()
+            .map(|component| self.extract_component(component))
+            .collect();
+    }
+
+    ///
+    /// Removes components with less than `min_faces` faces or area smaller than `min_area`, can be used to clean up scan debris.
+    /// Returns number of removed components.
+    ///
+    pub fn remove_small_components(&mut self, min_faces: usize, min_area: TScalar) -> usize {
+        let components = self.connected_components();
+        let mut removed = 0;
+
+        for component in components.components() {
+            if component.faces_count() >= min_faces && component.area() >= min_area {
+                continue;
+            }
+
+            // Vertices are not shared between components, so whole fans are removed with faces
+            for face in component.faces() {
+                for corner in face_corners(*face) {
+                    let vertex = self.corners[corner].get_vertex_index();
+                    self.vertices[vertex].set_deleted(true);
+                    self.corners[corner].set_opposite_corner_index(None);
+                    self.corners[corner].set_deleted(true);
+                }
+            }
+
+            removed += 1;
+        }
+
+        return removed;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use nalgebra::Point3;
+
+    use crate::mesh::{
+        corner_table::{prelude::CornerTableF, test_helpers::create_torus_mesh, descriptors::{VertexId, FaceId}},
+        traits::Mesh
+    };
+
+    /// Unit square made of two faces and small triangle far away from it
+    fn create_two_components_mesh() -> CornerTableF {
+        let vertices = [
+            Point3::new(0.0, 1.0, 0.0),
+            Point3::new(0.0, 0.0, 0.0),
+            Point3::new(1.0, 0.0, 0.0),
+            Point3::new(1.0, 1.0, 0.0),
+            Point3::new(5.0, 5.0, 1.0),
+            Point3::new(5.1, 5.0, 1.0),
+            Point3::new(5.0, 5.1, 1.0)
+        ];
+
+        return CornerTableF::from_vertices_and_indices(&vertices, &[
+            0, 1, 2,
+            4, 5, 6,
+            2, 3, 0
+        ]);
+    }
+
+    #[test]
+    fn should_label_faces_by_components() {
+        let mesh = create_two_components_mesh();
+        let components = mesh.connected_components();
+
+        assert_eq!(components.len(), 2);
+        assert_eq!(components.component_of(FaceId::new(0)), Some(0));
+        assert_eq!(components.component_of(FaceId::new(1)), Some(1));
+        assert_eq!(components.component_of(FaceId::new(2)), Some(0));
+
+        let square = &components.components()[0];
+        assert_eq!(square.faces(), &[FaceId::new(0), FaceId::new(2)]);
+        assert!((square.area() - 1.0).abs() < 1e-6);
+        assert_eq!(square.bbox().get_min(), &Point3::new(0.0, 0.0, 0.0));
+        assert_eq!(square.bbox().get_max(), &Point3::new(1.0, 1.0, 0.0));
+
+        let triangle = &components.components()[1];
+        assert_eq!(triangle.faces_count(), 1);
+        assert!((triangle.area() - 0.005).abs() < 1e-6);
+
+        assert_eq!(create_torus_mesh(6, 5).connected_components().len(), 1);
+    }
+
+    #[test]
+    fn should_split_mesh_into_components() {
+        let mesh = create_two_components_mesh();
+        let parts = mesh.split_components();
+
+        assert_eq!(parts.len(), 2);
+        assert_eq!(parts[0].faces().count(), 2);
+        assert_eq!(parts[0].vertices().count(), 4);
+        assert_eq!(parts[1].faces().count(), 1);
+        assert_eq!(parts[1].vertex_position(&VertexId::new(0)), &Point3::new(5.0, 5.0, 1.0));
+        assert_eq!(parts[0].validate(), vec![]);
+    }
+
+    #[test]
+    fn should_remove_small_components() {
+        let mut mesh = create_two_components_mesh();
+        assert_eq!(mesh.remove_small_components(0, 0.1), 1);
+        assert_eq!(mesh.faces().count(), 2);
+        assert_eq!(mesh.vertices().count(), 4);
+        assert_eq!(mesh.validate(), vec![]);
+
+        let mut mesh = create_two_components_mesh();
+        assert_eq!(mesh.remove_small_components(3, 0.0), 2);
+        assert_eq!(mesh.faces().count(), 0);
+    }
+}
@@ -1,76 +1,122 @@
-use std::cell::UnsafeCell;
-
 use tabled::Tabled;
-use crate::helpers::display::{display_option, display_unsafecell};
+#[cfg(feature = "serde")]
+use serde::{Serialize, Deserialize};
+use crate::{helpers::display::display_index, mesh::corner_table::descriptors::{ElementIndex, CornerId, VertexId, FaceId}};
 use super::{traits::Flags, flags};
 
 ///
 /// Default implementation for Corner trait
 /// 
 #[derive(Debug, Tabled)]
-pub struct Corner {
-    #[tabled(display_with = "display_option")]
-    opposite_corner_index: Option<usize>,
-    vertex_index: usize,
-
-    #[tabled(display_with = "display_unsafecell")]
-    flags: UnsafeCell<flags::Flags>
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "serde", serde(bound(
+    serialize = "TIndex: Serialize",
+    deserialize = "TIndex: Deserialize<'de>"
+)))]
+pub struct Corner<TIndex: ElementIndex = usize> {
+    #[tabled(display_with = "display_index")]
+    #[cfg_attr(feature = "serde", serde(with = "serde_optional_index"))]
+    opposite_corner_index: TIndex,
+    vertex_index: TIndex,
+
+    #[cfg_attr(feature = "serde", serde(with = "flags::serde_cell"))]
+    flags: flags::FlagsCell
 }
 
-impl Corner {
+impl<TIndex: ElementIndex> Corner<TIndex> {
     pub fn new(
-        opposite_corner_index: Option<usize>,
-        vertex_index: usize,
+        opposite_corner_index: Option<CornerId<TIndex>>,
+        vertex_index: VertexId<TIndex>,
         flags: flags::Flags
     ) -> Self { 
         return Self { 
-            opposite_corner_index, 
-            vertex_index, 
-            flags: UnsafeCell::new(flags) 
+            opposite_corner_index: TIndex::from_usize(opposite_corner_index.unwrap_or(CornerId::INVALID).index()), 
+            vertex_index: TIndex::from_usize(vertex_index.index()), 
+            flags: flags::FlagsCell::new(flags) 
         };
     }
 
     #[inline]
-    pub fn get_opposite_corner_index(&self) -> Option<usize> {
-        return self.opposite_corner_index;
+    pub fn get_opposite_corner_index(&self) -> Option<CornerId<TIndex>> {
+        if self.opposite_corner_index == TIndex::INVALID {
+            return None;
+        }
+
+        return Some(CornerId::new(self.opposite_corner_index.to_usize()));
     }
 
     #[inline]
-    pub fn set_opposite_corner_index(&mut self, index: Option<usize>) -> &mut Self {
-        self.opposite_corner_index = index;
+    pub fn set_opposite_corner_index(&mut self, index: Option<CornerId<TIndex>>) -> &mut Self {
+        self.opposite_corner_index = TIndex::from_usize(index.unwrap_or(CornerId::INVALID).index());
         return self;
     }
 
     #[inline]
-    pub fn get_vertex_index(&self) -> usize {
-        return self.vertex_index;
+    pub fn get_vertex_index(&self) -> VertexId<TIndex> {
+        return VertexId::new(self.vertex_index.to_usize());
     }
 
     #[inline]
-    pub fn set_vertex_index(&mut self, index: usize) -> &mut Self {
-        self.vertex_index = index;
+    pub fn set_vertex_index(&mut self, index: VertexId<TIndex>) -> &mut Self {
+        self.vertex_index = TIndex::from_usize(index.index());
         return self;
     }
 }
 
-impl Default for Corner {
+/// Serializes missing index as `None`, so that format stays same as for `Option<usize>` index
+#[cfg(feature = "serde")]
+mod serde_optional_index {
+    use serde::{Serialize, Deserialize, Serializer, Deserializer};
+
+    use crate::mesh::corner_table::descriptors::ElementIndex;
+
+    pub fn serialize<TSerializer, TIndex>(index: &TIndex, serializer: TSerializer) -> Result<TSerializer::Ok, TSerializer::Error> 
+    where
+        TSerializer: Serializer,
+        TIndex: ElementIndex + Serialize
+    {
+        let index = if *index == TIndex::INVALID { None } else { Some(*index) };
+        return index.serialize(serializer);
+    }
+
+    pub fn deserialize<'de, TDeserializer, TIndex>(deserializer: TDeserializer) -> Result<TIndex, TDeserializer::Error> 
+    where
+        TDeserializer: Deserializer<'de>,
+        TIndex: ElementIndex + Deserialize<'de>
+    {
+        return Ok(Option::<TIndex>::deserialize(deserializer)?.unwrap_or(TIndex::INVALID));
+    }
+}
+
+impl<TIndex: ElementIndex> Default for Corner<TIndex> {
     fn default() -> Self {
         return Self {
-            opposite_corner_index:  None,
-            vertex_index:           usize::max_value(),
+            opposite_corner_index:  TIndex::INVALID,
+            vertex_index:           TIndex::INVALID,
             flags:                  Default::default() 
         };
     }
 }
 
-impl Flags for Corner {
+impl<TIndex: ElementIndex> Clone for Corner<TIndex> {
     #[inline]
-    fn get_flags(&self) -> &UnsafeCell<super::flags::Flags> {
+    fn clone(&self) -> Self {
+        return Self { 
+            opposite_corner_index: self.opposite_corner_index, 
+            vertex_index: self.vertex_index, 
+            flags: flags::FlagsCell::new(self.flags.get()) 
+        };
+    }
+}
+
+impl<TIndex: ElementIndex> Flags for Corner<TIndex> {
+    #[inline]
+    fn get_flags(&self) -> &super::flags::FlagsCell {
         return &self.flags;
     }
 }
 
-impl PartialEq for Corner {
+impl<TIndex: ElementIndex> PartialEq for Corner<TIndex> {
     #[inline]
     fn eq(&self, other: &Self) -> bool {
         return 
@@ -78,36 +124,46 @@ impl PartialEq for Corner {
             self.vertex_index          == other.vertex_index;
     }
 }
-impl Eq for Corner {}
+impl<TIndex: ElementIndex> Eq for Corner<TIndex> {}
 
 
 #[inline]
-pub fn next(corner: usize) -> usize {
-    return if (corner % 3) == 2 { corner - 2 } else { corner + 1 };
+pub fn next<TIndex: ElementIndex>(corner: CornerId<TIndex>) -> CornerId<TIndex> {
+    let corner = corner.index();
+    return CornerId::new(if (corner % 3) == 2 { corner - 2 } else { corner + 1 });
 }
 
 #[inline]
-pub fn previous(corner: usize) -> usize {
-    return if (corner % 3) == 0 { corner + 2 } else { corner - 1 };
+#[allow(clippy::manual_is_multiple_of)] // is_multiple_of requires Rust 1.87
+pub fn previous<TIndex: ElementIndex>(corner: CornerId<TIndex>) -> CornerId<TIndex> {
+    let corner = corner.index();
+    return CornerId::new(if (corner % 3) == 0 { corner + 2 } else { corner - 1 });
 } 
 
 #[inline]
-pub fn face(corner: usize) -> usize {
-    return corner / 3;
+pub fn face<TIndex: ElementIndex>(corner: CornerId<TIndex>) -> FaceId<TIndex> {
+    return FaceId::new(corner.index() / 3);
+}
+
+#[inline]
+pub fn first_corner<TIndex: ElementIndex>(face: FaceId<TIndex>) -> CornerId<TIndex> {
+    return CornerId::new(face.index() * 3);
 }
 
+/// Returns corners of face
 #[inline]
-pub fn first_corner(face: usize) -> usize {
-    return face * 3;
+pub fn face_corners<TIndex: ElementIndex>(face: FaceId<TIndex>) -> [CornerId<TIndex>; 3] {
+    let first = face.index() * 3;
+    return [CornerId::new(first), CornerId::new(first + 1), CornerId::new(first + 2)];
 }
 
 #[inline]
-pub fn first_corner_from_corner(corner: usize) -> usize {
+pub fn first_corner_from_corner<TIndex: ElementIndex>(corner: CornerId<TIndex>) -> CornerId<TIndex> {
     return first_corner(face(corner));
 }
 
 /// Returns true when `corner` is part `face`
 #[inline]
-pub fn face_contains_corner(face: usize, corner: usize) -> bool {
+pub fn face_contains_corner<TIndex: ElementIndex>(face: FaceId<TIndex>, corner: CornerId<TIndex>) -> bool {
     return self::face(corner) == face;
 }
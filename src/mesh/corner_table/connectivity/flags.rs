@@ -1,4 +1,8 @@
 use std::fmt::Display;
+#[cfg(feature = "rayon")]
+use std::sync::atomic::{AtomicU8, Ordering};
+#[cfg(not(feature = "rayon"))]
+use std::cell::UnsafeCell;
 use bitflags::bitflags;
 
 use super::traits;
@@ -21,6 +25,71 @@ impl Default for Flags {
     }
 }
 
+///
+/// Flags with interior mutability. With `rayon` feature flags are stored as atomic so that mesh can be shared between threads,
+/// relaxed ordering is enough as flags are not used for synchronization. Otherwise plain cell is used.
+///
+#[cfg(feature = "rayon")]
+#[derive(Debug, Default)]
+pub struct FlagsCell(AtomicU8);
+
+#[cfg(feature = "rayon")]
+impl FlagsCell {
+    #[inline]
+    pub fn new(flags: Flags) -> Self {
+        return Self(AtomicU8::new(flags.bits()));
+    }
+
+    #[inline]
+    pub fn get(&self) -> Flags {
+        return Flags::from_bits_truncate(self.0.load(Ordering::Relaxed));
+    }
+
+    /// Sets or clears given flags
+    #[inline]
+    pub fn set(&self, flags: Flags, value: bool) {
+        if value {
+            self.0.fetch_or(flags.bits(), Ordering::Relaxed);
+        } else {
+            self.0.fetch_and(!flags.bits(), Ordering::Relaxed);
+        }
+    }
+}
+
+#[cfg(not(feature = "rayon"))]
+#[derive(Debug, Default)]
+pub struct FlagsCell(UnsafeCell<Flags>);
+
+#[cfg(not(feature = "rayon"))]
+impl FlagsCell {
+    #[inline]
+    pub fn new(flags: Flags) -> Self {
+        return Self(UnsafeCell::new(flags));
+    }
+
+    #[inline]
+    pub fn get(&self) -> Flags {
+        unsafe {
+            return *self.0.get();
+        }
+    }
+
+    /// Sets or clears given flags
+    #[inline]
+    pub fn set(&self, flags: Flags, value: bool) {
+        unsafe {
+            (*self.0.get()).set(flags, value);
+        }
+    }
+}
+
+impl Display for FlagsCell {
+    #[inline]
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        return self.get().fmt(f);
+    }
+}
+
 ///
 /// Sets visited flag to `false`
 /// 
@@ -49,6 +118,23 @@ pub fn clear_marked<'a, TEntity, TEntitiesIter>(iter: TEntitiesIter)
     }
 }
 
+#[cfg(feature = "serde")]
+pub(crate) mod serde_cell {
+    use serde::{Serialize, Deserialize, Serializer, Deserializer};
+
+    use super::{Flags, FlagsCell};
+
+    /// Serializes flags as raw bits
+    pub fn serialize<TSerializer: Serializer>(flags: &FlagsCell, serializer: TSerializer) -> Result<TSerializer::Ok, TSerializer::Error> {
+        return flags.get().bits().serialize(serializer);
+    }
+
+    pub fn deserialize<'de, TDeserializer: Deserializer<'de>>(deserializer: TDeserializer) -> Result<FlagsCell, TDeserializer::Error> {
+        let bits = u8::deserialize(deserializer)?;
+        return Ok(FlagsCell::new(Flags::from_bits_truncate(bits)));
+    }
+}
+
 impl Display for Flags {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         return write!(f, "{:#010b}", self.bits)
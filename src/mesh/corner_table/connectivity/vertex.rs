@@ -1,49 +1,66 @@
-use std::cell::UnsafeCell;
 use nalgebra::Point3;
 use tabled::Tabled;
-use crate::{helpers::display::display_unsafecell, geometry::traits::RealNumber};
+#[cfg(feature = "serde")]
+use serde::{Serialize, Deserialize};
+use crate::{geometry::traits::RealNumber, mesh::corner_table::descriptors::{ElementIndex, CornerId}};
 use super::{traits::Flags, flags};
 
 ///
 /// Default implementation for Vertex trait
 /// 
 #[derive(Debug, Tabled)]
-pub struct Vertex<TScalarType: RealNumber> {
-    corner_index: usize,
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "serde", serde(bound(
+    serialize = "TScalarType: Serialize, TIndex: Serialize",
+    deserialize = "TScalarType: Deserialize<'de>, TIndex: Deserialize<'de>"
+)))]
+pub struct Vertex<TScalarType: RealNumber, TIndex: ElementIndex = usize> {
+    corner_index: TIndex,
     position: Point3<TScalarType>,
 
-    #[tabled(display_with = "display_unsafecell")]
-    flags: UnsafeCell<flags::Flags>
+    #[cfg_attr(feature = "serde", serde(with = "flags::serde_cell"))]
+    flags: flags::FlagsCell
 }
 
-impl<TScalarType: RealNumber> Vertex<TScalarType> {
-    pub fn new(corner_index: usize, position: Point3<TScalarType>, flags: flags::Flags) -> Self { 
+impl<TScalarType: RealNumber, TIndex: ElementIndex> Vertex<TScalarType, TIndex> {
+    pub fn new(corner_index: CornerId<TIndex>, position: Point3<TScalarType>, flags: flags::Flags) -> Self { 
         return Self { 
-            corner_index, 
+            corner_index: TIndex::from_usize(corner_index.index()), 
             position, 
-            flags: UnsafeCell::new(flags)
+            flags: flags::FlagsCell::new(flags)
         };
     }
 }
 
-impl<TScalarType: RealNumber> Default for Vertex<TScalarType> {
+impl<TScalarType: RealNumber, TIndex: ElementIndex> Default for Vertex<TScalarType, TIndex> {
     fn default() -> Self {
         return Self {
-            corner_index: usize::max_value(), 
+            corner_index: TIndex::INVALID, 
             position: Default::default(), 
             flags: Default::default() 
         };
     }
 }
 
-impl<TScalarType: RealNumber> Flags for Vertex<TScalarType> {
+impl<TScalarType: RealNumber, TIndex: ElementIndex> Clone for Vertex<TScalarType, TIndex> {
     #[inline]
-    fn get_flags(&self) -> &UnsafeCell<flags::Flags> {
+    fn clone(&self) -> Self {
+        return Self { 
+            corner_index: self.corner_index, 
+            position: self.position, 
+            flags: flags::FlagsCell::new(self.flags.get()) 
+        };
+    }
+}
+
+impl<TScalarType: RealNumber, TIndex: ElementIndex> Flags for Vertex<TScalarType, TIndex> {
+    #[inline]
+    fn get_flags(&self) -> &flags::FlagsCell {
         return &self.flags;
     }
 }
 
-impl<TScalarType: RealNumber> Vertex<TScalarType> {
+impl<TScalarType: RealNumber, TIndex: ElementIndex> Vertex<TScalarType, TIndex> {
     #[inline]
     pub fn get_position(&self) -> &Point3<TScalarType> {
         return &self.position;
@@ -55,19 +72,20 @@ impl<TScalarType: RealNumber> Vertex<TScalarType> {
         return self;
     }
 
+    /// Returns one of vertex corners, [CornerId::INVALID] for isolated vertex
     #[inline]
-    pub fn get_corner_index(&self) ->  usize {
-        return self.corner_index;
+    pub fn get_corner_index(&self) -> CornerId<TIndex> {
+        return CornerId::new(self.corner_index.to_usize());
     }
 
     #[inline]
-    pub fn set_corner_index(&mut self, index: usize) -> &mut Self {
-        self.corner_index = index;
+    pub fn set_corner_index(&mut self, index: CornerId<TIndex>) -> &mut Self {
+        self.corner_index = TIndex::from_usize(index.index());
         return self;
     }
 }
 
-impl<TScalarType: RealNumber> PartialEq for Vertex<TScalarType> {
+impl<TScalarType: RealNumber, TIndex: ElementIndex> PartialEq for Vertex<TScalarType, TIndex> {
     #[inline]
     fn eq(&self, other: &Self) -> bool {
         return 
@@ -75,7 +93,7 @@ impl<TScalarType: RealNumber> PartialEq for Vertex<TScalarType> {
             self.position      == other.position;
     }
 }
-impl<TScalarType: RealNumber> Eq for Vertex<TScalarType> {}
+impl<TScalarType: RealNumber, TIndex: ElementIndex> Eq for Vertex<TScalarType, TIndex> {}
 
 /// Aliases
 pub type VertexF = Vertex<f32>;
@@ -1,40 +1,173 @@
-
 use std::{hash::Hash, fmt::{Display, Debug}};
 
 use crate::geometry::traits::RealNumber;
 
-use super::table::CornerTable;
+use super::{table::CornerTable, connectivity::{corner::Corner, vertex::Vertex}};
+
+///
+/// Integer type used to store indices of corner table elements.
+/// `u32` halves memory used by connectivity of meshes with less than `u32::MAX` elements.
+///
+pub trait ElementIndex: Copy + Eq + Ord + Hash + Debug + Display + Send + Sync + 'static {
+    /// Reserved value marking missing element
+    const INVALID: Self;
+
+    fn from_usize(index: usize) -> Self;
+    fn to_usize(self) -> usize;
+}
+
+impl ElementIndex for usize {
+    const INVALID: Self = usize::MAX;
+
+    #[inline]
+    fn from_usize(index: usize) -> Self {
+        return index;
+    }
+
+    #[inline]
+    fn to_usize(self) -> usize {
+        return self;
+    }
+}
+
+impl ElementIndex for u32 {
+    const INVALID: Self = u32::MAX;
+
+    #[inline]
+    fn from_usize(index: usize) -> Self {
+        assert!(index < u32::MAX as usize || index == usize::MAX, "Index {} does not fit into u32", index);
+        return index as u32;
+    }
+
+    #[inline]
+    fn to_usize(self) -> usize {
+        if self == Self::INVALID {
+            return usize::MAX;
+        }
+
+        return self as usize;
+    }
+}
+
+macro_rules! element_id {
+    ($(#[$meta:meta])* $name:ident) => {
+        $(#[$meta])*
+        #[derive(Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Debug)]
+        pub struct $name<TIndex: ElementIndex = usize>(TIndex);
+
+        impl<TIndex: ElementIndex> $name<TIndex> {
+            /// Id reserved for missing element
+            pub const INVALID: Self = Self(TIndex::INVALID);
+
+            #[inline]
+            pub fn new(index: usize) -> Self {
+                return Self(TIndex::from_usize(index));
+            }
+
+            /// Returns position of element in corner table storage
+            #[inline]
+            pub fn index(&self) -> usize {
+                return self.0.to_usize();
+            }
+
+            #[inline]
+            pub fn is_valid(&self) -> bool {
+                return self.0 != TIndex::INVALID;
+            }
+        }
+
+        impl<TIndex: ElementIndex> Display for $name<TIndex> {
+            #[inline]
+            fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                return write!(f, "{}", self.0);
+            }
+        }
+    };
+}
+
+element_id!(
+    /// Index of corner table vertex
+    VertexId
+);
+
+element_id!(
+    ///
+    /// Index of corner table face. Corners of face `f` are `3f`, `3f + 1` and `3f + 2`.
+    ///
+    /// Face descriptor of corner table used to be index of first corner of face (`3f`).
+    /// Code that treated it as corner should convert it with [first_corner](super::connectivity::corner::first_corner),
+    /// and corner is converted back to its face by [face](super::connectivity::corner::face).
+    ///
+    FaceId
+);
+
+element_id!(
+    /// Index of corner table corner
+    CornerId
+);
+
+impl<TIndex: ElementIndex> std::ops::Index<CornerId<TIndex>> for Vec<Corner<TIndex>> {
+    type Output = Corner<TIndex>;
+
+    #[inline]
+    fn index(&self, corner: CornerId<TIndex>) -> &Self::Output {
+        return &self[corner.index()];
+    }
+}
+
+impl<TIndex: ElementIndex> std::ops::IndexMut<CornerId<TIndex>> for Vec<Corner<TIndex>> {
+    #[inline]
+    fn index_mut(&mut self, corner: CornerId<TIndex>) -> &mut Self::Output {
+        return &mut self[corner.index()];
+    }
+}
+
+impl<TScalar: RealNumber, TIndex: ElementIndex> std::ops::Index<VertexId<TIndex>> for Vec<Vertex<TScalar, TIndex>> {
+    type Output = Vertex<TScalar, TIndex>;
+
+    #[inline]
+    fn index(&self, vertex: VertexId<TIndex>) -> &Self::Output {
+        return &self[vertex.index()];
+    }
+}
+
+impl<TScalar: RealNumber, TIndex: ElementIndex> std::ops::IndexMut<VertexId<TIndex>> for Vec<Vertex<TScalar, TIndex>> {
+    #[inline]
+    fn index_mut(&mut self, vertex: VertexId<TIndex>) -> &mut Self::Output {
+        return &mut self[vertex.index()];
+    }
+}
 
-/// 
+///
 /// Edge descriptor for corner table.
 /// Edge is saved as a corner opposite to it that has smaller index.
-/// 
+///
 #[derive(Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
-pub struct EdgeRef {
-    corner_index: usize
+pub struct EdgeRef<TIndex: ElementIndex = usize> {
+    corner_index: CornerId<TIndex>
 }
 
-impl EdgeRef {
-    pub fn new<TScalar: RealNumber>(corner_index: usize, corner_table: &CornerTable<TScalar>) -> Self { 
+impl<TIndex: ElementIndex> EdgeRef<TIndex> {
+    pub fn new<TScalar: RealNumber>(corner_index: CornerId<TIndex>, corner_table: &CornerTable<TScalar, TIndex>) -> Self {
         let corner = &corner_table.corners[corner_index];
-        return Self { 
-            corner_index: corner_index.min(corner.get_opposite_corner_index().unwrap_or(usize::MAX))
+        return Self {
+            corner_index: corner_index.min(corner.get_opposite_corner_index().unwrap_or(CornerId::INVALID))
         };
     }
 
     /// Returns corner index of `this` edge reference
-    pub fn get_corner_index(&self) -> usize {
+    pub fn get_corner_index(&self) -> CornerId<TIndex> {
         return self.corner_index;
     }
 }
 
-impl Display for EdgeRef {
+impl<TIndex: ElementIndex> Display for EdgeRef<TIndex> {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         return write!(f, "{}", self.corner_index);
     }
 }
 
-impl Debug for EdgeRef {
+impl<TIndex: ElementIndex> Debug for EdgeRef<TIndex> {
     #[inline]
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         return write!(f, "corner_index: {}", &self.corner_index);
@@ -5,16 +5,17 @@ use crate::{
 use super::{
     table::CornerTable, 
     traversal::{CornerWalker, collect_corners_around_vertex}, 
-    connectivity::{traits::Flags, corner}
+    connectivity::{traits::Flags, corner},
+    descriptors::{ElementIndex, VertexId, FaceId, CornerId}
 };
 
 /// Set corner for wing vertex of collapsed edge
 #[inline]
-fn set_corner_for_wing_vertex<TScalar: RealNumber>(
-    corner_table: &mut CornerTable<TScalar>, 
-    vertex_index: usize, 
-    opposite_corner_left: Option<usize>,
-    opposite_corner_right: Option<usize>
+fn set_corner_for_wing_vertex<TScalar: RealNumber, TIndex: ElementIndex>(
+    corner_table: &mut CornerTable<TScalar, TIndex>, 
+    vertex_index: VertexId<TIndex>, 
+    opposite_corner_left: Option<CornerId<TIndex>>,
+    opposite_corner_right: Option<CornerId<TIndex>>
 ) {
     if let Some(corner) = opposite_corner_left {
         corner_table.vertices[vertex_index].set_corner_index(corner::previous(corner));
@@ -27,10 +28,10 @@ fn set_corner_for_wing_vertex<TScalar: RealNumber>(
 
 /// Make corners opposite to each other
 #[inline]
-fn make_corners_opposite<TScalar: RealNumber>(
-    corner_table: &mut CornerTable<TScalar>, 
-    c1: Option<usize>,
-    c2: Option<usize>
+pub(super) fn make_corners_opposite<TScalar: RealNumber, TIndex: ElementIndex>(
+    corner_table: &mut CornerTable<TScalar, TIndex>, 
+    c1: Option<CornerId<TIndex>>,
+    c2: Option<CornerId<TIndex>>
 ) {
     if let Some(c1_idx) = c1 {
         corner_table.corners[c1_idx].set_opposite_corner_index(c2);
@@ -41,17 +42,12 @@ fn make_corners_opposite<TScalar: RealNumber>(
     }
 }
 
-impl<TScalar: RealNumber> CornerTable<TScalar> {
+impl<TScalar: RealNumber, TIndex: ElementIndex> CornerTable<TScalar, TIndex> {
     /// Splits inner edge opposite to corner at given position
-    fn split_inner_edge(&mut self, corner_index: usize, at: &Point3<TScalar>) {
+    fn split_inner_edge(&mut self, corner_index: CornerId<TIndex>, at: &Point3<TScalar>) {
         // New corner indices
-        let c6_idx = self.corners.len();
-        let c7_idx = c6_idx + 1;
-        let c8_idx = c6_idx + 2;
-
-        let c9_idx = c6_idx + 3;
-        let c10_idx = c6_idx + 4;
-        let c11_idx = c6_idx + 5;
+        let [c6_idx, c7_idx, c8_idx] = corner::face_corners(FaceId::new(self.corners.len() / 3));
+        let [c9_idx, c10_idx, c11_idx] = corner::face_corners(FaceId::new(self.corners.len() / 3 + 1));
         
         // Existing corners and vertices that needs to be updated
         let mut walker = CornerWalker::from_corner(self, corner_index);
@@ -69,7 +65,7 @@ impl<TScalar: RealNumber> CornerTable<TScalar> {
         self.get_vertex_mut(v2_idx).unwrap().set_corner_index(c2_idx);
 
         // New vertex, instead of shifted
-        let new_vertex_index = self.vertices.len();
+        let new_vertex_index = VertexId::new(self.vertices.len());
         let new_vertex = self.create_vertex();
         new_vertex.set_corner_index(c7_idx);
         new_vertex.set_position(old_vertex_position);
@@ -100,11 +96,9 @@ impl<TScalar: RealNumber> CornerTable<TScalar> {
     }
 
     /// Splits boundary edge opposite to corner at given position
-    fn split_boundary_edge(&mut self, corner_index: usize, at: &Point3<TScalar>) {
+    fn split_boundary_edge(&mut self, corner_index: CornerId<TIndex>, at: &Point3<TScalar>) {
         // New corner indices
-        let c3_idx = self.corners.len();
-        let c4_idx = c3_idx + 1;
-        let c5_idx = c3_idx + 2;
+        let [c3_idx, c4_idx, c5_idx] = corner::face_corners(FaceId::new(self.corners.len() / 3));
         
         // Existing corners and vertices that needs to be updated
         let mut walker = CornerWalker::from_corner(self, corner_index);
@@ -119,7 +113,7 @@ impl<TScalar: RealNumber> CornerTable<TScalar> {
         self.get_vertex_mut(v2_idx).unwrap().set_corner_index(c2_idx);
 
         // New vertex, instead of shifted
-        let new_vertex_index = self.vertices.len();
+        let new_vertex_index = VertexId::new(self.vertices.len());
         let new_vertex = self.create_vertex();
         new_vertex.set_corner_index(c4_idx);
         new_vertex.set_position(old_vertex_position);
@@ -143,8 +137,9 @@ impl<TScalar: RealNumber> CornerTable<TScalar> {
     }
 }
 
-impl<TScalar: RealNumber> EditableMesh for CornerTable<TScalar> {
+impl<TScalar: RealNumber, TIndex: ElementIndex> EditableMesh for CornerTable<TScalar, TIndex> {
     fn collapse_edge(&mut self, edge: &Self::EdgeDescriptor, at: &Point3<Self::ScalarType>) {
+        let attribute_rules = self.collapse_edge_rules(edge.get_corner_index(), at);
         let mut walker = CornerWalker::from_corner(self, edge.get_corner_index());
 
         // Collect corners of faces that is going to be removed, 
@@ -209,9 +204,12 @@ impl<TScalar: RealNumber> EditableMesh for CornerTable<TScalar> {
         // Setup new opposites
         make_corners_opposite(self, c28_idx, c21_idx);
         make_corners_opposite(self, c6_idx, c13_idx);
+
+        self.interpolate_attributes(attribute_rules);
     }
 
     fn flip_edge(&mut self, edge: &Self::EdgeDescriptor) {
+        let attribute_rules = self.flip_edge_rules(edge.get_corner_index());
         let mut walker = CornerWalker::from_corner(self, edge.get_corner_index());
 
         // Face 1
@@ -257,17 +255,22 @@ impl<TScalar: RealNumber> EditableMesh for CornerTable<TScalar> {
         self.vertices[v1_idx].set_corner_index(c0_idx);
         self.vertices[v2_idx].set_corner_index(c1_idx);
         self.vertices[v3_idx].set_corner_index(c2_idx);
+
+        self.interpolate_attributes(attribute_rules);
     }
 
     #[inline]
     fn split_edge(&mut self, edge: &Self::EdgeDescriptor, at: &Point3<Self::ScalarType>) {
         let corner_index = edge.get_corner_index();
         let corner = &self.corners[corner_index];
+        let attribute_rules = self.split_edge_rules(corner_index, at);
 
         match corner.get_opposite_corner_index() {
             Some(_) => self.split_inner_edge(corner_index, at),
             None => self.split_boundary_edge(corner_index, at),
         }
+
+        self.interpolate_attributes(attribute_rules);
     }
 
     #[inline]
@@ -281,9 +284,10 @@ impl<TScalar: RealNumber> EditableMesh for CornerTable<TScalar> {
     }
 }
 
-impl<TScalar: RealNumber> SplitFaceAtPoint for CornerTable<TScalar> {
+impl<TScalar: RealNumber, TIndex: ElementIndex> SplitFaceAtPoint for CornerTable<TScalar, TIndex> {
     fn split_face(&mut self, face: &Self::FaceDescriptor, point: Point3<Self::ScalarType>) {
-        let mut walker = CornerWalker::from_corner(self, *face);
+        let attribute_rules = self.split_face_rules(*face, &point);
+        let mut walker = CornerWalker::from_corner(self, corner::first_corner(*face));
 
         // Splitted face
         let c0_idx = walker.get_corner_index();
@@ -298,19 +302,14 @@ impl<TScalar: RealNumber> SplitFaceAtPoint for CornerTable<TScalar> {
         let v2_idx = walker.get_corner().get_vertex_index();
         
         // Create new vertex at split point
-        let new_vertex_idx = self.vertices.len();
+        let new_vertex_idx = VertexId::new(self.vertices.len());
         let new_vertex = self.create_vertex();
         new_vertex.set_corner_index(c2_idx);
         new_vertex.set_position(point);
 
         // New faces required for split
-        let c3_idx = self.create_face_from_vertices(v1_idx, v2_idx, new_vertex_idx);
-        let c4_idx = corner::next(c3_idx);
-        let c5_idx = corner::next(c4_idx);
-
-        let c6_idx = self.create_face_from_vertices(v2_idx, v0_idx, new_vertex_idx);
-        let c7_idx = corner::next(c6_idx);
-        let c8_idx = corner::next(c7_idx);
+        let [c3_idx, c4_idx, c5_idx] = corner::face_corners(self.create_face_from_vertices(v1_idx, v2_idx, new_vertex_idx));
+        let [c6_idx, c7_idx, c8_idx] = corner::face_corners(self.create_face_from_vertices(v2_idx, v0_idx, new_vertex_idx));
 
         // Corners relationship between internal faces
         self.set_opposite_relationship(c0_idx, c4_idx);
@@ -323,6 +322,8 @@ impl<TScalar: RealNumber> SplitFaceAtPoint for CornerTable<TScalar> {
 
         self.corners[c2_idx].set_vertex_index(new_vertex_idx);
         self.vertices[v2_idx].set_corner_index(c4_idx);
+
+        self.interpolate_attributes(attribute_rules);
     }
 }
 
@@ -342,7 +343,7 @@ mod tests {
                 create_collapse_edge_sample_mesh2, 
                 create_collapse_edge_sample_mesh3
             }, 
-        connectivity::{vertex::VertexF, corner::Corner}, descriptors::EdgeRef}, 
+        connectivity::{vertex::VertexF, corner::Corner}, descriptors::{EdgeRef, VertexId, FaceId, CornerId}}, 
         traits::{EditableMesh, SplitFaceAtPoint}
     };
 
@@ -351,33 +352,33 @@ mod tests {
         let mut mesh = create_unit_square_mesh();
 
         let expected_vertices = vec![
-            VertexF::new(5, Point3::<f32>::new(0.0, 1.0, 0.0), Default::default()), // 0
-            VertexF::new(1, Point3::<f32>::new(0.0, 0.0, 0.0), Default::default()), // 1
-            VertexF::new(2, Point3::<f32>::new(0.5, 0.5, 0.0), Default::default()), // 2
-            VertexF::new(4, Point3::<f32>::new(1.0, 1.0, 0.0), Default::default()), // 3
-            VertexF::new(7, Point3::<f32>::new(1.0, 0.0, 0.0), Default::default())  // 4
+            VertexF::new(CornerId::new(5), Point3::<f32>::new(0.0, 1.0, 0.0), Default::default()), // 0
+            VertexF::new(CornerId::new(1), Point3::<f32>::new(0.0, 0.0, 0.0), Default::default()), // 1
+            VertexF::new(CornerId::new(2), Point3::<f32>::new(0.5, 0.5, 0.0), Default::default()), // 2
+            VertexF::new(CornerId::new(4), Point3::<f32>::new(1.0, 1.0, 0.0), Default::default()), // 3
+            VertexF::new(CornerId::new(7), Point3::<f32>::new(1.0, 0.0, 0.0), Default::default())  // 4
         ];
 
         let expected_corners = vec![
             // next, opposite, vertex, index, flags
-            Corner::new(Some(7), 0, Default::default()), // 0
-            Corner::new(Some(4), 1, Default::default()), // 1
-            Corner::new(None,    2, Default::default()), // 2
+            Corner::new(Some(CornerId::new(7)), VertexId::new(0), Default::default()), // 0
+            Corner::new(Some(CornerId::new(4)), VertexId::new(1), Default::default()), // 1
+            Corner::new(None,    VertexId::new(2), Default::default()), // 2
     
-            Corner::new(None,    2, Default::default()), // 3
-            Corner::new(Some(1), 3, Default::default()), // 4
-            Corner::new(Some(9), 0, Default::default()), // 5
+            Corner::new(None,    VertexId::new(2), Default::default()), // 3
+            Corner::new(Some(CornerId::new(1)), VertexId::new(3), Default::default()), // 4
+            Corner::new(Some(CornerId::new(9)), VertexId::new(0), Default::default()), // 5
             
-            Corner::new(Some(10), 1, Default::default()), // 6
-            Corner::new(Some(0),  4, Default::default()), // 7
-            Corner::new(None,     2, Default::default()), // 8
+            Corner::new(Some(CornerId::new(10)), VertexId::new(1), Default::default()), // 6
+            Corner::new(Some(CornerId::new(0)),  VertexId::new(4), Default::default()), // 7
+            Corner::new(None,     VertexId::new(2), Default::default()), // 8
             
-            Corner::new(Some(5), 4, Default::default()), // 9
-            Corner::new(Some(6), 3, Default::default()), // 10
-            Corner::new(None,    2, Default::default()), // 11
+            Corner::new(Some(CornerId::new(5)), VertexId::new(4), Default::default()), // 9
+            Corner::new(Some(CornerId::new(6)), VertexId::new(3), Default::default()), // 10
+            Corner::new(None,    VertexId::new(2), Default::default()), // 11
         ];
 
-        mesh.split_edge(&EdgeRef::new(1, &mesh), &Point3::<f32>::new(0.5, 0.5, 0.0));
+        mesh.split_edge(&EdgeRef::new(CornerId::new(1), &mesh), &Point3::<f32>::new(0.5, 0.5, 0.0));
 
         assert_mesh_eq(&mesh, &expected_corners, &expected_vertices);
     }
@@ -387,42 +388,42 @@ mod tests {
         let mut mesh = create_unit_cross_square_mesh();
 
         let expected_vertices = vec![
-            VertexF::new(10, Point3::<f32>::new(0.0, 1.0, 0.0), Default::default()), // 0
-            VertexF::new(3, Point3::<f32>::new(0.0, 0.0, 0.0), Default::default()), // 1
-            VertexF::new(6, Point3::<f32>::new(1.0, 0.0, 0.0), Default::default()), // 2
-            VertexF::new(7, Point3::<f32>::new(0.75, 0.75, 0.0), Default::default()), // 3
-            VertexF::new(11, Point3::<f32>::new(0.5, 0.5, 0.0), Default::default()), // 4
-            VertexF::new(13, Point3::<f32>::new(1.0, 1.0, 0.0), Default::default())  // 5
+            VertexF::new(CornerId::new(10), Point3::<f32>::new(0.0, 1.0, 0.0), Default::default()), // 0
+            VertexF::new(CornerId::new(3), Point3::<f32>::new(0.0, 0.0, 0.0), Default::default()), // 1
+            VertexF::new(CornerId::new(6), Point3::<f32>::new(1.0, 0.0, 0.0), Default::default()), // 2
+            VertexF::new(CornerId::new(7), Point3::<f32>::new(0.75, 0.75, 0.0), Default::default()), // 3
+            VertexF::new(CornerId::new(11), Point3::<f32>::new(0.5, 0.5, 0.0), Default::default()), // 4
+            VertexF::new(CornerId::new(13), Point3::<f32>::new(1.0, 1.0, 0.0), Default::default())  // 5
         ];
 
         let expected_corners = vec![
             // opposite, vertex, flags
-            Corner::new(Some(4),  0, Default::default()), // 0
-            Corner::new(Some(9),  1, Default::default()), // 1
-            Corner::new(None,     4, Default::default()), // 2
+            Corner::new(Some(CornerId::new(4)),  VertexId::new(0), Default::default()), // 0
+            Corner::new(Some(CornerId::new(9)),  VertexId::new(1), Default::default()), // 1
+            Corner::new(None,     VertexId::new(4), Default::default()), // 2
 
-            Corner::new(Some(7),  1, Default::default()), // 3
-            Corner::new(Some(0),  2, Default::default()), // 4
-            Corner::new(None,     4, Default::default()), // 5
+            Corner::new(Some(CornerId::new(7)),  VertexId::new(1), Default::default()), // 3
+            Corner::new(Some(CornerId::new(0)),  VertexId::new(2), Default::default()), // 4
+            Corner::new(None,     VertexId::new(4), Default::default()), // 5
         
-            Corner::new(Some(10), 2, Default::default()), // 6
-            Corner::new(Some(3),  3, Default::default()), // 7
-            Corner::new(Some(13), 4, Default::default()), // 8
+            Corner::new(Some(CornerId::new(10)), VertexId::new(2), Default::default()), // 6
+            Corner::new(Some(CornerId::new(3)),  VertexId::new(3), Default::default()), // 7
+            Corner::new(Some(CornerId::new(13)), VertexId::new(4), Default::default()), // 8
          
-            Corner::new(Some(1),  3, Default::default()), // 9
-            Corner::new(Some(6),  0, Default::default()), // 10
-            Corner::new(Some(15), 4, Default::default()), // 11
+            Corner::new(Some(CornerId::new(1)),  VertexId::new(3), Default::default()), // 9
+            Corner::new(Some(CornerId::new(6)),  VertexId::new(0), Default::default()), // 10
+            Corner::new(Some(CornerId::new(15)), VertexId::new(4), Default::default()), // 11
             
-            Corner::new(Some(16), 2, Default::default()), // 12
-            Corner::new(Some(8),  5, Default::default()), // 13
-            Corner::new(None,     3, Default::default()), // 14
+            Corner::new(Some(CornerId::new(16)), VertexId::new(2), Default::default()), // 12
+            Corner::new(Some(CornerId::new(8)),  VertexId::new(5), Default::default()), // 13
+            Corner::new(None,     VertexId::new(3), Default::default()), // 14
             
-            Corner::new(Some(11), 5, Default::default()), // 15
-            Corner::new(Some(12), 0, Default::default()), // 16
-            Corner::new(None,     3, Default::default()), // 17
+            Corner::new(Some(CornerId::new(11)), VertexId::new(5), Default::default()), // 15
+            Corner::new(Some(CornerId::new(12)), VertexId::new(0), Default::default()), // 16
+            Corner::new(None,     VertexId::new(3), Default::default()), // 17
         ];
 
-        mesh.split_edge(&EdgeRef::new(6, &mesh), &Point3::<f32>::new(0.75, 0.75, 0.0));
+        mesh.split_edge(&EdgeRef::new(CornerId::new(6), &mesh), &Point3::<f32>::new(0.75, 0.75, 0.0));
 
         assert_mesh_eq(&mesh, &expected_corners, &expected_vertices);
     }
@@ -432,24 +433,24 @@ mod tests {
         let mut mesh = create_single_face_mesh();
 
         let expected_vertices = vec![
-            VertexF::new(0, Point3::<f32>::new(0.0, 1.0, 0.0), Default::default()), // 0
-            VertexF::new(1, Point3::<f32>::new(0.0, 0.0, 0.0), Default::default()), // 1
-            VertexF::new(2, Point3::<f32>::new(0.5, 0.5, 0.0), Default::default()), // 2
-            VertexF::new(4, Point3::<f32>::new(1.0, 0.0, 0.0), Default::default()), // 3
+            VertexF::new(CornerId::new(0), Point3::<f32>::new(0.0, 1.0, 0.0), Default::default()), // 0
+            VertexF::new(CornerId::new(1), Point3::<f32>::new(0.0, 0.0, 0.0), Default::default()), // 1
+            VertexF::new(CornerId::new(2), Point3::<f32>::new(0.5, 0.5, 0.0), Default::default()), // 2
+            VertexF::new(CornerId::new(4), Point3::<f32>::new(1.0, 0.0, 0.0), Default::default()), // 3
         ];
 
         let expected_corners = vec![
             // opposite, vertex, flags
-            Corner::new(Some(4), 0, Default::default()), // 0
-            Corner::new(None,    1, Default::default()), // 1
-            Corner::new(None,    2, Default::default()), // 2
+            Corner::new(Some(CornerId::new(4)), VertexId::new(0), Default::default()), // 0
+            Corner::new(None,    VertexId::new(1), Default::default()), // 1
+            Corner::new(None,    VertexId::new(2), Default::default()), // 2
     
-            Corner::new(None,    1, Default::default()), // 3
-            Corner::new(Some(0), 3, Default::default()), // 4
-            Corner::new(None,    2, Default::default()), // 5
+            Corner::new(None,    VertexId::new(1), Default::default()), // 3
+            Corner::new(Some(CornerId::new(0)), VertexId::new(3), Default::default()), // 4
+            Corner::new(None,    VertexId::new(2), Default::default()), // 5
         ];
 
-        mesh.split_edge(&EdgeRef::new(1, &mesh), &Point3::<f32>::new(0.5, 0.5, 0.0));
+        mesh.split_edge(&EdgeRef::new(CornerId::new(1), &mesh), &Point3::<f32>::new(0.5, 0.5, 0.0));
 
         assert_mesh_eq(&mesh, &expected_corners, &expected_vertices);
     }
@@ -459,62 +460,62 @@ mod tests {
         let mut mesh = create_collapse_edge_sample_mesh1();
 
         let expected_vertices = vec![
-            VertexF::new(28, Point3::<f32>::new(0.0, 1.0, 0.0), Default::default()), // 0
-            VertexF::new(3, Point3::<f32>::new(0.0, 0.5, 0.0), Default::default()), // 1
-            VertexF::new(6, Point3::<f32>::new(0.0, 0.0, 0.0), Default::default()), // 2
-            VertexF::new(12, Point3::<f32>::new(0.5, 0.0, 0.0), Default::default()), // 3
-            VertexF::new(15, Point3::<f32>::new(1.0, 0.0, 0.0), Default::default()), // 4
-            VertexF::new(18, Point3::<f32>::new(1.0, 0.5, 0.0), Default::default()), // 5
-            VertexF::new(21, Point3::<f32>::new(1.0, 1.0, 0.0), Default::default()), // 6
-            VertexF::new(27, Point3::<f32>::new(0.5, 1.0, 0.0), Default::default()), // 7
-            VertexF::new(29, Point3::<f32>::new(0.25, 0.5, 0.0), Default::default()), // 8
-            VertexF::new(23, Point3::<f32>::new(0.5, 0.5, 0.0), Default::default()), // 9
+            VertexF::new(CornerId::new(28), Point3::<f32>::new(0.0, 1.0, 0.0), Default::default()), // 0
+            VertexF::new(CornerId::new(3), Point3::<f32>::new(0.0, 0.5, 0.0), Default::default()), // 1
+            VertexF::new(CornerId::new(6), Point3::<f32>::new(0.0, 0.0, 0.0), Default::default()), // 2
+            VertexF::new(CornerId::new(12), Point3::<f32>::new(0.5, 0.0, 0.0), Default::default()), // 3
+            VertexF::new(CornerId::new(15), Point3::<f32>::new(1.0, 0.0, 0.0), Default::default()), // 4
+            VertexF::new(CornerId::new(18), Point3::<f32>::new(1.0, 0.5, 0.0), Default::default()), // 5
+            VertexF::new(CornerId::new(21), Point3::<f32>::new(1.0, 1.0, 0.0), Default::default()), // 6
+            VertexF::new(CornerId::new(27), Point3::<f32>::new(0.5, 1.0, 0.0), Default::default()), // 7
+            VertexF::new(CornerId::new(29), Point3::<f32>::new(0.25, 0.5, 0.0), Default::default()), // 8
+            VertexF::new(CornerId::new(23), Point3::<f32>::new(0.5, 0.5, 0.0), Default::default()), // 9
         ];
 
         let expected_corners = vec![
             // opposite, vertex, flags
-            Corner::new(Some(4),  0, Default::default()), // 0
-            Corner::new(Some(27), 1, Default::default()), // 1
-            Corner::new(None,     9, Default::default()), // 2
+            Corner::new(Some(CornerId::new(4)),  VertexId::new(0), Default::default()), // 0
+            Corner::new(Some(CornerId::new(27)), VertexId::new(1), Default::default()), // 1
+            Corner::new(None,     VertexId::new(9), Default::default()), // 2
     
-            Corner::new(Some(7), 1, Default::default()), // 3
-            Corner::new(Some(0), 2, Default::default()), // 4
-            Corner::new(None,    9, Default::default()), // 5
+            Corner::new(Some(CornerId::new(7)), VertexId::new(1), Default::default()), // 3
+            Corner::new(Some(CornerId::new(0)), VertexId::new(2), Default::default()), // 4
+            Corner::new(None,    VertexId::new(9), Default::default()), // 5
     
-            Corner::new(Some(13), 2, Default::default()), // 6
-            Corner::new(Some(3),  3, Default::default()), // 7
-            Corner::new(None,     9, Default::default()), // 8
+            Corner::new(Some(CornerId::new(13)), VertexId::new(2), Default::default()), // 6
+            Corner::new(Some(CornerId::new(3)),  VertexId::new(3), Default::default()), // 7
+            Corner::new(None,     VertexId::new(9), Default::default()), // 8
     
-            Corner::new(Some(24), 3, Default::default()), // 9
-            Corner::new(Some(6),  9, Default::default()), // 10
-            Corner::new(Some(13), 9, Default::default()), // 11
+            Corner::new(Some(CornerId::new(24)), VertexId::new(3), Default::default()), // 9
+            Corner::new(Some(CornerId::new(6)),  VertexId::new(9), Default::default()), // 10
+            Corner::new(Some(CornerId::new(13)), VertexId::new(9), Default::default()), // 11
     
-            Corner::new(Some(16), 3, Default::default()), // 12
-            Corner::new(Some(6),  4, Default::default()), // 13
-            Corner::new(None,     9, Default::default()), // 14
+            Corner::new(Some(CornerId::new(16)), VertexId::new(3), Default::default()), // 12
+            Corner::new(Some(CornerId::new(6)),  VertexId::new(4), Default::default()), // 13
+            Corner::new(None,     VertexId::new(9), Default::default()), // 14
     
-            Corner::new(Some(19), 4, Default::default()), // 15
-            Corner::new(Some(12), 5, Default::default()), // 16
-            Corner::new(None,     9, Default::default()), // 17
+            Corner::new(Some(CornerId::new(19)), VertexId::new(4), Default::default()), // 15
+            Corner::new(Some(CornerId::new(12)), VertexId::new(5), Default::default()), // 16
+            Corner::new(None,     VertexId::new(9), Default::default()), // 17
     
-            Corner::new(Some(22), 5, Default::default()), // 18
-            Corner::new(Some(15), 6, Default::default()), // 19
-            Corner::new(None,     9, Default::default()), // 20
+            Corner::new(Some(CornerId::new(22)), VertexId::new(5), Default::default()), // 18
+            Corner::new(Some(CornerId::new(15)), VertexId::new(6), Default::default()), // 19
+            Corner::new(None,     VertexId::new(9), Default::default()), // 20
     
-            Corner::new(Some(28), 6, Default::default()), // 21
-            Corner::new(Some(18), 7, Default::default()), // 22
-            Corner::new(None,     9, Default::default()), // 23
+            Corner::new(Some(CornerId::new(28)), VertexId::new(6), Default::default()), // 21
+            Corner::new(Some(CornerId::new(18)), VertexId::new(7), Default::default()), // 22
+            Corner::new(None,     VertexId::new(9), Default::default()), // 23
     
-            Corner::new(Some(9),  7, Default::default()), // 24
-            Corner::new(Some(21), 9, Default::default()), // 25
-            Corner::new(Some(28), 9, Default::default()), // 26
+            Corner::new(Some(CornerId::new(9)),  VertexId::new(7), Default::default()), // 24
+            Corner::new(Some(CornerId::new(21)), VertexId::new(9), Default::default()), // 25
+            Corner::new(Some(CornerId::new(28)), VertexId::new(9), Default::default()), // 26
     
-            Corner::new(Some(1),  7, Default::default()), // 27
-            Corner::new(Some(21), 0, Default::default()), // 28
-            Corner::new(None,      9, Default::default()), // 29
+            Corner::new(Some(CornerId::new(1)),  VertexId::new(7), Default::default()), // 27
+            Corner::new(Some(CornerId::new(21)), VertexId::new(0), Default::default()), // 28
+            Corner::new(None,      VertexId::new(9), Default::default()), // 29
         ];
 
-        mesh.collapse_edge(&EdgeRef::new(9, &mesh), &Point3::new(0.5, 0.5, 0.0));
+        mesh.collapse_edge(&EdgeRef::new(CornerId::new(9), &mesh), &Point3::new(0.5, 0.5, 0.0));
 
         assert_mesh_eq(&mesh, &expected_corners, &expected_vertices);
     }
@@ -524,43 +525,43 @@ mod tests {
         let mut mesh = create_collapse_edge_sample_mesh2();
 
         let expected_vertices = vec![
-            VertexF::new(0,  Point3::<f32>::new(0.5, 0.0, 0.0), Default::default()), // 0
-            VertexF::new(3, Point3::<f32>::new(1.0, 0.0, 0.0), Default::default()), // 1
-            VertexF::new(6, Point3::<f32>::new(1.0, 0.5, 0.0), Default::default()), // 2
-            VertexF::new(9, Point3::<f32>::new(1.0, 1.0, 0.0), Default::default()), // 3
-            VertexF::new(10, Point3::<f32>::new(0.5, 1.0, 0.0), Default::default()), // 4
-            VertexF::new(11, Point3::<f32>::new(0.5, 0.5, 0.0), Default::default()), // 5
-            VertexF::new(17, Point3::<f32>::new(0.75, 0.5, 0.0), Default::default()), // 6
+            VertexF::new(CornerId::new(0),  Point3::<f32>::new(0.5, 0.0, 0.0), Default::default()), // 0
+            VertexF::new(CornerId::new(3), Point3::<f32>::new(1.0, 0.0, 0.0), Default::default()), // 1
+            VertexF::new(CornerId::new(6), Point3::<f32>::new(1.0, 0.5, 0.0), Default::default()), // 2
+            VertexF::new(CornerId::new(9), Point3::<f32>::new(1.0, 1.0, 0.0), Default::default()), // 3
+            VertexF::new(CornerId::new(10), Point3::<f32>::new(0.5, 1.0, 0.0), Default::default()), // 4
+            VertexF::new(CornerId::new(11), Point3::<f32>::new(0.5, 0.5, 0.0), Default::default()), // 5
+            VertexF::new(CornerId::new(17), Point3::<f32>::new(0.75, 0.5, 0.0), Default::default()), // 6
         ];
 
         let expected_corners = vec![
             // opposite, vertex, flags
-            Corner::new(Some(4),  0, Default::default()), // 0
-            Corner::new(None,     1, Default::default()), // 1
-            Corner::new(None,     5, Default::default()), // 2
+            Corner::new(Some(CornerId::new(4)),  VertexId::new(0), Default::default()), // 0
+            Corner::new(None,     VertexId::new(1), Default::default()), // 1
+            Corner::new(None,     VertexId::new(5), Default::default()), // 2
     
-            Corner::new(Some(7), 1, Default::default()), // 3
-            Corner::new(Some(0), 2, Default::default()), // 4
-            Corner::new(None,    5, Default::default()), // 5
+            Corner::new(Some(CornerId::new(7)), VertexId::new(1), Default::default()), // 3
+            Corner::new(Some(CornerId::new(0)), VertexId::new(2), Default::default()), // 4
+            Corner::new(None,    VertexId::new(5), Default::default()), // 5
     
-            Corner::new(Some(10), 2, Default::default()), // 6
-            Corner::new(Some(3),  3, Default::default()), // 7
-            Corner::new(None,     5, Default::default()), // 8
+            Corner::new(Some(CornerId::new(10)), VertexId::new(2), Default::default()), // 6
+            Corner::new(Some(CornerId::new(3)),  VertexId::new(3), Default::default()), // 7
+            Corner::new(None,     VertexId::new(5), Default::default()), // 8
     
-            Corner::new(None,     3, Default::default()), // 9
-            Corner::new(Some(6),  4, Default::default()), // 10
-            Corner::new(None,     5, Default::default()), // 11
+            Corner::new(None,     VertexId::new(3), Default::default()), // 9
+            Corner::new(Some(CornerId::new(6)),  VertexId::new(4), Default::default()), // 10
+            Corner::new(None,     VertexId::new(5), Default::default()), // 11
     
-            Corner::new(Some(16), 4, Default::default()), // 12
-            Corner::new(Some(9),  5, Default::default()), // 13
-            Corner::new(None,     5, Default::default()), // 14
+            Corner::new(Some(CornerId::new(16)), VertexId::new(4), Default::default()), // 12
+            Corner::new(Some(CornerId::new(9)),  VertexId::new(5), Default::default()), // 13
+            Corner::new(None,     VertexId::new(5), Default::default()), // 14
     
-            Corner::new(Some(1),  5, Default::default()), // 15
-            Corner::new(Some(12), 0, Default::default()), // 16
-            Corner::new(None,     5, Default::default()), // 17
+            Corner::new(Some(CornerId::new(1)),  VertexId::new(5), Default::default()), // 15
+            Corner::new(Some(CornerId::new(12)), VertexId::new(0), Default::default()), // 16
+            Corner::new(None,     VertexId::new(5), Default::default()), // 17
         ];
 
-        mesh.collapse_edge(&EdgeRef::new(12, &mesh), &Point3::new(0.5, 0.5, 0.0));
+        mesh.collapse_edge(&EdgeRef::new(CornerId::new(12), &mesh), &Point3::new(0.5, 0.5, 0.0));
 
         assert_mesh_eq(&mesh, &expected_corners, &expected_vertices);
     }
@@ -570,29 +571,29 @@ mod tests {
         let mut mesh = create_collapse_edge_sample_mesh3();
 
         let expected_vertices = vec![
-            VertexF::new(0,  Point3::<f32>::new(0.0, 1.0, 0.0), Default::default()), // 0
-            VertexF::new(6,  Point3::<f32>::new(2.0, 0.0, 0.0), Default::default()), // 1
-            VertexF::new(6,  Point3::<f32>::new(3.0, 0.0, 0.0), Default::default()), // 2
-            VertexF::new(7,  Point3::<f32>::new(4.0, 1.0, 0.0), Default::default()), // 3
-            VertexF::new(2,  Point3::<f32>::new(2.0, 1.0, 0.0), Default::default()), // 4
+            VertexF::new(CornerId::new(0),  Point3::<f32>::new(0.0, 1.0, 0.0), Default::default()), // 0
+            VertexF::new(CornerId::new(6),  Point3::<f32>::new(2.0, 0.0, 0.0), Default::default()), // 1
+            VertexF::new(CornerId::new(6),  Point3::<f32>::new(3.0, 0.0, 0.0), Default::default()), // 2
+            VertexF::new(CornerId::new(7),  Point3::<f32>::new(4.0, 1.0, 0.0), Default::default()), // 3
+            VertexF::new(CornerId::new(2),  Point3::<f32>::new(2.0, 1.0, 0.0), Default::default()), // 4
         ];
 
         let expected_corners = vec![
             // opposite, vertex, flags
-            Corner::new(Some(7),  0, Default::default()), // 0
-            Corner::new(None,     1, Default::default()), // 1
-            Corner::new(None,     4, Default::default()), // 2
+            Corner::new(Some(CornerId::new(7)),  VertexId::new(0), Default::default()), // 0
+            Corner::new(None,     VertexId::new(1), Default::default()), // 1
+            Corner::new(None,     VertexId::new(4), Default::default()), // 2
     
-            Corner::new(Some(7), 1, Default::default()), // 3
-            Corner::new(Some(0), 1, Default::default()), // 4
-            Corner::new(None,    4, Default::default()), // 5
+            Corner::new(Some(CornerId::new(7)), VertexId::new(1), Default::default()), // 3
+            Corner::new(Some(CornerId::new(0)), VertexId::new(1), Default::default()), // 4
+            Corner::new(None,    VertexId::new(4), Default::default()), // 5
     
-            Corner::new(None,    1, Default::default()), // 6
-            Corner::new(Some(0), 3, Default::default()), // 7
-            Corner::new(None,    4, Default::default()), // 8
+            Corner::new(None,    VertexId::new(1), Default::default()), // 6
+            Corner::new(Some(CornerId::new(0)), VertexId::new(3), Default::default()), // 7
+            Corner::new(None,    VertexId::new(4), Default::default()), // 8
         ];
 
-        mesh.collapse_edge(&EdgeRef::new(5, &mesh), &Point3::new(2.0, 0.0, 0.0));
+        mesh.collapse_edge(&EdgeRef::new(CornerId::new(5), &mesh), &Point3::new(2.0, 0.0, 0.0));
 
         assert_mesh_eq(&mesh, &expected_corners, &expected_vertices);
     }
@@ -602,44 +603,44 @@ mod tests {
         let mut mesh = create_flip_edge_sample_mesh();
 
         let expected_vertices = vec![
-            VertexF::new(4, Point3::<f32>::new(0.5, 1.0, 0.0), Default::default()), // 0
-            VertexF::new(0, Point3::<f32>::new(0.0, 0.5, 0.0), Default::default()), // 1
-            VertexF::new(1, Point3::<f32>::new(0.5, 0.0, 0.0), Default::default()), // 2
-            VertexF::new(2, Point3::<f32>::new(1.0, 0.5, 0.0), Default::default()), // 3
-            VertexF::new(13, Point3::<f32>::new(1.0, 1.0, 0.0), Default::default()), // 4
-            VertexF::new(16, Point3::<f32>::new(0.0, 1.0, 0.0), Default::default()), // 5
-            VertexF::new(7, Point3::<f32>::new(0.0, 0.0, 0.0), Default::default()), // 6
-            VertexF::new(10, Point3::<f32>::new(1.0, 0.0, 0.0), Default::default()), // 7
+            VertexF::new(CornerId::new(4), Point3::<f32>::new(0.5, 1.0, 0.0), Default::default()), // 0
+            VertexF::new(CornerId::new(0), Point3::<f32>::new(0.0, 0.5, 0.0), Default::default()), // 1
+            VertexF::new(CornerId::new(1), Point3::<f32>::new(0.5, 0.0, 0.0), Default::default()), // 2
+            VertexF::new(CornerId::new(2), Point3::<f32>::new(1.0, 0.5, 0.0), Default::default()), // 3
+            VertexF::new(CornerId::new(13), Point3::<f32>::new(1.0, 1.0, 0.0), Default::default()), // 4
+            VertexF::new(CornerId::new(16), Point3::<f32>::new(0.0, 1.0, 0.0), Default::default()), // 5
+            VertexF::new(CornerId::new(7), Point3::<f32>::new(0.0, 0.0, 0.0), Default::default()), // 6
+            VertexF::new(CornerId::new(10), Point3::<f32>::new(1.0, 0.0, 0.0), Default::default()), // 7
         ];
 
         let expected_corners = vec![
             // opposite, vertex, flags
-            Corner::new(Some(10), 1, Default::default()), // 0
-            Corner::new(Some(4),  2, Default::default()), // 1
-            Corner::new(Some(7),  3, Default::default()), // 2
+            Corner::new(Some(CornerId::new(10)), VertexId::new(1), Default::default()), // 0
+            Corner::new(Some(CornerId::new(4)),  VertexId::new(2), Default::default()), // 1
+            Corner::new(Some(CornerId::new(7)),  VertexId::new(3), Default::default()), // 2
         
-            Corner::new(Some(16), 3, Default::default()), // 3
-            Corner::new(Some(1),  0, Default::default()), // 4
-            Corner::new(Some(13), 1, Default::default()), // 5
+            Corner::new(Some(CornerId::new(16)), VertexId::new(3), Default::default()), // 3
+            Corner::new(Some(CornerId::new(1)),  VertexId::new(0), Default::default()), // 4
+            Corner::new(Some(CornerId::new(13)), VertexId::new(1), Default::default()), // 5
 
-            Corner::new(None,    1, Default::default()), // 6
-            Corner::new(Some(2), 6, Default::default()), // 7
-            Corner::new(None,    2, Default::default()), // 8
+            Corner::new(None,    VertexId::new(1), Default::default()), // 6
+            Corner::new(Some(CornerId::new(2)), VertexId::new(6), Default::default()), // 7
+            Corner::new(None,    VertexId::new(2), Default::default()), // 8
 
-            Corner::new(None,    2, Default::default()), // 9
-            Corner::new(Some(0), 7, Default::default()), // 10
-            Corner::new(None,    3, Default::default()), // 11
+            Corner::new(None,    VertexId::new(2), Default::default()), // 9
+            Corner::new(Some(CornerId::new(0)), VertexId::new(7), Default::default()), // 10
+            Corner::new(None,    VertexId::new(3), Default::default()), // 11
 
-            Corner::new(None,    3, Default::default()), // 12
-            Corner::new(Some(5), 4, Default::default()), // 13
-            Corner::new(None,    0, Default::default()), // 14
+            Corner::new(None,    VertexId::new(3), Default::default()), // 12
+            Corner::new(Some(CornerId::new(5)), VertexId::new(4), Default::default()), // 13
+            Corner::new(None,    VertexId::new(0), Default::default()), // 14
 
-            Corner::new(None,    0, Default::default()), // 15
-            Corner::new(Some(3), 5, Default::default()), // 16
-            Corner::new(None,    1,  Default::default()), // 17
+            Corner::new(None,    VertexId::new(0), Default::default()), // 15
+            Corner::new(Some(CornerId::new(3)), VertexId::new(5), Default::default()), // 16
+            Corner::new(None,    VertexId::new(1),  Default::default()), // 17
         ];
 
-        mesh.flip_edge(&EdgeRef::new(1, &mesh));
+        mesh.flip_edge(&EdgeRef::new(CornerId::new(1), &mesh));
 
         assert_mesh_eq(&mesh, &expected_corners, &expected_vertices);
     }
@@ -649,33 +650,33 @@ mod tests {
         let mut mesh = create_unit_square_mesh();
 
         let expected_vertices = vec![
-            VertexF::new(5, Point3::<f32>::new(0.0, 1.0, 0.0), Default::default()), // 0
-            VertexF::new(1, Point3::<f32>::new(0.0, 0.0, 0.0), Default::default()), // 1
-            VertexF::new(7, Point3::<f32>::new(1.0, 0.0, 0.0), Default::default()), // 2
-            VertexF::new(4, Point3::<f32>::new(1.0, 1.0, 0.0), Default::default()), // 3
-            VertexF::new(2, Point3::<f32>::new(0.5, 0.5, 0.0), Default::default()), // 4
+            VertexF::new(CornerId::new(5), Point3::<f32>::new(0.0, 1.0, 0.0), Default::default()), // 0
+            VertexF::new(CornerId::new(1), Point3::<f32>::new(0.0, 0.0, 0.0), Default::default()), // 1
+            VertexF::new(CornerId::new(7), Point3::<f32>::new(1.0, 0.0, 0.0), Default::default()), // 2
+            VertexF::new(CornerId::new(4), Point3::<f32>::new(1.0, 1.0, 0.0), Default::default()), // 3
+            VertexF::new(CornerId::new(2), Point3::<f32>::new(0.5, 0.5, 0.0), Default::default()), // 4
         ];
 
         let expected_corners = vec![
             // opposite, vertex, flags
-            Corner::new(Some(7), 0, Default::default()), // 0
-            Corner::new(Some(9), 1, Default::default()), // 1
-            Corner::new(None,    4, Default::default()), // 2
+            Corner::new(Some(CornerId::new(7)), VertexId::new(0), Default::default()), // 0
+            Corner::new(Some(CornerId::new(9)), VertexId::new(1), Default::default()), // 1
+            Corner::new(None,    VertexId::new(4), Default::default()), // 2
         
-            Corner::new(None,     2, Default::default()), // 3
-            Corner::new(Some(11), 3, Default::default()), // 4
-            Corner::new(None,     0, Default::default()), // 5
+            Corner::new(None,     VertexId::new(2), Default::default()), // 3
+            Corner::new(Some(CornerId::new(11)), VertexId::new(3), Default::default()), // 4
+            Corner::new(None,     VertexId::new(0), Default::default()), // 5
 
-            Corner::new(Some(10), 1, Default::default()), // 6
-            Corner::new(Some(0),  2, Default::default()), // 7
-            Corner::new(None,     4, Default::default()), // 8
+            Corner::new(Some(CornerId::new(10)), VertexId::new(1), Default::default()), // 6
+            Corner::new(Some(CornerId::new(0)),  VertexId::new(2), Default::default()), // 7
+            Corner::new(None,     VertexId::new(4), Default::default()), // 8
 
-            Corner::new(Some(1), 2, Default::default()), // 9
-            Corner::new(Some(6), 0, Default::default()), // 10
-            Corner::new(Some(4), 4, Default::default()), // 11
+            Corner::new(Some(CornerId::new(1)), VertexId::new(2), Default::default()), // 9
+            Corner::new(Some(CornerId::new(6)), VertexId::new(0), Default::default()), // 10
+            Corner::new(Some(CornerId::new(4)), VertexId::new(4), Default::default()), // 11
         ];
 
-        mesh.split_face(&0, Point3::new(0.5, 0.5, 0.0));
+        mesh.split_face(&FaceId::new(0), Point3::new(0.5, 0.5, 0.0));
 
         assert_mesh_eq(&mesh, &expected_corners, &expected_vertices);
     }
@@ -0,0 +1,511 @@
+use std::{collections::HashMap, fmt::Display};
+
+use nalgebra::{Point3, Vector3};
+use crate::{geometry::traits::RealNumber, mesh::polygon::triangulation::triangulate_polygon};
+use super::{
+    table::CornerTable,
+    traversal::{corners_around_vertex, collect_corners_around_vertex},
+    connectivity::{traits::Flags, corner::{next, previous, face_corners}},
+    descriptors::{ElementIndex, VertexId, FaceId, CornerId},
+    editable::make_corners_opposite
+};
+
+///
+/// Error returned by topological editing operations of [CornerTable].
+/// Mesh is left unchanged when operation fails.
+///
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum TopologyError {
+    /// Vertex does not exist or is deleted
+    InvalidVertex { vertex: usize },
+    /// Face references same vertex more than once
+    DegenerateFace,
+    /// Edge would be shared by more than two faces or by faces with inconsistent orientation
+    NonManifoldEdge { start: usize, end: usize },
+    /// Faces around vertex would not form single fan
+    NonManifoldVertex { vertex: usize }
+}
+
+impl Display for TopologyError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            TopologyError::InvalidVertex { vertex } => return write!(f, "vertex {} does not exist", vertex),
+            TopologyError::DegenerateFace => return write!(f, "face is degenerate"),
+            TopologyError::NonManifoldEdge { start, end } => return write!(f, "edge {}-{} is non-manifold", start, end),
+            TopologyError::NonManifoldVertex { vertex } => return write!(f, "vertex {} is non-manifold", vertex),
+        }
+    }
+}
+
+impl std::error::Error for TopologyError {}
+
+impl<TScalar: RealNumber, TIndex: ElementIndex> CornerTable<TScalar, TIndex> {
+    /// Adds isolated vertex and returns its index. Vertex stays isolated until face referencing it is added.
+    #[inline]
+    pub fn add_vertex(&mut self, position: Point3<TScalar>) -> VertexId<TIndex> {
+        self.create_vertex().set_position(position);
+        return VertexId::new(self.vertices.len() - 1);
+    }
+
+    ///
+    /// Adds face with given vertices in counterclockwise order and connects it to adjacent faces.
+    /// Face can only be attached to boundary edges with opposite direction, and every not isolated vertex
+    /// must share at least one edge with new face, so that its faces still form single fan.
+    /// Returns new face.
+    ///
+    pub fn add_face(&mut self, v1: VertexId<TIndex>, v2: VertexId<TIndex>, v3: VertexId<TIndex>) -> Result<FaceId<TIndex>, TopologyError> {
+        let vertices = [v1, v2, v3];
+
+        if let Some(vertex) = vertices.iter().find(|vertex| self.get_vertex(**vertex).is_none_or(|v| v.is_deleted())) {
+            return Err(TopologyError::InvalidVertex { vertex: vertex.index() });
+        }
+
+        if v1 == v2 || v2 == v3 || v3 == v1 {
+            return Err(TopologyError::DegenerateFace);
+        }
+
+        // Corners opposite to existing edges of other direction
+        let mut twins = [None; 3];
+
+        for i in 0..3 {
+            let start = vertices[(i + 1) % 3];
+            let end = vertices[(i + 2) % 3];
+
+            if self.find_directed_edge(start, end).is_some() {
+                return Err(TopologyError::NonManifoldEdge { start: start.index(), end: end.index() });
+            }
+
+            twins[i] = self.find_directed_edge(end, start);
+        }
+
+        // Vertex with faces must be connected to new face by edge
+        for i in 0..3 {
+            let is_isolated = !self.vertices[vertices[i]].get_corner_index().is_valid();
+            let is_connected = twins[(i + 1) % 3].is_some() || twins[(i + 2) % 3].is_some();
+
+            if !is_isolated && !is_connected {
+                return Err(TopologyError::NonManifoldVertex { vertex: vertices[i].index() });
+            }
+        }
+
+        let face = self.create_face_from_vertices(v1, v2, v3);
+
+        for (i, corner) in face_corners(face).into_iter().enumerate() {
+            make_corners_opposite(self, Some(corner), twins[i]);
+
+            let vertex = &mut self.vertices[vertices[i]];
+            if !vertex.get_corner_index().is_valid() {
+                vertex.set_corner_index(corner);
+            }
+        }
+
+        return Ok(face);
+    }
+
+    ///
+    /// Removes face, its edges that are not shared with other faces become boundary.
+    /// Vertices left without faces are deleted. Vertex whose faces are split into two fans by removal is duplicated,
+    /// returns `(original, duplicate)` pairs of such vertices.
+    ///
+    pub fn remove_face(&mut self, face: FaceId<TIndex>) -> Vec<(VertexId<TIndex>, VertexId<TIndex>)> {
+        let corners = face_corners(face);
+        debug_assert!(!self.corners[corners[0]].is_deleted(), "Removing deleted face");
+
+        // Corners of vertex in adjacent faces across edges incident to it
+        let mut adjacent_corners = [(VertexId::INVALID, None, None); 3];
+
+        for (i, corner) in corners.into_iter().enumerate() {
+            adjacent_corners[i] = (
+                self.corners[corner].get_vertex_index(),
+                self.corners[previous(corner)].get_opposite_corner_index().map(previous),
+                self.corners[next(corner)].get_opposite_corner_index().map(next)
+            );
+        }
+
+        for corner in corners {
+            let opposite = self.corners[corner].get_opposite_corner_index();
+            make_corners_opposite(self, opposite, None);
+            self.corners[corner].set_opposite_corner_index(None);
+            self.corners[corner].set_deleted(true);
+        }
+
+        let mut duplicates = Vec::new();
+
+        for (vertex, left, right) in adjacent_corners {
+            match (left, right) {
+                (None, None) => {
+                    self.vertices[vertex].set_deleted(true);
+                },
+                (Some(corner), None) | (None, Some(corner)) => {
+                    self.vertices[vertex].set_corner_index(corner);
+                },
+                (Some(left), Some(right)) => {
+                    self.vertices[vertex].set_corner_index(left);
+
+                    if collect_corners_around_vertex(self, vertex).contains(&right) {
+                        continue;
+                    }
+
+                    // Faces on the right side of removed face form separate fan
+                    let duplicate = self.add_vertex(*self.vertices[vertex].get_position());
+                    self.vertices[duplicate].set_corner_index(right);
+
+                    for corner in collect_corners_around_vertex(self, duplicate) {
+                        self.corners[corner].set_vertex_index(duplicate);
+                    }
+
+                    let attribute_rules = self.copy_rules(vec![(duplicate.index(), vertex.index())], Vec::new());
+                    self.interpolate_attributes(attribute_rules);
+
+                    duplicates.push((vertex, duplicate));
+                }
+            }
+        }
+
+        return duplicates;
+    }
+
+    ///
+    /// Removes vertex with its one-ring faces and triangulates resulting hole.
+    /// For boundary vertex hole is closed by new boundary edge between its boundary neighbors.
+    /// Fails when one-ring of vertex is not simple polygon or triangulation would duplicate existing edge.
+    ///
+    pub fn remove_vertex(&mut self, vertex: VertexId<TIndex>) -> Result<(), TopologyError> {
+        if self.get_vertex(vertex).is_none_or(|v| v.is_deleted()) {
+            return Err(TopologyError::InvalidVertex { vertex: vertex.index() });
+        }
+
+        if !self.vertices[vertex].get_corner_index().is_valid() {
+            self.vertices[vertex].set_deleted(true);
+            return Ok(());
+        }
+
+        let (ring, is_boundary) = self.ordered_vertex_corners(vertex);
+
+        // Polygon of hole
+        let mut polygon: Vec<_> = ring.iter().map(|corner| self.corners[next(*corner)].get_vertex_index()).collect();
+
+        if is_boundary {
+            polygon.push(self.corners[previous(*ring.last().unwrap())].get_vertex_index());
+        }
+
+        for (i, polygon_vertex) in polygon.iter().enumerate() {
+            if polygon[i + 1..].contains(polygon_vertex) {
+                return Err(TopologyError::NonManifoldVertex { vertex: vertex.index() });
+            }
+        }
+
+        let positions: Vec<_> = polygon.iter().map(|v| *self.vertices[*v].get_position()).collect();
+        let mut normal = Vector3::zeros();
+
+        for corner in &ring {
+            let a = self.vertices[self.corners[next(*corner)].get_vertex_index()].get_position();
+            let b = self.vertices[self.corners[previous(*corner)].get_vertex_index()].get_position();
+            normal += (a - self.vertices[vertex].get_position()).cross(&(b - self.vertices[vertex].get_position()));
+        }
+
+        let triangles: Vec<[VertexId<TIndex>; 3]> = if polygon.len() < 3 {
+            Vec::new()
+        } else {
+            triangulate_polygon(&positions, &normal).into_iter()
+                .map(|triangle| triangle.map(|index| polygon[index]))
+                .collect()
+        };
+
+        // Corners outside of hole opposite to its boundary edges
+        let mut outer_corners = HashMap::new();
+
+        for corner in &ring {
+            let start = self.corners[next(*corner)].get_vertex_index();
+            let end = self.corners[previous(*corner)].get_vertex_index();
+            outer_corners.insert((start, end), self.corners[*corner].get_opposite_corner_index());
+        }
+
+        // New edges must not exist outside of one-ring
+        for triangle in &triangles {
+            for i in 0..3 {
+                let (start, end) = (triangle[i], triangle[(i + 1) % 3]);
+
+                if outer_corners.contains_key(&(start, end)) || outer_corners.contains_key(&(end, start)) {
+                    continue;
+                }
+
+                if self.find_directed_edge(start, end).is_some() || self.find_directed_edge(end, start).is_some() {
+                    return Err(TopologyError::NonManifoldEdge { start: start.index(), end: end.index() });
+                }
+            }
+        }
+
+        // Corners of removed faces at polygon vertices are sources of attributes for new corners
+        let mut source_corners = HashMap::new();
+
+        for corner in &ring {
+            source_corners.insert(self.corners[next(*corner)].get_vertex_index(), next(*corner));
+            source_corners.insert(self.corners[previous(*corner)].get_vertex_index(), previous(*corner));
+        }
+
+        // Remove one-ring
+        for corner in &ring {
+            for face_corner in [*corner, next(*corner), previous(*corner)] {
+                self.corners[face_corner].set_deleted(true);
+            }
+        }
+
+        self.vertices[vertex].set_deleted(true);
+
+        for ((start, end), outer_corner) in &outer_corners {
+            if let Some(outer_corner) = outer_corner {
+                self.corners[*outer_corner].set_opposite_corner_index(None);
+                self.vertices[*start].set_corner_index(previous(*outer_corner));
+                self.vertices[*end].set_corner_index(next(*outer_corner));
+            }
+        }
+
+        // Fill hole
+        let mut inner_edges = HashMap::new();
+        let mut copied_corners = Vec::new();
+
+        for [v1, v2, v3] in triangles {
+            let face = self.create_face_from_vertices(v1, v2, v3);
+            let face_vertices = [v1, v2, v3];
+
+            for (i, corner) in face_corners(face).into_iter().enumerate() {
+                let edge = (face_vertices[(i + 1) % 3], face_vertices[(i + 2) % 3]);
+
+                if let Some(outer_corner) = outer_corners.get(&edge) {
+                    make_corners_opposite(self, Some(corner), *outer_corner);
+                } else if let Some(twin) = inner_edges.remove(&(edge.1, edge.0)) {
+                    self.set_opposite_relationship(corner, twin);
+                } else {
+                    inner_edges.insert(edge, corner);
+                }
+
+                self.vertices[face_vertices[i]].set_corner_index(corner);
+                copied_corners.push((corner.index(), source_corners[&face_vertices[i]].index()));
+            }
+        }
+
+        // Polygon vertices left without faces
+        for polygon_vertex in polygon {
+            let corner = self.vertices[polygon_vertex].get_corner_index();
+
+            if self.corners[corner].is_deleted() {
+                self.vertices[polygon_vertex].set_deleted(true);
+            }
+        }
+
+        let attribute_rules = self.copy_rules(Vec::new(), copied_corners);
+        self.interpolate_attributes(attribute_rules);
+
+        return Ok(());
+    }
+
+    /// Returns corner opposite to directed edge from `start` to `end`
+    fn find_directed_edge(&self, start: VertexId<TIndex>, end: VertexId<TIndex>) -> Option<CornerId<TIndex>> {
+        if !self.vertices[start].get_corner_index().is_valid() {
+            return None;
+        }
+
+        let mut edge_corner = None;
+        corners_around_vertex(self, start, |corner| {
+            if self.corners[next(*corner)].get_vertex_index() == end {
+                edge_corner = Some(previous(*corner));
+            }
+        });
+
+        return edge_corner;
+    }
+
+    ///
+    /// Returns corners of vertex in counterclockwise order and `true` if vertex is on boundary.
+    /// For boundary vertex order starts at face with outgoing boundary edge.
+    ///
+    fn ordered_vertex_corners(&self, vertex: VertexId<TIndex>) -> (Vec<CornerId<TIndex>>, bool) {
+        let start = self.vertices[vertex].get_corner_index();
+        let mut first = start;
+        let mut is_boundary = false;
+
+        // Rotate clockwise to boundary
+        loop {
+            match self.corners[previous(first)].get_opposite_corner_index() {
+                Some(opposite) => {
+                    first = previous(opposite);
+
+                    if first == start {
+                        break;
+                    }
+                },
+                None => {
+                    is_boundary = true;
+                    break;
+                }
+            }
+        }
+
+        let mut corners = vec![first];
+
+        while let Some(opposite) = self.corners[next(*corners.last().unwrap())].get_opposite_corner_index() {
+            let corner = next(opposite);
+
+            if corner == first {
+                break;
+            }
+
+            corners.push(corner);
+        }
+
+        return (corners, is_boundary);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use nalgebra::Point3;
+    use crate::mesh::{
+        corner_table::{
+            prelude::CornerTableF,
+            test_helpers::{create_unit_cross_square_mesh, create_collapse_edge_sample_mesh1},
+            connectivity::traits::Flags,
+            descriptors::{VertexId, FaceId}
+        },
+        traits::{Mesh, TopologicalMesh}
+    };
+    use crate::geometry::primitives::triangle3::Triangle3;
+    use super::TopologyError;
+
+    fn create_boundary_fan_mesh() -> CornerTableF {
+        let vertices = vec![
+            Point3::new(0.0, 0.0, 0.0),
+            Point3::new(1.0, 0.0, 0.0),
+            Point3::new(0.7, 0.7, 0.0),
+            Point3::new(0.0, 1.0, 0.0),
+            Point3::new(-0.7, 0.7, 0.0)
+        ];
+
+        return CornerTableF::from_vertices_and_indices(&vertices, &[0, 1, 2, 0, 2, 3, 0, 3, 4]);
+    }
+
+    fn mesh_area(mesh: &CornerTableF) -> f32 {
+        return mesh.faces().map(|face| { let triangle = mesh.face_positions(&face); Triangle3::area(triangle.p1(), triangle.p2(), triangle.p3()) }).sum();
+    }
+
+    #[test]
+    fn should_build_mesh_from_faces() {
+        let mut mesh = CornerTableF::new();
+        let v0 = mesh.add_vertex(Point3::new(0.0, 1.0, 0.0));
+        let v1 = mesh.add_vertex(Point3::new(0.0, 0.0, 0.0));
+        let v2 = mesh.add_vertex(Point3::new(1.0, 0.0, 0.0));
+        let v3 = mesh.add_vertex(Point3::new(1.0, 1.0, 0.0));
+        let v4 = mesh.add_vertex(Point3::new(0.5, 0.5, 0.0));
+
+        assert_eq!(mesh.add_face(v0, v1, v4), Ok(FaceId::new(0)));
+        assert_eq!(mesh.add_face(v2, v3, v4), Err(TopologyError::NonManifoldVertex { vertex: v4.index() }));
+        assert_eq!(mesh.add_face(v1, v2, v4), Ok(FaceId::new(1)));
+        assert_eq!(mesh.add_face(v2, v3, v4), Ok(FaceId::new(2)));
+        assert_eq!(mesh.add_face(v3, v0, v4), Ok(FaceId::new(3)));
+
+        assert_eq!(mesh.validate(), vec![]);
+        assert!(!mesh.is_vertex_on_boundary(&v4));
+        assert_eq!(mesh.edges().filter(|edge| mesh.is_edge_on_boundary(edge)).count(), 4);
+    }
+
+    #[test]
+    fn should_reject_invalid_faces() {
+        let mut mesh = create_unit_cross_square_mesh();
+        let [v0, v1, v2, v3, v4] = [0, 1, 2, 3, 4].map(VertexId::new);
+        let v5 = mesh.add_vertex(Point3::new(2.0, 2.0, 0.0));
+        let v6 = mesh.add_vertex(Point3::new(2.0, 3.0, 0.0));
+
+        assert_eq!(mesh.add_face(v0, v1, VertexId::new(10)), Err(TopologyError::InvalidVertex { vertex: 10 }));
+        assert_eq!(mesh.add_face(v0, v0, v5), Err(TopologyError::DegenerateFace));
+        assert_eq!(mesh.add_face(v0, v1, v5), Err(TopologyError::NonManifoldEdge { start: 0, end: 1 }));
+        assert_eq!(mesh.add_face(v4, v5, v6), Err(TopologyError::NonManifoldVertex { vertex: 4 }));
+        assert_eq!(mesh.add_face(v3, v5, v6), Err(TopologyError::NonManifoldVertex { vertex: 3 }));
+        assert_eq!(mesh.faces().count(), 4);
+
+        assert_eq!(mesh.add_face(v3, v2, v5), Ok(FaceId::new(4)));
+        assert_eq!(mesh.add_face(v5, v6, v3), Ok(FaceId::new(5)));
+        assert_eq!(mesh.validate(), vec![]);
+    }
+
+    #[test]
+    fn should_remove_faces() {
+        let mut mesh = create_unit_cross_square_mesh();
+        assert_eq!(mesh.remove_face(FaceId::new(1)), vec![]);
+        assert_eq!(mesh.validate(), vec![]);
+        assert_eq!(mesh.faces().count(), 3);
+        assert!(mesh.is_vertex_on_boundary(&VertexId::new(4)));
+
+        // Middle face of boundary fan, center vertex is split
+        let mut mesh = create_boundary_fan_mesh();
+        let [v0, v1, v2, v3, v4, v5] = [0, 1, 2, 3, 4, 5].map(VertexId::new);
+        assert_eq!(mesh.remove_face(FaceId::new(1)), vec![(v0, v5)]);
+        assert_eq!(mesh.validate(), vec![]);
+        assert_eq!(mesh.face_vertices(&FaceId::new(0)), (v0, v1, v2));
+        assert_eq!(mesh.face_vertices(&FaceId::new(2)), (v5, v3, v4));
+
+        // Vertices of isolated face are removed
+        assert_eq!(mesh.remove_face(FaceId::new(0)), vec![]);
+        assert_eq!(mesh.validate(), vec![]);
+        assert!(mesh.get_vertex(v1).unwrap().is_deleted());
+        assert!(mesh.get_vertex(v0).unwrap().is_deleted());
+        assert_eq!(mesh.vertices().collect::<Vec<_>>(), vec![v3, v4, v5]);
+    }
+
+    #[test]
+    fn should_remove_interior_vertex() {
+        let mut mesh = create_unit_cross_square_mesh();
+        assert_eq!(mesh.remove_vertex(VertexId::new(4)), Ok(()));
+        assert_eq!(mesh.validate(), vec![]);
+        assert_eq!(mesh.faces().count(), 2);
+        assert!((mesh_area(&mesh) - 1.0).abs() < 1e-6);
+
+        for face in mesh.faces() {
+            assert!(mesh.face_normal(&face).z > 0.0);
+        }
+
+        let mut mesh = create_collapse_edge_sample_mesh1();
+        assert_eq!(mesh.remove_vertex(VertexId::new(8)), Ok(()));
+        assert_eq!(mesh.validate(), vec![]);
+        assert_eq!(mesh.faces().count(), 8);
+        assert!((mesh_area(&mesh) - 1.0).abs() < 1e-6);
+    }
+
+    #[test]
+    fn should_remove_boundary_vertex() {
+        let mut mesh = create_boundary_fan_mesh();
+        assert_eq!(mesh.remove_vertex(VertexId::new(0)), Ok(()));
+        assert_eq!(mesh.validate(), vec![]);
+        assert_eq!(mesh.faces().count(), 2);
+
+        for face in mesh.faces() {
+            assert!(mesh.face_normal(&face).z > 0.0);
+        }
+
+        // Single face at boundary vertex
+        let mut mesh = create_collapse_edge_sample_mesh1();
+        assert_eq!(mesh.remove_vertex(VertexId::new(0)), Ok(()));
+        assert_eq!(mesh.validate(), vec![]);
+        assert_eq!(mesh.faces().count(), 9);
+    }
+
+    #[test]
+    fn should_not_duplicate_edges_when_removing_vertex() {
+        // Closed pyramid with non-convex base, base diagonal 2-4 is the only diagonal inside of hole
+        let vertices = vec![
+            Point3::new(0.0, -0.8, 1.0),
+            Point3::new(-2.0, 0.0, 0.0),
+            Point3::new(0.0, -2.0, 0.0),
+            Point3::new(2.0, 0.0, 0.0),
+            Point3::new(0.0, -1.0, 0.0)
+        ];
+        let indices = [0, 1, 2, 0, 2, 3, 0, 3, 4, 0, 4, 1, 1, 4, 2, 2, 4, 3];
+        let mut mesh = CornerTableF::from_vertices_and_indices(&vertices, &indices);
+        assert_eq!(mesh.validate(), vec![]);
+
+        let result = mesh.remove_vertex(VertexId::new(0));
+        assert!(matches!(result, Err(TopologyError::NonManifoldEdge { .. })), "{:?}", result);
+        assert_eq!(mesh.faces().count(), 6);
+        assert_eq!(mesh.validate(), vec![]);
+    }
+}
@@ -0,0 +1,549 @@
+use nalgebra::{Point3, Vector3};
+use crate::{
+    geometry::traits::RealNumber,
+    mesh::traits::{Mesh, TopologicalMesh, EditableMesh, SplitFaceAtPoint, MeshMarker, Marker, Position}
+};
+use super::{
+    table::CornerTable,
+    traversal::{CornerWalker, CornerTableFacesIter, CornerTableVerticesIter, CornerTableEdgesIter, collect_corners_around_vertex},
+    connectivity::{corner::{Corner, next, previous, face_corners}, vertex::Vertex},
+    descriptors::{EdgeRef, ElementIndex, VertexId, FaceId, CornerId},
+    marker::CornerTableMarker
+};
+
+/// Saved element with its attribute values
+type ElementState<TId, TElement, TScalar> = (TId, TElement, Vec<TScalar>);
+
+/// State of subset of corner table elements
+struct Snapshot<TScalar: RealNumber, TIndex: ElementIndex> {
+    vertices_count: usize,
+    corners_count: usize,
+    vertices: Vec<ElementState<VertexId<TIndex>, Vertex<TScalar, TIndex>, TScalar>>,
+    corners: Vec<ElementState<CornerId<TIndex>, Corner<TIndex>, TScalar>>
+}
+
+impl<TScalar: RealNumber, TIndex: ElementIndex> Snapshot<TScalar, TIndex> {
+    fn new(mesh: &CornerTable<TScalar, TIndex>, vertices: &[VertexId<TIndex>], corners: &[CornerId<TIndex>]) -> Self {
+        return Self {
+            vertices_count: mesh.vertices.len(),
+            corners_count: mesh.corners.len(),
+            vertices: vertices.iter()
+                .map(|vertex| (*vertex, mesh.vertices[*vertex].clone(), mesh.attributes.vertex_values(vertex.index())))
+                .collect(),
+            corners: corners.iter()
+                .map(|corner| (*corner, mesh.corners[*corner].clone(), mesh.attributes.corner_values(corner.index())))
+                .collect()
+        };
+    }
+
+    fn restore(&self, mesh: &mut CornerTable<TScalar, TIndex>) {
+        mesh.vertices.resize_with(self.vertices_count, Default::default);
+        mesh.corners.resize_with(self.corners_count, Default::default);
+        mesh.attributes.resize(self.vertices_count, self.corners_count);
+
+        for (index, vertex, values) in &self.vertices {
+            mesh.vertices[*index] = vertex.clone();
+            mesh.attributes.set_vertex_values(index.index(), values);
+        }
+
+        for (index, corner, values) in &self.corners {
+            mesh.corners[*index] = corner.clone();
+            mesh.attributes.set_corner_values(index.index(), values);
+        }
+    }
+}
+
+/// Invertible change made by single editing operation
+struct Delta<TScalar: RealNumber, TIndex: ElementIndex> {
+    before: Snapshot<TScalar, TIndex>,
+    after: Snapshot<TScalar, TIndex>
+}
+
+///
+/// Corner table that records every editing operation, so it can be undone and redone.
+/// Each operation stores only elements it changes, mesh is never copied as a whole.
+/// Implements same mesh traits as [CornerTable], so it can be passed to remeshing and other algorithms.
+///
+/// ## Example
+/// ```ignore
+/// let mut mesh = JournaledCornerTable::new(corner_table);
+/// mesh.checkpoint("before brush");
+/// IncrementalRemesher::new().remesh(&mut mesh, 0.01);
+/// mesh.restore_checkpoint("before brush");
+/// ```
+///
+pub struct JournaledCornerTable<TScalar: RealNumber, TIndex: ElementIndex = usize> {
+    mesh: CornerTable<TScalar, TIndex>,
+    history: Vec<Delta<TScalar, TIndex>>,
+    position: usize,
+    checkpoints: Vec<(String, usize)>
+}
+
+impl<TScalar: RealNumber, TIndex: ElementIndex> JournaledCornerTable<TScalar, TIndex> {
+    pub fn new(mesh: CornerTable<TScalar, TIndex>) -> Self {
+        return Self {
+            mesh,
+            history: Vec::new(),
+            position: 0,
+            checkpoints: Vec::new()
+        };
+    }
+
+    /// Returns current state of mesh
+    #[inline]
+    pub fn mesh(&self) -> &CornerTable<TScalar, TIndex> {
+        return &self.mesh;
+    }
+
+    /// Returns mesh dropping history
+    #[inline]
+    pub fn into_inner(self) -> CornerTable<TScalar, TIndex> {
+        return self.mesh;
+    }
+
+    #[inline]
+    pub fn can_undo(&self) -> bool {
+        return self.position > 0;
+    }
+
+    #[inline]
+    pub fn can_redo(&self) -> bool {
+        return self.position < self.history.len();
+    }
+
+    /// Reverts last operation, returns `false` if there is nothing to undo
+    pub fn undo(&mut self) -> bool {
+        if !self.can_undo() {
+            return false;
+        }
+
+        self.position -= 1;
+        self.history[self.position].before.restore(&mut self.mesh);
+
+        return true;
+    }
+
+    /// Repeats last undone operation, returns `false` if there is nothing to redo
+    pub fn redo(&mut self) -> bool {
+        if !self.can_redo() {
+            return false;
+        }
+
+        self.history[self.position].after.restore(&mut self.mesh);
+        self.position += 1;
+
+        return true;
+    }
+
+    ///
+    /// Remembers current state under given name, existing checkpoint with same name is replaced.
+    /// Checkpoints in undone part of history are dropped when new operation is recorded.
+    ///
+    pub fn checkpoint(&mut self, name: &str) {
+        self.checkpoints.retain(|(checkpoint, _)| checkpoint != name);
+        self.checkpoints.push((name.to_owned(), self.position));
+    }
+
+    /// Undoes or redoes operations to return to checkpoint, returns `false` if checkpoint does not exist
+    pub fn restore_checkpoint(&mut self, name: &str) -> bool {
+        let Some((_, position)) = self.checkpoints.iter().find(|(checkpoint, _)| checkpoint == name) else {
+            return false;
+        };
+
+        let position = *position;
+
+        while self.position > position {
+            self.undo();
+        }
+
+        while self.position < position {
+            self.redo();
+        }
+
+        return true;
+    }
+
+    /// Forgets all recorded operations and checkpoints
+    pub fn clear_history(&mut self) {
+        self.history.clear();
+        self.checkpoints.clear();
+        self.position = 0;
+    }
+
+    ///
+    /// Records operation. Operations change faces around vertices of edited faces only,
+    /// so these faces, their vertices and appended elements are stored.
+    ///
+    fn record<TEdit: FnOnce(&mut CornerTable<TScalar, TIndex>)>(&mut self, faces: &[FaceId<TIndex>], edit: TEdit) {
+        let mut corners = Vec::new();
+
+        for face in faces {
+            for corner in face_corners(*face) {
+                for vertex_corner in collect_corners_around_vertex(&self.mesh, self.mesh.corners[corner].get_vertex_index()) {
+                    corners.extend([vertex_corner, next(vertex_corner), previous(vertex_corner)]);
+                }
+            }
+        }
+
+        corners.sort_unstable();
+        corners.dedup();
+
+        let mut vertices: Vec<_> = corners.iter().map(|corner| self.mesh.corners[*corner].get_vertex_index()).collect();
+        vertices.sort_unstable();
+        vertices.dedup();
+
+        let before = Snapshot::new(&self.mesh, &vertices, &corners);
+
+        edit(&mut self.mesh);
+
+        corners.extend((before.corners_count..self.mesh.corners.len()).map(CornerId::new));
+        vertices.extend((before.vertices_count..self.mesh.vertices.len()).map(VertexId::new));
+        let after = Snapshot::new(&self.mesh, &vertices, &corners);
+
+        self.push(Delta { before, after });
+    }
+
+    fn push(&mut self, delta: Delta<TScalar, TIndex>) {
+        self.history.truncate(self.position);
+        self.checkpoints.retain(|(_, position)| *position <= self.position);
+        self.history.push(delta);
+        self.position += 1;
+    }
+}
+
+impl<TScalar: RealNumber, TIndex: ElementIndex> Mesh for JournaledCornerTable<TScalar, TIndex> {
+    type ScalarType = TScalar;
+
+    type EdgeDescriptor = EdgeRef<TIndex>;
+    type VertexDescriptor = VertexId<TIndex>;
+    type FaceDescriptor = FaceId<TIndex>;
+
+    type FacesIter<'iter> = CornerTableFacesIter<'iter, TScalar, TIndex>;
+    type VerticesIter<'iter> = CornerTableVerticesIter<'iter, TScalar, TIndex>;
+    type EdgesIter<'iter> = CornerTableEdgesIter<'iter, TScalar, TIndex>;
+
+    #[inline]
+    fn from_vertices_and_indices(vertices: &[Point3<Self::ScalarType>], faces: &[usize]) -> Self {
+        return Self::new(CornerTable::from_vertices_and_indices(vertices, faces));
+    }
+
+    #[inline]
+    fn from_vertices_and_indices_with_duplicates(vertices: &[Point3<Self::ScalarType>], faces: &[usize]) -> (Self, Vec<(usize, usize)>) {
+        let (table, duplicates) = CornerTable::from_vertices_and_indices_with_duplicates(vertices, faces);
+        return (Self::new(table), duplicates);
+    }
+
+    #[inline]
+    fn faces(&self) -> Self::FacesIter<'_> {
+        return self.mesh.faces();
+    }
+
+    #[inline]
+    fn vertices(&self) -> Self::VerticesIter<'_> {
+        return self.mesh.vertices();
+    }
+
+    #[inline]
+    fn edges(&self) -> Self::EdgesIter<'_> {
+        return self.mesh.edges();
+    }
+
+    #[inline]
+    fn face_vertices(&self, face: &Self::FaceDescriptor) -> (Self::VertexDescriptor, Self::VertexDescriptor, Self::VertexDescriptor) {
+        return self.mesh.face_vertices(face);
+    }
+
+    #[inline]
+    fn edge_positions(&self, edge: &Self::EdgeDescriptor) -> (Point3<Self::ScalarType>, Point3<Self::ScalarType>) {
+        return self.mesh.edge_positions(edge);
+    }
+
+    #[inline]
+    fn edge_vertices(&self, edge: &Self::EdgeDescriptor) -> (Self::VertexDescriptor, Self::VertexDescriptor) {
+        return self.mesh.edge_vertices(edge);
+    }
+
+    #[inline]
+    fn vertex_position(&self, vertex: &Self::VertexDescriptor) -> &Point3<Self::ScalarType> {
+        return self.mesh.vertex_position(vertex);
+    }
+
+    #[inline]
+    fn vertex_normal(&self, vertex: &Self::VertexDescriptor) -> Option<Vector3<Self::ScalarType>> {
+        return self.mesh.vertex_normal(vertex);
+    }
+
+    #[inline]
+    fn assert_valid(&self) {
+        self.mesh.assert_valid();
+    }
+}
+
+impl<TScalar: RealNumber, TIndex: ElementIndex> TopologicalMesh for JournaledCornerTable<TScalar, TIndex> {
+    type Position<'a> = JournaledCornerWalker<'a, TScalar, TIndex>;
+
+    #[inline]
+    fn vertices_around_vertex<TVisit: FnMut(&Self::VertexDescriptor)>(&self, vertex: &Self::VertexDescriptor, visit: TVisit) {
+        self.mesh.vertices_around_vertex(vertex, visit);
+    }
+
+    #[inline]
+    fn faces_around_vertex<TVisit: FnMut(&Self::FaceDescriptor)>(&self, vertex: &Self::VertexDescriptor, visit: TVisit) {
+        self.mesh.faces_around_vertex(vertex, visit);
+    }
+
+    #[inline]
+    fn edges_around_vertex<TVisit: FnMut(&Self::EdgeDescriptor)>(&self, vertex: &Self::VertexDescriptor, visit: TVisit) {
+        self.mesh.edges_around_vertex(vertex, visit);
+    }
+
+    #[inline]
+    fn is_vertex_on_boundary(&self, vertex: &Self::VertexDescriptor) -> bool {
+        return self.mesh.is_vertex_on_boundary(vertex);
+    }
+
+    #[inline]
+    fn is_edge_on_boundary(&self, edge: &Self::EdgeDescriptor) -> bool {
+        return self.mesh.is_edge_on_boundary(edge);
+    }
+
+    #[inline]
+    fn edge_faces(&self, edge: &Self::EdgeDescriptor) -> (Self::FaceDescriptor, Option<Self::FaceDescriptor>) {
+        return self.mesh.edge_faces(edge);
+    }
+
+    #[inline]
+    fn face_edges(&self, face: &Self::FaceDescriptor) -> (Self::EdgeDescriptor, Self::EdgeDescriptor, Self::EdgeDescriptor) {
+        return self.mesh.face_edges(face);
+    }
+}
+
+impl<TScalar: RealNumber, TIndex: ElementIndex> EditableMesh for JournaledCornerTable<TScalar, TIndex> {
+    fn collapse_edge(&mut self, edge: &Self::EdgeDescriptor, at: &Point3<Self::ScalarType>) {
+        let (face, opposite_face) = self.mesh.edge_faces(edge);
+        let faces: Vec<_> = [Some(face), opposite_face].into_iter().flatten().collect();
+        self.record(&faces, |mesh| mesh.collapse_edge(edge, at));
+    }
+
+    fn flip_edge(&mut self, edge: &Self::EdgeDescriptor) {
+        let (face, opposite_face) = self.mesh.edge_faces(edge);
+        let faces: Vec<_> = [Some(face), opposite_face].into_iter().flatten().collect();
+        self.record(&faces, |mesh| mesh.flip_edge(edge));
+    }
+
+    fn split_edge(&mut self, edge: &Self::EdgeDescriptor, at: &Point3<Self::ScalarType>) {
+        let (face, opposite_face) = self.mesh.edge_faces(edge);
+        let faces: Vec<_> = [Some(face), opposite_face].into_iter().flatten().collect();
+        self.record(&faces, |mesh| mesh.split_edge(edge, at));
+    }
+
+    fn shift_vertex(&mut self, vertex: &Self::VertexDescriptor, to: &Point3<Self::ScalarType>) {
+        let vertices = [*vertex];
+        let before = Snapshot::new(&self.mesh, &vertices, &[]);
+        self.mesh.shift_vertex(vertex, to);
+        let after = Snapshot::new(&self.mesh, &vertices, &[]);
+
+        self.push(Delta { before, after });
+    }
+
+    #[inline]
+    fn edge_exist(&self, edge: &Self::EdgeDescriptor) -> bool {
+        return self.mesh.edge_exist(edge);
+    }
+}
+
+impl<TScalar: RealNumber, TIndex: ElementIndex> SplitFaceAtPoint for JournaledCornerTable<TScalar, TIndex> {
+    fn split_face(&mut self, face: &Self::FaceDescriptor, point: Point3<Self::ScalarType>) {
+        self.record(&[*face], |mesh| mesh.split_face(face, point));
+    }
+}
+
+impl<TScalar: RealNumber, TIndex: ElementIndex> MeshMarker for JournaledCornerTable<TScalar, TIndex> {
+    type Marker = JournaledCornerTableMarker<TScalar, TIndex>;
+
+    #[inline]
+    fn marker(&self) -> Self::Marker {
+        return JournaledCornerTableMarker(CornerTableMarker::new(&self.mesh));
+    }
+}
+
+/// Implementation of [Position] for [JournaledCornerTable], same as [CornerWalker]
+pub struct JournaledCornerWalker<'a, TScalar: RealNumber, TIndex: ElementIndex = usize>(CornerWalker<'a, TScalar, TIndex>);
+
+impl<'a, TScalar: RealNumber, TIndex: ElementIndex> Position<'a, JournaledCornerTable<TScalar, TIndex>> for JournaledCornerWalker<'a, TScalar, TIndex> {
+    #[inline]
+    fn from_vertex_on_face(mesh: &'a JournaledCornerTable<TScalar, TIndex>, face: &FaceId<TIndex>, vertex: &VertexId<TIndex>) -> Self {
+        return Self(CornerWalker::from_vertex_on_face(&mesh.mesh, face, vertex));
+    }
+
+    #[inline]
+    fn from_edge_on_face(mesh: &'a JournaledCornerTable<TScalar, TIndex>, face: &FaceId<TIndex>, edge: &EdgeRef<TIndex>) -> Self {
+        return Self(CornerWalker::from_edge_on_face(&mesh.mesh, face, edge));
+    }
+
+    #[inline]
+    fn from_edge(mesh: &'a JournaledCornerTable<TScalar, TIndex>, edge: &EdgeRef<TIndex>) -> Self {
+        return Self(<CornerWalker<'a, TScalar, TIndex> as Position<'a, CornerTable<TScalar, TIndex>>>::from_edge(&mesh.mesh, edge));
+    }
+
+    #[inline]
+    fn set_from_vertex_on_face(&mut self, face: &FaceId<TIndex>, vertex: &VertexId<TIndex>) -> &mut Self {
+        self.0.set_from_vertex_on_face(face, vertex);
+        return self;
+    }
+
+    #[inline]
+    fn set_from_edge_on_face(&mut self, face: &FaceId<TIndex>, edge: &EdgeRef<TIndex>) -> &mut Self {
+        self.0.set_from_edge_on_face(face, edge);
+        return self;
+    }
+
+    #[inline]
+    fn next(&mut self) -> &mut Self {
+        self.0.next();
+        return self;
+    }
+
+    #[inline]
+    fn opposite(&mut self) -> &mut Self {
+        self.0.opposite();
+        return self;
+    }
+
+    #[inline]
+    fn get_vertex(&self) -> VertexId<TIndex> {
+        return Position::get_vertex(&self.0);
+    }
+}
+
+/// Implementation of [Marker] API for [JournaledCornerTable]. Marks are not recorded in history.
+pub struct JournaledCornerTableMarker<TScalar: RealNumber, TIndex: ElementIndex = usize>(CornerTableMarker<TScalar, TIndex>);
+
+impl<TScalar: RealNumber, TIndex: ElementIndex> Marker<JournaledCornerTable<TScalar, TIndex>> for JournaledCornerTableMarker<TScalar, TIndex> {
+    #[inline]
+    fn mark_face(&mut self, face: &FaceId<TIndex>, marked: bool) {
+        self.0.mark_face(face, marked);
+    }
+
+    #[inline]
+    fn is_face_marked(&self, face: &FaceId<TIndex>) -> bool {
+        return self.0.is_face_marked(face);
+    }
+
+    #[inline]
+    fn mark_vertex(&mut self, vertex: &VertexId<TIndex>, marked: bool) {
+        self.0.mark_vertex(vertex, marked);
+    }
+
+    #[inline]
+    fn is_vertex_marked(&self, vertex: &VertexId<TIndex>) -> bool {
+        return self.0.is_vertex_marked(vertex);
+    }
+
+    #[inline]
+    fn mark_edge(&mut self, edge: &EdgeRef<TIndex>, marked: bool) {
+        self.0.mark_edge(edge, marked);
+    }
+
+    #[inline]
+    fn is_edge_marked(&self, edge: &EdgeRef<TIndex>) -> bool {
+        return self.0.is_edge_marked(edge);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use nalgebra::Point3;
+    use crate::{
+        mesh::{
+            corner_table::{
+                prelude::CornerTableF,
+                test_helpers::{create_uv_sphere_mesh, create_unit_cross_square_mesh},
+                attributes::Interpolation,
+                descriptors::{EdgeRef, CornerId, VertexId, FaceId}
+            },
+            traits::{Mesh, EditableMesh, SplitFaceAtPoint}
+        },
+        remeshing::incremental::IncrementalRemesher
+    };
+    use super::JournaledCornerTable;
+
+    fn triangles(mesh: &CornerTableF) -> Vec<[Point3<f32>; 3]> {
+        return mesh.faces()
+            .map(|face| {
+                let (v1, v2, v3) = mesh.face_vertices(&face);
+                return [*mesh.vertex_position(&v1), *mesh.vertex_position(&v2), *mesh.vertex_position(&v3)];
+            })
+            .collect();
+    }
+
+    #[test]
+    fn should_undo_and_redo_operations() {
+        let original = create_unit_cross_square_mesh();
+        let original_triangles = triangles(&original);
+        let mut mesh = JournaledCornerTable::new(original);
+
+        let edge = EdgeRef::new(CornerId::new(0), mesh.mesh());
+        mesh.split_edge(&edge, &Point3::new(0.5, 0.5, 0.0));
+        mesh.split_face(&FaceId::new(1), Point3::new(0.6, 0.3, 0.0));
+        mesh.shift_vertex(&VertexId::new(4), &Point3::new(0.4, 0.4, 0.0));
+        let edge = EdgeRef::new(CornerId::new(1), mesh.mesh());
+        mesh.flip_edge(&edge);
+        let edge = EdgeRef::new(CornerId::new(2), mesh.mesh());
+        mesh.collapse_edge(&edge, &Point3::new(0.5, 0.5, 0.0));
+        assert_eq!(mesh.mesh().validate(), vec![]);
+
+        let edited_triangles = triangles(mesh.mesh());
+
+        while mesh.undo() {
+            assert_eq!(mesh.mesh().validate(), vec![]);
+        }
+
+        assert!(!mesh.can_undo());
+        assert_eq!(triangles(mesh.mesh()), original_triangles);
+
+        while mesh.redo() {
+            assert_eq!(mesh.mesh().validate(), vec![]);
+        }
+
+        assert_eq!(triangles(mesh.mesh()), edited_triangles);
+    }
+
+    #[test]
+    fn should_restore_checkpoints() {
+        let mut original = create_uv_sphere_mesh(8, 12);
+        let attribute = original.add_vertex_attribute("height", 1, Interpolation::Linear);
+
+        for vertex in original.vertices().collect::<Vec<_>>() {
+            original.vertex_attribute_mut(attribute, vertex)[0] = original.vertex_position(&vertex).z;
+        }
+
+        let original_triangles = triangles(&original);
+        let mut mesh = JournaledCornerTable::new(original);
+        mesh.checkpoint("original");
+
+        let remesher = IncrementalRemesher::new().with_iterations_count(2).with_project_vertices(false);
+        remesher.remesh(&mut mesh, 0.2);
+        assert_eq!(mesh.mesh().validate(), vec![]);
+        let remeshed_triangles = triangles(mesh.mesh());
+        mesh.checkpoint("remeshed");
+
+        assert!(mesh.restore_checkpoint("original"));
+        assert_eq!(triangles(mesh.mesh()), original_triangles);
+
+        for vertex in mesh.vertices() {
+            assert_eq!(mesh.mesh().vertex_attribute(attribute, vertex)[0], mesh.vertex_position(&vertex).z);
+        }
+
+        assert!(mesh.restore_checkpoint("remeshed"));
+        assert_eq!(triangles(mesh.mesh()), remeshed_triangles);
+
+        // New edit drops checkpoints of undone operations
+        mesh.restore_checkpoint("original");
+        mesh.shift_vertex(&VertexId::new(0), &Point3::new(0.0, 0.0, 2.0));
+        assert!(!mesh.restore_checkpoint("remeshed"));
+        assert!(!mesh.can_redo());
+        assert!(mesh.restore_checkpoint("original"));
+        assert_eq!(triangles(mesh.mesh()), original_triangles);
+    }
+}
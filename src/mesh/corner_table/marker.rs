@@ -1,33 +1,33 @@
 use crate::{geometry::traits::RealNumber, mesh::traits::{Marker, Mesh}};
 
-use super::{table::CornerTable, connectivity::{traits::Flags, corner}};
+use super::{table::CornerTable, connectivity::{traits::Flags, corner}, descriptors::ElementIndex};
 
 /// Implementation of [Marker] API for [CornerTable] 
-pub struct CornerTableMarker<TScalar: RealNumber> {
-    corner_table: *const CornerTable<TScalar>
+pub struct CornerTableMarker<TScalar: RealNumber, TIndex: ElementIndex = usize> {
+    corner_table: *const CornerTable<TScalar, TIndex>
 }
 
-impl<TScalar: RealNumber> CornerTableMarker<TScalar> {
-    pub fn new(corner_table: &CornerTable<TScalar>) -> Self { 
+impl<TScalar: RealNumber, TIndex: ElementIndex> CornerTableMarker<TScalar, TIndex> {
+    pub fn new(corner_table: &CornerTable<TScalar, TIndex>) -> Self { 
         return Self { corner_table } ;
     }
 }
 
-impl<TScalar: RealNumber> Marker<CornerTable<TScalar>> for CornerTableMarker<TScalar> {
+impl<TScalar: RealNumber, TIndex: ElementIndex> Marker<CornerTable<TScalar, TIndex>> for CornerTableMarker<TScalar, TIndex> {
 
     //
     // Face
     //
 
     #[inline]
-    fn mark_face(&mut self, face: &<CornerTable<TScalar> as Mesh>::FaceDescriptor, marked: bool) {
-        let first_corner = corner::first_corner_from_corner(*face);
+    fn mark_face(&mut self, face: &<CornerTable<TScalar, TIndex> as Mesh>::FaceDescriptor, marked: bool) {
+        let first_corner = corner::first_corner(*face);
         unsafe { (*self.corner_table).corners[first_corner].set_marked_1(marked); }
     }
 
     #[inline]
-    fn is_face_marked(&self, face: &<CornerTable<TScalar> as Mesh>::FaceDescriptor) -> bool {
-        let first_corner = corner::first_corner_from_corner(*face);
+    fn is_face_marked(&self, face: &<CornerTable<TScalar, TIndex> as Mesh>::FaceDescriptor) -> bool {
+        let first_corner = corner::first_corner(*face);
         unsafe { return (*self.corner_table).corners[first_corner].is_marked_1(); }
     }
 
@@ -36,12 +36,12 @@ impl<TScalar: RealNumber> Marker<CornerTable<TScalar>> for CornerTableMarker<TSc
     //
 
     #[inline]
-    fn mark_vertex(&mut self, vertex: &<CornerTable<TScalar> as Mesh>::VertexDescriptor, marked: bool) {
+    fn mark_vertex(&mut self, vertex: &<CornerTable<TScalar, TIndex> as Mesh>::VertexDescriptor, marked: bool) {
         unsafe { (*self.corner_table).vertices[*vertex].set_marked_1(marked); }
     }
 
     #[inline]
-    fn is_vertex_marked(&self, vertex: &<CornerTable<TScalar> as Mesh>::VertexDescriptor) -> bool {
+    fn is_vertex_marked(&self, vertex: &<CornerTable<TScalar, TIndex> as Mesh>::VertexDescriptor) -> bool {
         unsafe { return (*self.corner_table).vertices[*vertex].is_marked_1(); }
     }
 
@@ -50,7 +50,7 @@ impl<TScalar: RealNumber> Marker<CornerTable<TScalar>> for CornerTableMarker<TSc
     // 
 
     #[inline]
-    fn mark_edge(&mut self, edge: &<CornerTable<TScalar> as Mesh>::EdgeDescriptor, marked: bool)  {
+    fn mark_edge(&mut self, edge: &<CornerTable<TScalar, TIndex> as Mesh>::EdgeDescriptor, marked: bool)  {
         unsafe { 
             let corner = &(*self.corner_table).corners[edge.get_corner_index()];
             corner.set_marked_2(marked);
@@ -62,7 +62,7 @@ impl<TScalar: RealNumber> Marker<CornerTable<TScalar>> for CornerTableMarker<TSc
     }
 
     #[inline]
-    fn is_edge_marked(&self, edge: &<CornerTable<TScalar> as Mesh>::EdgeDescriptor) -> bool {
+    fn is_edge_marked(&self, edge: &<CornerTable<TScalar, TIndex> as Mesh>::EdgeDescriptor) -> bool {
         unsafe { return (*self.corner_table).corners[edge.get_corner_index()].is_marked_2(); }
     }
 }
@@ -2,11 +2,21 @@ pub mod table;
 pub mod prelude;
 pub mod traversal;
 pub mod connectivity;
+pub mod compaction;
+pub mod attributes;
+pub mod validation;
+pub mod editing;
+pub mod journal;
+pub mod components;
+pub mod submesh;
 
 mod marker;
 mod editable;
-mod descriptors;
+pub mod descriptors;
 mod property_maps;
 
+#[cfg(feature = "serde")]
+pub mod serialization;
+
 #[cfg(test)]
-mod test_helpers;
+pub(crate) mod test_helpers;
@@ -2,3 +2,7 @@ use super::{table::CornerTable};
 
 pub type CornerTableF = CornerTable<f32>;
 pub type CornerTableD = CornerTable<f64>;
+
+/// Corner tables with 32-bit indices, for meshes with less than `u32::MAX` corners
+pub type CompactCornerTableF = CornerTable<f32, u32>;
+pub type CompactCornerTableD = CornerTable<f64, u32>;
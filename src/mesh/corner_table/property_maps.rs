@@ -1,56 +1,207 @@
-use std::ops::{Index, IndexMut};
+use std::{ops::{Index, IndexMut}, marker::PhantomData};
 
-use crate::{mesh::traits::{PropertyMap, VertexProperties}, geometry::traits::RealNumber};
+use crate::{mesh::traits::{PropertyMap, VertexProperties, FaceProperties, EdgeProperties}, geometry::traits::RealNumber};
 
-use super::table::CornerTable;
+use super::{table::CornerTable, descriptors::{EdgeRef, ElementIndex, VertexId, FaceId}};
 
 /// Property map for corner table vertices
-pub struct VertexPropertyMap<TProperty: Default> {
-    props: Vec<TProperty>
+pub struct VertexPropertyMap<TProperty: Default, TIndex: ElementIndex = usize> {
+    props: Vec<TProperty>,
+    index_type: PhantomData<TIndex>
 }
 
-impl<TProperty: Default> VertexPropertyMap<TProperty> {
+impl<TProperty: Default, TIndex: ElementIndex> VertexPropertyMap<TProperty, TIndex> {
     pub fn new(vertices_count: usize) -> Self {
         let mut props = Vec::new();
         props.resize_with(vertices_count, Default::default);
-        return Self { props };
+        return Self { props, index_type: PhantomData };
     }
 }
 
-impl<TProperty: Default> Index<usize> for VertexPropertyMap<TProperty> {
+impl<TProperty: Default, TIndex: ElementIndex> Index<VertexId<TIndex>> for VertexPropertyMap<TProperty, TIndex> {
     type Output = TProperty;
 
     #[inline]
-    fn index(&self, index: usize) -> &Self::Output {
-        return &self.props[index];
+    fn index(&self, index: VertexId<TIndex>) -> &Self::Output {
+        return &self.props[index.index()];
     }
 }
 
-impl<TProperty: Default> IndexMut<usize> for VertexPropertyMap<TProperty> {
+impl<TProperty: Default, TIndex: ElementIndex> IndexMut<VertexId<TIndex>> for VertexPropertyMap<TProperty, TIndex> {
     #[inline]
-    fn index_mut(&mut self, index: usize) -> &mut Self::Output {
-        return &mut self.props[index];
+    fn index_mut(&mut self, index: VertexId<TIndex>) -> &mut Self::Output {
+        return &mut self.props[index.index()];
     }
 }
 
-impl<TProperty: Default> PropertyMap<usize, TProperty> for VertexPropertyMap<TProperty> {
+impl<TProperty: Default, TIndex: ElementIndex> PropertyMap<VertexId<TIndex>, TProperty> for VertexPropertyMap<TProperty, TIndex> {
     #[inline]
-    fn get(&self, key: &usize) -> Option<&TProperty> {
-        return self.props.get(*key);
+    fn get(&self, key: &VertexId<TIndex>) -> Option<&TProperty> {
+        return self.props.get(key.index());
     }
 
     #[inline]
-    fn get_mut(&mut self, key: &usize) -> Option<&mut TProperty> {
-        return self.props.get_mut(*key);
+    fn get_mut(&mut self, key: &VertexId<TIndex>) -> Option<&mut TProperty> {
+        return self.props.get_mut(key.index());
     }
 }
 
 /// Implementation of vertex property maps for corner table
-impl<TScalar: RealNumber> VertexProperties for CornerTable<TScalar> {
-    type VertexPropertyMap<TProperty: Default> = VertexPropertyMap<TProperty>;
+impl<TScalar: RealNumber, TIndex: ElementIndex> VertexProperties for CornerTable<TScalar, TIndex> {
+    type VertexPropertyMap<TProperty: Default> = VertexPropertyMap<TProperty, TIndex>;
 
     #[inline]
     fn create_vertex_properties_map<TProperty: Default>(&self) -> Self::VertexPropertyMap<TProperty> {
         return VertexPropertyMap::new(self.vertices.len());
     }
 }
+
+/// Property map for corner table faces
+pub struct FacePropertyMap<TProperty: Default, TIndex: ElementIndex = usize> {
+    props: Vec<TProperty>,
+    index_type: PhantomData<TIndex>
+}
+
+impl<TProperty: Default, TIndex: ElementIndex> FacePropertyMap<TProperty, TIndex> {
+    pub fn new(faces_count: usize) -> Self {
+        let mut props = Vec::new();
+        props.resize_with(faces_count, Default::default);
+        return Self { props, index_type: PhantomData };
+    }
+}
+
+impl<TProperty: Default, TIndex: ElementIndex> Index<FaceId<TIndex>> for FacePropertyMap<TProperty, TIndex> {
+    type Output = TProperty;
+
+    #[inline]
+    fn index(&self, index: FaceId<TIndex>) -> &Self::Output {
+        return &self.props[index.index()];
+    }
+}
+
+impl<TProperty: Default, TIndex: ElementIndex> IndexMut<FaceId<TIndex>> for FacePropertyMap<TProperty, TIndex> {
+    #[inline]
+    fn index_mut(&mut self, index: FaceId<TIndex>) -> &mut Self::Output {
+        return &mut self.props[index.index()];
+    }
+}
+
+impl<TProperty: Default, TIndex: ElementIndex> PropertyMap<FaceId<TIndex>, TProperty> for FacePropertyMap<TProperty, TIndex> {
+    #[inline]
+    fn get(&self, key: &FaceId<TIndex>) -> Option<&TProperty> {
+        return self.props.get(key.index());
+    }
+
+    #[inline]
+    fn get_mut(&mut self, key: &FaceId<TIndex>) -> Option<&mut TProperty> {
+        return self.props.get_mut(key.index());
+    }
+}
+
+/// Implementation of face property maps for corner table
+impl<TScalar: RealNumber, TIndex: ElementIndex> FaceProperties for CornerTable<TScalar, TIndex> {
+    type FacePropertyMap<TProperty: Default> = FacePropertyMap<TProperty, TIndex>;
+
+    #[inline]
+    fn create_face_properties_map<TProperty: Default>(&self) -> Self::FacePropertyMap<TProperty> {
+        return FacePropertyMap::new(self.corners.len() / 3);
+    }
+}
+
+/// 
+/// Property map for corner table edges.
+/// Edge reference is a corner opposite to edge with smaller index, so it is used as a key directly.
+/// 
+pub struct EdgePropertyMap<TProperty: Default, TIndex: ElementIndex = usize> {
+    props: Vec<TProperty>,
+    index_type: PhantomData<TIndex>
+}
+
+impl<TProperty: Default, TIndex: ElementIndex> EdgePropertyMap<TProperty, TIndex> {
+    pub fn new(corners_count: usize) -> Self {
+        let mut props = Vec::new();
+        props.resize_with(corners_count, Default::default);
+        return Self { props, index_type: PhantomData };
+    }
+}
+
+impl<TProperty: Default, TIndex: ElementIndex> Index<EdgeRef<TIndex>> for EdgePropertyMap<TProperty, TIndex> {
+    type Output = TProperty;
+
+    #[inline]
+    fn index(&self, edge: EdgeRef<TIndex>) -> &Self::Output {
+        return &self.props[edge.get_corner_index().index()];
+    }
+}
+
+impl<TProperty: Default, TIndex: ElementIndex> IndexMut<EdgeRef<TIndex>> for EdgePropertyMap<TProperty, TIndex> {
+    #[inline]
+    fn index_mut(&mut self, edge: EdgeRef<TIndex>) -> &mut Self::Output {
+        return &mut self.props[edge.get_corner_index().index()];
+    }
+}
+
+impl<TProperty: Default, TIndex: ElementIndex> PropertyMap<EdgeRef<TIndex>, TProperty> for EdgePropertyMap<TProperty, TIndex> {
+    #[inline]
+    fn get(&self, edge: &EdgeRef<TIndex>) -> Option<&TProperty> {
+        return self.props.get(edge.get_corner_index().index());
+    }
+
+    #[inline]
+    fn get_mut(&mut self, edge: &EdgeRef<TIndex>) -> Option<&mut TProperty> {
+        return self.props.get_mut(edge.get_corner_index().index());
+    }
+}
+
+/// Implementation of edge property maps for corner table
+impl<TScalar: RealNumber, TIndex: ElementIndex> EdgeProperties for CornerTable<TScalar, TIndex> {
+    type EdgePropertyMap<TProperty: Default> = EdgePropertyMap<TProperty, TIndex>;
+
+    #[inline]
+    fn create_edge_properties_map<TProperty: Default>(&self) -> Self::EdgePropertyMap<TProperty> {
+        return EdgePropertyMap::new(self.corners.len());
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::mesh::{
+        corner_table::{test_helpers::create_unit_cross_square_mesh, descriptors::{EdgeRef, CornerId}},
+        traits::{Mesh, TopologicalMesh, FaceProperties, EdgeProperties}
+    };
+
+    #[test]
+    fn face_properties() {
+        let mesh = create_unit_cross_square_mesh();
+        let mut props = mesh.create_face_properties_map();
+
+        for (index, face) in mesh.faces().enumerate() {
+            props[face] = index;
+        }
+
+        for (index, face) in mesh.faces().enumerate() {
+            assert_eq!(props[face], index);
+        }
+    }
+
+    #[test]
+    fn edge_properties_are_shared_by_opposite_corners() {
+        let mesh = create_unit_cross_square_mesh();
+        let mut props = mesh.create_edge_properties_map();
+
+        for (index, edge) in mesh.edges().enumerate() {
+            props[edge] = index + 1;
+        }
+
+        for corner_index in (0..mesh.corners.len()).map(CornerId::new) {
+            let edge = EdgeRef::new(corner_index, &mesh);
+            assert_ne!(props[edge], 0);
+
+            if let Some(opposite) = mesh.corners[corner_index].get_opposite_corner_index() {
+                assert_eq!(props[edge], props[EdgeRef::new(opposite, &mesh)]);
+            } else {
+                assert!(mesh.is_edge_on_boundary(&edge));
+            }
+        }
+    }
+}
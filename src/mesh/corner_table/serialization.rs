@@ -0,0 +1,161 @@
+use serde::{Serialize, Serializer};
+
+use crate::geometry::traits::RealNumber;
+use super::{
+    table::CornerTable,
+    connectivity::{corner::Corner, vertex::Vertex, traits::Flags},
+    descriptors::{ElementIndex, CornerId, VertexId}
+};
+
+///
+/// Serializable view of corner table that skips deleted vertices and faces.
+/// Indices are remapped same way as by [CornerTable::compact], so result is deserialized as regular [CornerTable] without deleted elements.
+/// Attribute values of deleted elements are skipped as well.
+///
+/// ## Example
+/// ```ignore
+/// // Deleted elements are preserved
+/// let json = serde_json::to_string(&mesh)?;
+/// // Deleted elements are removed
+/// let json = serde_json::to_string(&mesh.compacted())?;
+/// ```
+///
+pub struct CompactedCornerTable<'a, TScalar: RealNumber, TIndex: ElementIndex = usize> {
+    mesh: &'a CornerTable<TScalar, TIndex>
+}
+
+impl<TScalar: RealNumber, TIndex: ElementIndex> CornerTable<TScalar, TIndex> {
+    /// Returns view of corner table that is serialized without deleted vertices and faces
+    #[inline]
+    pub fn compacted(&self) -> CompactedCornerTable<'_, TScalar, TIndex> {
+        return CompactedCornerTable { mesh: self };
+    }
+}
+
+impl<TScalar: RealNumber + Serialize, TIndex: ElementIndex + Serialize> Serialize for CompactedCornerTable<'_, TScalar, TIndex> {
+    fn serialize<TSerializer: Serializer>(&self, serializer: TSerializer) -> Result<TSerializer::Ok, TSerializer::Error> {
+        let mesh = self.mesh;
+        let map = mesh.compaction_map();
+        let mut compacted = CornerTable::<TScalar, TIndex>::new();
+
+        for vertex in mesh.vertices.iter().filter(|vertex| !vertex.is_deleted()) {
+            let corner = map.corner(vertex.get_corner_index()).unwrap_or(CornerId::INVALID);
+            compacted.vertices.push(Vertex::new(corner, *vertex.get_position(), vertex.get_flags().get()));
+        }
+
+        for corner in mesh.corners.iter().filter(|corner| !corner.is_deleted()) {
+            let opposite = corner.get_opposite_corner_index().and_then(|opposite| map.corner(opposite));
+            let vertex = map.vertex(corner.get_vertex_index()).unwrap_or(VertexId::INVALID);
+            compacted.corners.push(Corner::new(opposite, vertex, corner.get_flags().get()));
+        }
+
+        compacted.attributes = mesh.attributes.serializable();
+        compacted.attributes.retain(|vertex| !mesh.vertices[vertex].is_deleted(), |corner| !mesh.corners[corner].is_deleted());
+
+        return compacted.serialize(serializer);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use nalgebra::Point3;
+
+    use crate::mesh::{
+        corner_table::{
+            prelude::{CornerTableF, CompactCornerTableF},
+            test_helpers::create_collapse_edge_sample_mesh1,
+            descriptors::{EdgeRef, CornerId},
+            attributes::Interpolation
+        },
+        traits::{EditableMesh, Mesh}
+    };
+
+    fn sorted_faces(mesh: &CornerTableF) -> Vec<[[i32; 3]; 3]> {
+        let mut faces: Vec<_> = mesh.faces()
+            .map(|face| {
+                let triangle = mesh.face_positions(&face);
+                [triangle.p1(), triangle.p2(), triangle.p3()].map(|p| [p.x, p.y, p.z].map(|c| (c * 1000.0) as i32))
+            })
+            .collect();
+        faces.sort();
+        return faces;
+    }
+
+    #[test]
+    fn serialize_with_deleted_elements() {
+        let mut mesh = create_collapse_edge_sample_mesh1();
+        mesh.collapse_edge(&EdgeRef::new(CornerId::new(9), &mesh), &Point3::new(0.5, 0.5, 0.0));
+
+        let preserved: CornerTableF = serde_json::from_str(&serde_json::to_string(&mesh).unwrap()).unwrap();
+        assert_eq!(preserved.vertices.len(), mesh.vertices.len());
+        assert_eq!(preserved.corners.len(), mesh.corners.len());
+        assert_eq!(preserved.vertices, mesh.vertices);
+        assert_eq!(preserved.corners, mesh.corners);
+        assert_eq!(sorted_faces(&preserved), sorted_faces(&mesh));
+
+        let compacted: CornerTableF = serde_json::from_str(&serde_json::to_string(&mesh.compacted()).unwrap()).unwrap();
+        assert_eq!(compacted.vertices.len(), mesh.vertices().count());
+        assert_eq!(compacted.corners.len(), mesh.faces().count() * 3);
+        assert_eq!(sorted_faces(&compacted), sorted_faces(&mesh));
+        assert!(compacted.vertices().all(|vertex| compacted.get_vertex(vertex).unwrap().get_corner_index().index() < compacted.corners.len()));
+    }
+
+    #[test]
+    fn serialize_missing_opposite_corner_as_none() {
+        let mesh = CompactCornerTableF::from_vertices_and_indices(&[
+            Point3::new(0.0, 0.0, 0.0),
+            Point3::new(1.0, 0.0, 0.0),
+            Point3::new(0.0, 1.0, 0.0)
+        ], &[0, 1, 2]);
+
+        let json = serde_json::to_value(&mesh).unwrap();
+        assert!(json["corners"][0]["opposite_corner_index"].is_null());
+        assert_eq!(json["corners"][0]["vertex_index"], 0);
+
+        let read_back: CompactCornerTableF = serde_json::from_value(json).unwrap();
+        assert_eq!(read_back.corners, mesh.corners);
+        assert_eq!(read_back.corners[0].get_opposite_corner_index(), None);
+    }
+
+    #[test]
+    fn serialize_attributes() {
+        let mut mesh = create_collapse_edge_sample_mesh1();
+        let height = mesh.add_vertex_attribute("height", 1, Interpolation::Linear);
+        let uv = mesh.add_corner_attribute("uv", 2, Interpolation::Nearest);
+        mesh.add_vertex_attribute("custom", 1, Interpolation::Custom(Box::new(|_, output| output.fill(0.0))));
+
+        for vertex in mesh.vertices().collect::<Vec<_>>() {
+            mesh.vertex_attribute_mut(height, vertex)[0] = mesh.vertex_position(&vertex).x;
+        }
+
+        for corner in 0..mesh.corners.len() {
+            mesh.corner_attribute_mut(uv, CornerId::new(corner)).copy_from_slice(&[corner as f32, 1.0]);
+        }
+
+        let preserved: CornerTableF = serde_json::from_str(&serde_json::to_string(&mesh).unwrap()).unwrap();
+        assert_eq!(preserved.find_vertex_attribute("height"), Some(height));
+        assert_eq!(preserved.find_corner_attribute("uv"), Some(uv));
+        assert_eq!(preserved.find_vertex_attribute("custom"), None);
+
+        for vertex in mesh.vertices() {
+            assert_eq!(preserved.vertex_attribute(height, vertex), mesh.vertex_attribute(height, vertex));
+        }
+
+        // Values of deleted elements are dropped together with them
+        mesh.collapse_edge(&EdgeRef::new(CornerId::new(9), &mesh), &Point3::new(0.5, 0.5, 0.0));
+        let compacted: CornerTableF = serde_json::from_str(&serde_json::to_string(&mesh.compacted()).unwrap()).unwrap();
+
+        for face in compacted.faces() {
+            let (v1, _, _) = compacted.face_vertices(&face);
+            let corner = CornerId::new(face.index() * 3);
+            assert_eq!(compacted.vertex_attribute(height, v1)[0], compacted.vertex_position(&v1).x);
+            assert_eq!(compacted.corner_attribute(uv, corner)[1], 1.0);
+        }
+
+        // Tables serialized before attributes were supported
+        let mut json = serde_json::to_value(&mesh).unwrap();
+        json.as_object_mut().unwrap().remove("attributes");
+        let without_attributes: CornerTableF = serde_json::from_value(json).unwrap();
+        assert_eq!(without_attributes.find_vertex_attribute("height"), None);
+    }
+}
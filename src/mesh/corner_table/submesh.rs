@@ -0,0 +1,262 @@
+use std::collections::HashMap;
+
+use crate::{data_structures::vertex_index_map::PointIndexMap, geometry::traits::RealNumber, mesh::traits::Mesh};
+use super::{
+    table::CornerTable,
+    traversal::collect_corners_around_vertex,
+    connectivity::{traits::Flags, corner::{next, previous, face, first_corner, face_corners}},
+    descriptors::{ElementIndex, VertexId, FaceId, CornerId},
+    editable::make_corners_opposite
+};
+
+impl<TScalar: RealNumber, TIndex: ElementIndex> CornerTable<TScalar, TIndex> {
+    ///
+    /// Creates new corner table from selected faces. Faces of new mesh follow order of selection.
+    /// Returns new mesh and vertex map that contains index of original vertex for every vertex of new mesh.
+    /// Vertices where selection is connected only through vertex are duplicated, duplicates map to same original vertex.
+    /// Attributes are not copied, vertex map can be used to transfer them.
+    ///
+    pub fn extract_faces(&self, selection: &[FaceId<TIndex>]) -> (Self, Vec<VertexId<TIndex>>) {
+        let mut new_vertex_index = HashMap::new();
+        let mut vertex_map = Vec::new();
+        let mut positions = Vec::new();
+        let mut indices = Vec::with_capacity(selection.len() * 3);
+
+        for face in selection {
+            let (v1, v2, v3) = self.face_vertices(face);
+
+            for vertex in [v1, v2, v3] {
+                let index = *new_vertex_index.entry(vertex).or_insert_with(|| {
+                    vertex_map.push(vertex);
+                    positions.push(*self.vertex_position(&vertex));
+                    return vertex_map.len() - 1;
+                });
+
+                indices.push(index);
+            }
+        }
+
+        let (submesh, duplicates) = Self::from_vertices_and_indices_with_duplicates(&positions, &indices);
+
+        for (original, _) in duplicates {
+            vertex_map.push(vertex_map[original]);
+        }
+
+        return (submesh, vertex_map);
+    }
+
+    ///
+    /// Appends faces and vertices of other mesh. Deleted elements of other mesh are skipped.
+    /// When `weld` is `true`, boundary vertices of other mesh that coincide with boundary vertices of this mesh are merged
+    /// and boundary edges between merged vertices are connected, so patch extracted by [CornerTable::extract_faces]
+    /// is stitched back into its hole. Vertices where welding would join separate fans of faces are duplicated.
+    /// Returns index of new vertex for every vertex of other mesh ([VertexId::INVALID] for deleted ones).
+    /// Attributes of appended elements are zero.
+    ///
+    pub fn append(&mut self, other: &Self, weld: bool) -> Vec<VertexId<TIndex>> {
+        // Boundary edges of this mesh given by start and end vertices
+        let mut boundary_edges = HashMap::new();
+        let mut boundary_vertices = PointIndexMap::<3, TScalar>::new();
+
+        if weld {
+            for (corner, start, end) in boundary_edges_of(self) {
+                boundary_edges.insert((start, end), corner);
+                boundary_vertices.insert(*self.vertices[start].get_position(), start.index());
+            }
+        }
+
+        let mut vertex_map = vec![VertexId::INVALID; other.vertices.len()];
+        let mut welded_vertices = HashMap::new();
+
+        for (corner, start, _) in boundary_edges_of(other).filter(|_| weld) {
+            let position = other.vertices[start].get_position();
+
+            if let Some(vertex) = boundary_vertices.get_index(*position) {
+                vertex_map[start.index()] = VertexId::new(*vertex);
+                welded_vertices.entry(VertexId::new(*vertex)).or_insert_with(Vec::new).push(corner);
+            }
+        }
+
+        let first_new_vertex = self.vertices.len();
+
+        for vertex in other.vertices() {
+            if !vertex_map[vertex.index()].is_valid() {
+                vertex_map[vertex.index()] = self.add_vertex(*other.vertex_position(&vertex));
+            }
+        }
+
+        // Original fans of welded vertices
+        let mut welded_corners: Vec<_> = welded_vertices.into_iter()
+            .map(|(vertex, corners)| (vertex, collect_corners_around_vertex(self, vertex), corners))
+            .collect();
+
+        // Copy faces
+        let mut corner_map = vec![CornerId::INVALID; other.corners.len()];
+
+        for face in other.faces() {
+            let (v1, v2, v3) = other.face_vertices(&face);
+            let new_face = self.create_face_from_vertices(vertex_map[v1.index()], vertex_map[v2.index()], vertex_map[v3.index()]);
+
+            for (corner, new_corner) in face_corners(face).into_iter().zip(face_corners(new_face)) {
+                corner_map[corner.index()] = new_corner;
+            }
+        }
+
+        for face in other.faces() {
+            for corner in face_corners(face) {
+                let new_corner = corner_map[corner.index()];
+                let new_vertex = self.corners[new_corner].get_vertex_index();
+
+                if new_vertex.index() >= first_new_vertex {
+                    self.vertices[new_vertex].set_corner_index(new_corner);
+                }
+
+                match other.corners[corner].get_opposite_corner_index() {
+                    Some(opposite) => {
+                        self.corners[new_corner].set_opposite_corner_index(Some(corner_map[opposite.index()]));
+                    },
+                    None => {
+                        // Connect to boundary edge of this mesh in opposite direction
+                        let start = self.corners[next(new_corner)].get_vertex_index();
+                        let end = self.corners[previous(new_corner)].get_vertex_index();
+
+                        if let Some(opposite) = boundary_edges.remove(&(end, start)) {
+                            make_corners_opposite(self, Some(opposite), Some(new_corner));
+                        }
+                    },
+                }
+            }
+        }
+
+        // Corner of appended face at welded vertex represents its fan. Boundary edge starts at vertex of next corner.
+        for (_, fan, corners) in &mut welded_corners {
+            fan.extend(corners.iter().map(|corner| next(corner_map[corner.index()])));
+        }
+
+        for (vertex, fans, _) in welded_corners {
+            self.split_disconnected_fans(vertex, fans);
+        }
+
+        return vertex_map;
+    }
+
+    ///
+    /// Moves fans of vertex that are not connected to fan of its corner to duplicates of vertex.
+    /// `corners` should contain at least one corner of every fan.
+    ///
+    fn split_disconnected_fans(&mut self, vertex: VertexId<TIndex>, mut corners: Vec<CornerId<TIndex>>) {
+        let fan = collect_corners_around_vertex(self, vertex);
+        corners.retain(|corner| !fan.contains(corner));
+
+        while let Some(corner) = corners.first() {
+            let duplicate = self.add_vertex(*self.vertices[vertex].get_position());
+            self.vertices[duplicate].set_corner_index(*corner);
+
+            let fan = collect_corners_around_vertex(self, duplicate);
+
+            for corner in &fan {
+                self.corners[*corner].set_vertex_index(duplicate);
+            }
+
+            corners.retain(|corner| !fan.contains(corner));
+        }
+    }
+}
+
+/// Iterates over live corners opposite to boundary edges, returns corner with start and end vertices of edge
+fn boundary_edges_of<TScalar: RealNumber, TIndex: ElementIndex>(mesh: &CornerTable<TScalar, TIndex>) -> impl Iterator<Item = (CornerId<TIndex>, VertexId<TIndex>, VertexId<TIndex>)> + '_ {
+    return mesh.corners.iter()
+        .enumerate()
+        .map(|(index, corner)| (CornerId::new(index), corner))
+        .filter(|(corner, _)| !mesh.corners[first_corner(face(*corner))].is_deleted())
+        .filter(|(_, corner)| corner.get_opposite_corner_index().is_none())
+        .map(|(corner, _)| (
+            corner,
+            mesh.corners[next(corner)].get_vertex_index(),
+            mesh.corners[previous(corner)].get_vertex_index()
+        ));
+}
+
+#[cfg(test)]
+mod tests {
+    use nalgebra::Point3;
+
+    use crate::mesh::{corner_table::{prelude::CornerTableF, test_helpers::create_uv_sphere_mesh}, traits::{Mesh, TopologicalMesh}};
+
+    fn boundary_edges_count(mesh: &CornerTableF) -> usize {
+        return mesh.edges().filter(|edge| mesh.is_edge_on_boundary(edge)).count();
+    }
+
+    #[test]
+    fn should_extract_faces() {
+        let mesh = create_uv_sphere_mesh(6, 8);
+        let selection: Vec<_> = mesh.faces().take(8).collect();
+        let (submesh, vertex_map) = mesh.extract_faces(&selection);
+
+        assert_eq!(submesh.faces().count(), 8);
+        assert_eq!(submesh.vertices().count(), vertex_map.len());
+        assert_eq!(submesh.validate(), vec![]);
+
+        for (face, original) in submesh.faces().zip(&selection) {
+            let (v1, v2, v3) = submesh.face_vertices(&face);
+            let mapped = (vertex_map[v1.index()], vertex_map[v2.index()], vertex_map[v3.index()]);
+            assert_eq!(mapped, mesh.face_vertices(original));
+        }
+    }
+
+    #[test]
+    fn should_stitch_patch_back() {
+        let mesh = create_uv_sphere_mesh(6, 8);
+        let (cap, rest): (Vec<_>, Vec<_>) = mesh.faces().partition(|face| mesh.face_positions(face).center().z > 0.5);
+
+        let (mut hole, _) = mesh.extract_faces(&rest);
+        let (patch, _) = mesh.extract_faces(&cap);
+        assert!(boundary_edges_count(&hole) > 0);
+
+        let vertex_map = hole.append(&patch, true);
+
+        assert_eq!(vertex_map.len(), patch.vertices().count());
+        assert_eq!(hole.validate(), vec![]);
+        assert_eq!(hole.faces().count(), mesh.faces().count());
+        assert_eq!(hole.vertices().count(), mesh.vertices().count());
+        assert_eq!(boundary_edges_count(&hole), 0);
+    }
+
+    #[test]
+    fn should_append_without_welding() {
+        let mesh = create_uv_sphere_mesh(6, 8);
+        let (cap, rest): (Vec<_>, Vec<_>) = mesh.faces().partition(|face| mesh.face_positions(face).center().z > 0.5);
+
+        let (mut hole, _) = mesh.extract_faces(&rest);
+        let (patch, _) = mesh.extract_faces(&cap);
+        let hole_boundary = boundary_edges_count(&hole);
+        let vertices_count = hole.vertices().count();
+
+        hole.append(&patch, false);
+
+        assert_eq!(hole.validate(), vec![]);
+        assert_eq!(hole.vertices().count(), vertices_count + patch.vertices().count());
+        assert_eq!(boundary_edges_count(&hole), hole_boundary * 2);
+    }
+
+    #[test]
+    fn should_not_weld_vertex_shared_by_separate_fans() {
+        let mut mesh = CornerTableF::from_vertices_and_indices(&[
+            Point3::new(0.0, 0.0, 0.0),
+            Point3::new(1.0, 0.0, 0.0),
+            Point3::new(0.0, 1.0, 0.0)
+        ], &[0, 1, 2]);
+
+        let other = CornerTableF::from_vertices_and_indices(&[
+            Point3::new(0.0, 0.0, 0.0),
+            Point3::new(-1.0, 0.0, 0.0),
+            Point3::new(0.0, -1.0, 0.0)
+        ], &[0, 1, 2]);
+
+        mesh.append(&other, true);
+
+        assert_eq!(mesh.validate(), vec![]);
+        assert_eq!(mesh.vertices().count(), 6);
+        assert_eq!(boundary_edges_count(&mesh), 6);
+    }
+}
@@ -1,8 +1,14 @@
 use std::{collections::HashMap, fmt::Display};
 use nalgebra::{Point3, Vector3};
 use tabled::Table;
+#[cfg(feature = "serde")]
+use serde::{Serialize, Deserialize};
+#[cfg(feature = "rayon")]
+use rayon::iter::{IntoParallelIterator, IntoParallelRefIterator, IndexedParallelIterator, ParallelIterator};
 use crate::{mesh::traits::{Mesh, TopologicalMesh, MeshMarker}, geometry::traits::RealNumber};
 use self::helpers::Edge;
+#[cfg(feature = "rayon")]
+use super::connectivity::traits::Flags;
 use super::{
     traversal::{
         CornerTableFacesIter, 
@@ -14,71 +20,77 @@ use super::{
         edges_around_vertex
     }, 
     connectivity::{
-        corner::{Corner, first_corner_from_corner}, 
+        corner::{Corner, first_corner, face_corners, face, next, previous}, 
         vertex::Vertex
     }, 
-    marker::CornerTableMarker, descriptors::EdgeRef
+    marker::CornerTableMarker, descriptors::{EdgeRef, ElementIndex, VertexId, FaceId, CornerId},
+    attributes::Attributes
 };
 
-pub struct CornerTable<TScalar: RealNumber> {
-    pub(super) vertices: Vec<Vertex<TScalar>>,
-    pub(super) corners: Vec<Corner>
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct CornerTable<TScalar: RealNumber, TIndex: ElementIndex = usize> {
+    pub(super) vertices: Vec<Vertex<TScalar, TIndex>>,
+    pub(super) corners: Vec<Corner<TIndex>>,
+    #[cfg_attr(feature = "serde", serde(default))]
+    pub(super) attributes: Attributes<TScalar>
 }
 
-impl<TScalar: RealNumber> Default for CornerTable<TScalar> {
+impl<TScalar: RealNumber, TIndex: ElementIndex> Default for CornerTable<TScalar, TIndex> {
     fn default() -> Self {
         return Self { 
             vertices: Vec::new(), 
-            corners: Vec::new() 
+            corners: Vec::new(),
+            attributes: Attributes::default()
         };
     }
 }
 
-impl<TScalar: RealNumber> CornerTable<TScalar> {
+impl<TScalar: RealNumber, TIndex: ElementIndex> CornerTable<TScalar, TIndex> {
     #[inline]
     pub fn new() -> Self {
         return Default::default();
     }
 
     #[inline]
-    pub fn get_vertex(&self, vertex_index:  usize) -> Option<&Vertex<TScalar>> {
-        return self.vertices.get(vertex_index);
+    pub fn get_vertex(&self, vertex_index: VertexId<TIndex>) -> Option<&Vertex<TScalar, TIndex>> {
+        return self.vertices.get(vertex_index.index());
     }
 
     #[inline]
-    pub fn get_vertex_mut(&mut self, vertex_index:  usize) -> Option<&mut Vertex<TScalar>> {
-        return self.vertices.get_mut(vertex_index);
+    pub fn get_vertex_mut(&mut self, vertex_index: VertexId<TIndex>) -> Option<&mut Vertex<TScalar, TIndex>> {
+        return self.vertices.get_mut(vertex_index.index());
     }
 
     #[inline]
-    pub fn get_corner(&self, corner_index:  usize) -> Option<&Corner> {
-        return self.corners.get(corner_index);
+    pub fn get_corner(&self, corner_index: CornerId<TIndex>) -> Option<&Corner<TIndex>> {
+        return self.corners.get(corner_index.index());
     }
 
     #[inline]
-    pub fn get_corner_mut(&mut self, corner_index:  usize) -> Option<&mut Corner> {
-        return self.corners.get_mut(corner_index);
+    pub fn get_corner_mut(&mut self, corner_index: CornerId<TIndex>) -> Option<&mut Corner<TIndex>> {
+        return self.corners.get_mut(corner_index.index());
     }
 
     /// Create new isolated corner
     #[inline]
-    pub fn create_corner(&mut self) -> &mut Corner {
+    pub fn create_corner(&mut self) -> &mut Corner<TIndex> {
         let idx = self.corners.len();
         self.corners.push(Corner::default());
+        self.attributes.push_corner();
         return self.corners.get_mut(idx).unwrap();
     }
 
     /// Create new isolated vertex
     #[inline]
-    pub fn create_vertex(&mut self) -> &mut Vertex<TScalar> {
+    pub fn create_vertex(&mut self) -> &mut Vertex<TScalar, TIndex> {
         let idx = self.vertices.len();
         self.vertices.push(Default::default());
+        self.attributes.push_vertex();
         return self.vertices.get_mut(idx).unwrap();
     }
 
     /// Creates isolated face from existing vertices vertices
-    /// Returns first corner of face
-    pub fn create_face_from_vertices(&mut self, v1: usize, v2: usize, v3: usize) -> usize {
+    pub fn create_face_from_vertices(&mut self, v1: VertexId<TIndex>, v2: VertexId<TIndex>, v3: VertexId<TIndex>) -> FaceId<TIndex> {
         let c1 = self.create_corner();
         c1.set_vertex_index(v1);
 
@@ -88,47 +100,133 @@ impl<TScalar: RealNumber> CornerTable<TScalar> {
         let c3 = self.create_corner();
         c3.set_vertex_index(v3);
 
-        return self.corners.len() - 3;
+        return face(CornerId::new(self.corners.len() - 3));
     }
 
     /// Makes give corners opposite to each other
     #[inline]
-    pub fn set_opposite_relationship(&mut self, corner1_index: usize, corner2_index: usize) {
+    pub fn set_opposite_relationship(&mut self, corner1_index: CornerId<TIndex>, corner2_index: CornerId<TIndex>) {
         self.get_corner_mut(corner1_index).unwrap().set_opposite_corner_index(Some(corner2_index));
         self.get_corner_mut(corner2_index).unwrap().set_opposite_corner_index(Some(corner1_index));
     }
 
-    fn corner_from(
-        &mut self,
-        edge_opposite_corner_map: &mut HashMap<Edge, usize>,
-        mut edge: Edge,
-        vertex_index: usize
-    ) {
-        let corner_index = self.corners.len();
-        let corner = self.create_corner();
-        corner.set_vertex_index(vertex_index);
+    ///
+    /// Creates corner table from vertices and triangle indices keeping all input faces.
+    /// Edges shared by more than two faces (or by faces with inconsistent orientation) become boundary edges,
+    /// vertices where several fans of faces meet are duplicated, one copy per fan.
+    /// Returns corner table and `(original, duplicate)` pairs of vertex indices. Duplicates are appended after input vertices.
+    /// Empty `faces` is valid input and gives mesh without faces.
+    ///
+    #[allow(clippy::manual_is_multiple_of)] // is_multiple_of requires Rust 1.87
+    pub fn from_vertices_and_indices_with_duplicates(vertices: &[Point3<TScalar>], faces: &[usize]) -> (Self, Vec<(usize, usize)>) {
+        assert!(faces.len() % 3 == 0, "Invalid number of face indices: {}", faces.len());
 
-        // Find opposite corner
-        edge.flip();
-        let opposite_corner_index = edge_opposite_corner_map.get(&edge);
-        edge.flip();
+        assert!(
+            vertices.len() < TIndex::INVALID.to_usize() && faces.len() < TIndex::INVALID.to_usize(),
+            "Mesh is too large for index type"
+        );
 
-        if let Some(opposite_corner_index) = opposite_corner_index {
-            // Set opposite for corners
-            corner.set_opposite_corner_index(Some(*opposite_corner_index));
-            let opposite_corner = &mut self.corners[*opposite_corner_index];
+        let mut corner_table = Self::new();
 
-            opposite_corner.set_opposite_corner_index(Some(corner_index));
-    
-            edge_opposite_corner_map.insert(edge, corner_index);
-        } else {
-            // Save directed edge and it`s opposite corner
-            edge_opposite_corner_map.insert(edge, corner_index);
+        for position in vertices {
+            let vertex = corner_table.create_vertex();
+            vertex.set_position(*position);
+        }
+
+        // Directed edge opposite to corner and number of corners it is opposite to
+        let mut edge_opposite_corner_map = HashMap::<Edge, (usize, usize)>::new();
+
+        for face_idx in (0..faces.len()).step_by(3) {
+            let v1_index = faces[face_idx];
+            let v2_index = faces[face_idx + 1];
+            let v3_index = faces[face_idx + 2];
+
+            let edges = [
+                (Edge::new(v2_index, v3_index), v1_index),
+                (Edge::new(v3_index, v1_index), v2_index),
+                (Edge::new(v1_index, v2_index), v3_index)
+            ];
+
+            for (edge, vertex_index) in edges {
+                let corner_index = corner_table.corners.len();
+                corner_table.create_corner().set_vertex_index(VertexId::new(vertex_index));
+
+                let opposite = edge_opposite_corner_map.entry(edge).or_insert((corner_index, 0));
+                opposite.1 += 1;
+            }
+        }
+
+        // Only edges shared by exactly two consistently oriented faces are connected
+        for (edge, (corner_index, count)) in &edge_opposite_corner_map {
+            let mut flipped = *edge;
+            flipped.flip();
+
+            if let Some((opposite_corner_index, opposite_count)) = edge_opposite_corner_map.get(&flipped) {
+                if *count == 1 && *opposite_count == 1 {
+                    corner_table.corners[*corner_index].set_opposite_corner_index(Some(CornerId::new(*opposite_corner_index)));
+                }
+            }
+        }
+
+        let duplicates = corner_table.split_non_manifold_vertices();
+
+        return (corner_table, duplicates);
+    }
+
+    ///
+    /// Assigns separate vertex to every fan of faces around vertex.
+    /// First fan (in corners order) keeps original vertex, returns `(original, duplicate)` pairs for others.
+    ///
+    fn split_non_manifold_vertices(&mut self) -> Vec<(usize, usize)> {
+        let mut duplicates = Vec::new();
+        let mut visited = vec![false; self.corners.len()];
+        let mut vertex_has_fan = vec![false; self.vertices.len()];
+        let mut stack = Vec::new();
+
+        for start_corner in 0..self.corners.len() {
+            if visited[start_corner] {
+                continue;
+            }
+
+            let original_vertex = self.corners[start_corner].get_vertex_index();
+            let fan_vertex = if vertex_has_fan[original_vertex.index()] {
+                let duplicate = VertexId::new(self.vertices.len());
+                let position = *self.vertices[original_vertex].get_position();
+                self.create_vertex().set_position(position);
+                duplicates.push((original_vertex.index(), duplicate.index()));
+
+                duplicate
+            } else {
+                vertex_has_fan[original_vertex.index()] = true;
+                original_vertex
+            };
+
+            // Collect corners of fan by walking over faces adjacent by edges incident to vertex
+            let mut last_corner = CornerId::new(start_corner);
+            visited[start_corner] = true;
+            stack.push(last_corner);
+
+            while let Some(corner_index) = stack.pop() {
+                self.corners[corner_index].set_vertex_index(fan_vertex);
+                last_corner = last_corner.max(corner_index);
+
+                let neighbors = [
+                    self.corners[next(corner_index)].get_opposite_corner_index().map(next),
+                    self.corners[previous(corner_index)].get_opposite_corner_index().map(previous)
+                ];
+
+                for neighbor in neighbors.into_iter().flatten() {
+                    if !visited[neighbor.index()] {
+                        visited[neighbor.index()] = true;
+                        stack.push(neighbor);
+                    }
+                }
+            }
+
+            self.vertices[fan_vertex].set_corner_index(last_corner);
         }
 
-        // Set corner index for vertex
-        let vertex = &mut self.vertices[vertex_index];
-        vertex.set_corner_index(corner_index);
+        return duplicates;
     }
 }
 
@@ -137,57 +235,30 @@ impl<TScalar: RealNumber> CornerTable<TScalar> {
 /// 
 /// Edge is represented by index of opposite corner.
 /// Vertex is represented by it`s index in vertices vector.
-/// Face is represented by index of one of it`s.
+/// Face is represented by it`s index, corners of face are stored next to each other.
+/// Note that face descriptor is not index of first corner of face anymore, see [FaceId].
 /// 
-impl<TScalar: RealNumber> Mesh for CornerTable<TScalar> {
+impl<TScalar: RealNumber, TIndex: ElementIndex> Mesh for CornerTable<TScalar, TIndex> {
     type ScalarType = TScalar;
 
     /// Index of corner opposite to edge
-    type EdgeDescriptor = EdgeRef;
+    type EdgeDescriptor = EdgeRef<TIndex>;
     /// Vertex index
-    type VertexDescriptor = usize;
-    /// Index of one of face corners
-    type FaceDescriptor = usize;
+    type VertexDescriptor = VertexId<TIndex>;
+    /// Face index
+    type FaceDescriptor = FaceId<TIndex>;
 
-    type FacesIter<'iter> = CornerTableFacesIter<'iter, TScalar>;
-    type VerticesIter<'iter> = CornerTableVerticesIter<'iter, TScalar>;
-    type EdgesIter<'iter> = CornerTableEdgesIter<'iter, TScalar>;
+    type FacesIter<'iter> = CornerTableFacesIter<'iter, TScalar, TIndex>;
+    type VerticesIter<'iter> = CornerTableVerticesIter<'iter, TScalar, TIndex>;
+    type EdgesIter<'iter> = CornerTableEdgesIter<'iter, TScalar, TIndex>;
 
     fn from_vertices_and_indices(vertices: &[Point3<Self::ScalarType>], faces: &[usize]) -> Self {
-        assert!(faces.len() % 3 == 0, "Invalid number of face indices: {}", faces.len());
-
-        let mut edge_opposite_corner_map = HashMap::<helpers::Edge, usize>::new();
-        let mut corner_table = Self::new();
-
-        for vertex_index in 0..vertices.len() {
-            let v_position = vertices.get(vertex_index).unwrap();
-            let vertex = corner_table.create_vertex();
-            vertex.set_position(*v_position);
-        }
-
-        for face_idx in (0..faces.len() - 1).step_by(3) {
-            let v1_index = faces[face_idx];
-            let v2_index = faces[face_idx + 1];
-            let v3_index = faces[face_idx + 2];
-
-            let edge1 = Edge::new(v2_index, v3_index);
-            let edge2 = Edge::new(v3_index, v1_index);
-            let edge3 = Edge::new(v1_index, v2_index);
-
-            // If edge already exist in map then it is non manifold. For now we will skip faces that introduces non-manifoldness.
-            if edge_opposite_corner_map.contains_key(&edge1) ||
-               edge_opposite_corner_map.contains_key(&edge2) ||
-               edge_opposite_corner_map.contains_key(&edge3) 
-            {
-                continue;        
-            }
-
-            corner_table.corner_from(&mut edge_opposite_corner_map, edge1, v1_index);
-            corner_table.corner_from(&mut edge_opposite_corner_map, edge2, v2_index);
-            corner_table.corner_from(&mut edge_opposite_corner_map, edge3, v3_index);
-        }
+        return Self::from_vertices_and_indices_with_duplicates(vertices, faces).0;
+    }
 
-        return corner_table;
+    #[inline]
+    fn from_vertices_and_indices_with_duplicates(vertices: &[Point3<Self::ScalarType>], faces: &[usize]) -> (Self, Vec<(usize, usize)>) {
+        return CornerTable::from_vertices_and_indices_with_duplicates(vertices, faces);
     }
 
     #[inline]
@@ -205,9 +276,26 @@ impl<TScalar: RealNumber> Mesh for CornerTable<TScalar> {
         return Self::EdgesIter::new(self);
     }
 
+    #[cfg(feature = "rayon")]
+    #[inline]
+    fn par_faces(&self) -> impl ParallelIterator<Item = Self::FaceDescriptor> + '_ {
+        return (0..self.corners.len() / 3).into_par_iter()
+            .map(FaceId::new)
+            .filter(|&face| !self.corners[first_corner(face)].is_deleted());
+    }
+
+    #[cfg(feature = "rayon")]
+    #[inline]
+    fn par_vertices(&self) -> impl ParallelIterator<Item = Self::VertexDescriptor> + '_ {
+        return self.vertices.par_iter()
+            .enumerate()
+            .filter(|(_, vertex)| !vertex.is_deleted())
+            .map(|(index, _)| VertexId::new(index));
+    }
+
     #[inline]
     fn face_vertices(&self, face: &Self::FaceDescriptor) -> (Self::VertexDescriptor, Self::VertexDescriptor, Self::VertexDescriptor) {
-        let mut walker = CornerWalker::from_corner(self, *face);
+        let mut walker = CornerWalker::from_corner(self, first_corner(*face));
 
         return (
             walker.get_corner().get_vertex_index(),
@@ -252,10 +340,15 @@ impl<TScalar: RealNumber> Mesh for CornerTable<TScalar> {
 
         return Some(sum.normalize());
     }
+
+    fn assert_valid(&self) {
+        let violations = self.validate();
+        assert!(violations.is_empty(), "Corner table is corrupted: {:?}", violations);
+    }
 }
 
-impl<TScalar: RealNumber> TopologicalMesh for CornerTable<TScalar> {
-    type Position<'a> = CornerWalker<'a, TScalar>;
+impl<TScalar: RealNumber, TIndex: ElementIndex> TopologicalMesh for CornerTable<TScalar, TIndex> {
+    type Position<'a> = CornerWalker<'a, TScalar, TIndex>;
     
     #[inline]
     fn vertices_around_vertex<TVisit: FnMut(&Self::VertexDescriptor)>(&self, vertex: &Self::VertexDescriptor, visit: TVisit) {
@@ -299,26 +392,26 @@ impl<TScalar: RealNumber> TopologicalMesh for CornerTable<TScalar> {
 
     #[inline]
     fn edge_faces(&self, edge: &Self::EdgeDescriptor) -> (Self::FaceDescriptor, Option<Self::FaceDescriptor>) {
-        let f1 = edge.get_corner_index();
+        let corner = edge.get_corner_index();
         return (
-            f1,
-            self.corners[f1].get_opposite_corner_index()
+            face(corner),
+            self.corners[corner].get_opposite_corner_index().map(face)
         );
     }
 
     #[inline]
     fn face_edges(&self, face: &Self::FaceDescriptor) -> (Self::EdgeDescriptor, Self::EdgeDescriptor, Self::EdgeDescriptor) {
-        let first_corner = first_corner_from_corner(*face);
+        let [c1, c2, c3] = face_corners(*face);
         return (
-            Self::EdgeDescriptor::new(first_corner, self),
-            Self::EdgeDescriptor::new(first_corner + 1, self),
-            Self::EdgeDescriptor::new(first_corner + 2, self)
+            Self::EdgeDescriptor::new(c1, self),
+            Self::EdgeDescriptor::new(c2, self),
+            Self::EdgeDescriptor::new(c3, self)
         );
     }
 }
 
-impl<TScalar: RealNumber> MeshMarker for CornerTable<TScalar> {
-    type Marker = CornerTableMarker<TScalar>;
+impl<TScalar: RealNumber, TIndex: ElementIndex> MeshMarker for CornerTable<TScalar, TIndex> {
+    type Marker = CornerTableMarker<TScalar, TIndex>;
 
     #[inline]
     fn marker(&self) -> Self::Marker {
@@ -326,7 +419,7 @@ impl<TScalar: RealNumber> MeshMarker for CornerTable<TScalar> {
     }
 }
 
-impl<TScalar: RealNumber> Display for CornerTable<TScalar> {
+impl<TScalar: RealNumber, TIndex: ElementIndex> Display for CornerTable<TScalar, TIndex> {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         let vertices = Table::new(self.vertices.iter());
         let corners = Table::new(self.corners.iter());
@@ -371,11 +464,12 @@ mod tests {
 
     use crate::mesh::{
         corner_table::{
-            test_helpers::{create_unit_square_mesh, assert_mesh_eq}, 
+            test_helpers::{create_unit_square_mesh, assert_mesh_eq, create_uv_sphere_mesh}, 
             connectivity::{vertex::VertexF, corner::Corner}, 
-            prelude::CornerTableF
+            descriptors::{VertexId, FaceId, CornerId, EdgeRef},
+            prelude::{CornerTableF, CompactCornerTableF}
         }, 
-        traits::Mesh
+        traits::{Mesh, EditableMesh}
     };
 
     #[test]
@@ -383,35 +477,48 @@ mod tests {
         let mesh = create_unit_square_mesh();
 
         let expected_vertices = vec![
-            VertexF::new(5, Point3::<f32>::new(0.0, 1.0, 0.0), Default::default()),
-            VertexF::new(1, Point3::<f32>::new(0.0, 0.0, 0.0), Default::default()),
-            VertexF::new(3, Point3::<f32>::new(1.0, 0.0, 0.0), Default::default()),
-            VertexF::new(4, Point3::<f32>::new(1.0, 1.0, 0.0), Default::default())
+            VertexF::new(CornerId::new(5), Point3::<f32>::new(0.0, 1.0, 0.0), Default::default()),
+            VertexF::new(CornerId::new(1), Point3::<f32>::new(0.0, 0.0, 0.0), Default::default()),
+            VertexF::new(CornerId::new(3), Point3::<f32>::new(1.0, 0.0, 0.0), Default::default()),
+            VertexF::new(CornerId::new(4), Point3::<f32>::new(1.0, 1.0, 0.0), Default::default())
         ];
 
         let expected_corners = vec![
-            Corner::new(None,    0, Default::default()),
-            Corner::new(Some(4), 1, Default::default()),
-            Corner::new(None,    2, Default::default()),
+            Corner::new(None,    VertexId::new(0), Default::default()),
+            Corner::new(Some(CornerId::new(4)), VertexId::new(1), Default::default()),
+            Corner::new(None,    VertexId::new(2), Default::default()),
 
-            Corner::new(None,    2, Default::default()),
-            Corner::new(Some(1), 3, Default::default()),
-            Corner::new(None,    0, Default::default())
+            Corner::new(None,    VertexId::new(2), Default::default()),
+            Corner::new(Some(CornerId::new(1)), VertexId::new(3), Default::default()),
+            Corner::new(None,    VertexId::new(0), Default::default())
         ];
 
         assert_mesh_eq(&mesh, &expected_corners, &expected_vertices);
     }
 
     #[test]
-    fn should_remove_face_that_introduces_non_manifold_edge() {
-        let mesh = CornerTableF::from_vertices_and_indices(&[
+    fn from_vertices_and_empty_indices() {
+        let vertices = [Point3::new(0.0, 0.0, 0.0), Point3::new(1.0, 0.0, 0.0)];
+
+        let mesh = CornerTableF::from_vertices_and_indices(&vertices, &[]);
+        assert_eq!(mesh.faces().count(), 0);
+        assert_eq!(mesh.vertices.len(), 2);
+
+        let mesh = CornerTableF::from_vertices_and_indices(&[], &[]);
+        assert_eq!(mesh.vertices().count(), 0);
+    }
+
+    #[test]
+    fn should_duplicate_vertices_of_non_manifold_edge() {
+        let vertices = [
             Point3::new(0.0, 1.0, 0.0),
             Point3::new(0.0, 0.0, 0.0),
             Point3::new(1.0, 0.0, 0.0),
             Point3::new(-1.0, 0.0, 0.0),
             Point3::new(0.0, 0.0, -1.0),
             Point3::new(0.0, 0.0, -1.0),
-        ], &[
+        ];
+        let (mesh, duplicates) = CornerTableF::from_vertices_and_indices_with_duplicates(&vertices, &[
             0, 1, 2,
             0, 1, 4,
             0, 3, 1,
@@ -419,6 +526,93 @@ mod tests {
             1, 5, 2,
         ]);
 
-        assert!(mesh.faces().count() == 4);
+        // Edge (0, 1) is shared by three faces, so each of them forms separate fan at vertex 0.
+        // At vertex 1 other faces are connected through remaining edges, only second face is detached.
+        assert_eq!(mesh.faces().count(), 5);
+        assert_eq!(duplicates, vec![(0, 6), (1, 7), (0, 8)]);
+        assert_eq!(mesh.face_vertices(&FaceId::new(1)), (VertexId::new(6), VertexId::new(7), VertexId::new(4)));
+        assert_eq!(mesh.face_vertices(&FaceId::new(2)), (VertexId::new(8), VertexId::new(3), VertexId::new(1)));
+        assert_eq!(mesh.vertex_position(&VertexId::new(7)), &vertices[1]);
+
+        for (index, corner) in mesh.corners.iter().enumerate() {
+            if let Some(opposite) = corner.get_opposite_corner_index() {
+                assert_eq!(mesh.corners[opposite].get_opposite_corner_index(), Some(CornerId::new(index)));
+            }
+        }
+
+        for vertex in mesh.vertices() {
+            let corner = mesh.get_vertex(vertex).unwrap().get_corner_index();
+            assert_eq!(mesh.corners[corner].get_vertex_index(), vertex);
+        }
+    }
+
+    #[test]
+    fn should_duplicate_vertex_shared_by_two_fans() {
+        // Two triangles touching at single vertex
+        let (mesh, duplicates) = CornerTableF::from_vertices_and_indices_with_duplicates(&[
+            Point3::new(0.0, 0.0, 0.0),
+            Point3::new(1.0, 0.0, 0.0),
+            Point3::new(0.0, 1.0, 0.0),
+            Point3::new(-1.0, 0.0, 0.0),
+            Point3::new(0.0, -1.0, 0.0),
+        ], &[
+            0, 1, 2,
+            0, 3, 4,
+        ]);
+
+        assert_eq!(duplicates, vec![(0, 5)]);
+        assert_eq!(mesh.face_vertices(&FaceId::new(0)), (VertexId::new(0), VertexId::new(1), VertexId::new(2)));
+        assert_eq!(mesh.face_vertices(&FaceId::new(1)), (VertexId::new(5), VertexId::new(3), VertexId::new(4)));
+    }
+
+    #[test]
+    fn should_match_with_32_bit_indices() {
+        let mesh = create_uv_sphere_mesh(6, 8);
+        let vertices: Vec<_> = mesh.vertices().map(|vertex| *mesh.vertex_position(&vertex)).collect();
+        let indices: Vec<_> = mesh.faces()
+            .flat_map(|face| {
+                let (v1, v2, v3) = mesh.face_vertices(&face);
+                return [v1.index(), v2.index(), v3.index()];
+            })
+            .collect();
+
+        let mut compact = CompactCornerTableF::from_vertices_and_indices(&vertices, &indices);
+        let mut mesh = mesh;
+        assert_eq!(compact.validate(), vec![]);
+        assert!(std::mem::size_of::<Corner<u32>>() < std::mem::size_of::<Corner>());
+
+        let edge = EdgeRef::new(CornerId::new(7), &mesh);
+        let compact_edge = EdgeRef::new(CornerId::new(7), &compact);
+        mesh.split_edge(&edge, &Point3::new(0.0, 0.0, 0.0));
+        compact.split_edge(&compact_edge, &Point3::new(0.0, 0.0, 0.0));
+        assert_eq!(compact.validate(), vec![]);
+
+        let faces: Vec<_> = mesh.faces().map(|face| face.index()).collect();
+        let compact_faces: Vec<_> = compact.faces().map(|face| face.index()).collect();
+        assert_eq!(faces, compact_faces);
+
+        for face in compact.faces() {
+            let (v1, v2, v3) = compact.face_vertices(&face);
+            let expected = mesh.face_vertices(&FaceId::new(face.index()));
+            assert_eq!((v1.index(), v2.index(), v3.index()), (expected.0.index(), expected.1.index(), expected.2.index()));
+        }
+
+        // Index that doesn't fit into u32 is not truncated in release builds either
+        assert!(std::panic::catch_unwind(|| FaceId::<u32>::new(u32::MAX as usize + 1)).is_err());
+        assert!(!FaceId::<u32>::new(usize::MAX).is_valid());
+    }
+
+    #[cfg(feature = "rayon")]
+    #[test]
+    fn par_faces_and_vertices_should_match_sequential() {
+        use rayon::iter::ParallelIterator;
+        use crate::mesh::corner_table::{connectivity::traits::Flags, test_helpers::create_collapse_edge_sample_mesh1};
+
+        let mut mesh = create_collapse_edge_sample_mesh1();
+        mesh.get_corner_mut(CornerId::new(3)).unwrap().set_deleted(true);
+        mesh.get_vertex_mut(VertexId::new(0)).unwrap().set_deleted(true);
+
+        assert_eq!(mesh.par_faces().collect::<Vec<_>>(), mesh.faces().collect::<Vec<_>>());
+        assert_eq!(mesh.par_vertices().collect::<Vec<_>>(), mesh.vertices().collect::<Vec<_>>());
     }
 }
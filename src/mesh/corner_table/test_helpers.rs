@@ -144,7 +144,63 @@ pub fn create_flip_edge_sample_mesh() -> CornerTableF {
     return CornerTableF::from_vertices_and_indices(&vertices, &indices);
 }
 
+pub fn create_uv_sphere_mesh(rings: usize, segments: usize) -> CornerTableF {
+    let mut vertices = vec![Point3::new(0.0, 0.0, 1.0), Point3::new(0.0, 0.0, -1.0)];
+    let mut indices = Vec::new();
+
+    for ring in 1..rings {
+        let theta = std::f32::consts::PI * ring as f32 / rings as f32;
+
+        for segment in 0..segments {
+            let phi = 2.0 * std::f32::consts::PI * segment as f32 / segments as f32;
+            vertices.push(Point3::new(theta.sin() * phi.cos(), theta.sin() * phi.sin(), theta.cos()));
+        }
+    }
+
+    let vertex = |ring: usize, segment: usize| 2 + (ring - 1) * segments + segment % segments;
+
+    for segment in 0..segments {
+        indices.extend([0, vertex(1, segment), vertex(1, segment + 1)]);
+        indices.extend([1, vertex(rings - 1, segment + 1), vertex(rings - 1, segment)]);
+
+        for ring in 1..rings - 1 {
+            indices.extend([vertex(ring, segment), vertex(ring + 1, segment), vertex(ring + 1, segment + 1)]);
+            indices.extend([vertex(ring, segment), vertex(ring + 1, segment + 1), vertex(ring, segment + 1)]);
+        }
+    }
+
+    return CornerTableF::from_vertices_and_indices(&vertices, &indices);
+}
+
+pub fn create_torus_mesh(rings: usize, segments: usize) -> CornerTableF {
+    let mut vertices = Vec::new();
+    let mut indices = Vec::new();
+
+    for ring in 0..rings {
+        let theta = 2.0 * std::f32::consts::PI * ring as f32 / rings as f32;
+
+        for segment in 0..segments {
+            let phi = 2.0 * std::f32::consts::PI * segment as f32 / segments as f32;
+            let radius = 2.0 + phi.cos();
+            vertices.push(Point3::new(radius * theta.cos(), radius * theta.sin(), phi.sin()));
+        }
+    }
+
+    let vertex = |ring: usize, segment: usize| ring % rings * segments + segment % segments;
+
+    for ring in 0..rings {
+        for segment in 0..segments {
+            indices.extend([vertex(ring, segment), vertex(ring + 1, segment), vertex(ring + 1, segment + 1)]);
+            indices.extend([vertex(ring, segment), vertex(ring + 1, segment + 1), vertex(ring, segment + 1)]);
+        }
+    }
+
+    return CornerTableF::from_vertices_and_indices(&vertices, &indices);
+}
+
 pub fn assert_mesh_eq(mesh: &CornerTableF, expected_corners: &Vec<Corner>, expected_vertices: &Vec<VertexF>) {
+    assert_eq!(mesh.validate(), vec![]);
+
     // Assert equality for each element separately for readability
 
     assert_eq!(expected_vertices.len(), mesh.vertices.len());
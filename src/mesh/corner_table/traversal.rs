@@ -1,18 +1,18 @@
 use crate::{mesh::traits::{mesh_stats::MAX_VERTEX_VALENCE, Position}, geometry::traits::RealNumber};
 
-use super::{table::CornerTable, connectivity::{flags::clear_visited, vertex::Vertex, corner::{Corner, first_corner, face, next, previous, face_contains_corner}, traits::Flags}, descriptors::EdgeRef};
+use super::{table::CornerTable, connectivity::{flags::clear_visited, vertex::Vertex, corner::{Corner, first_corner, face, next, previous, face_contains_corner}, traits::Flags}, descriptors::{EdgeRef, ElementIndex, CornerId, VertexId, FaceId}};
 
 ///
 /// Can be used to traverse corner table topology
 /// 
-pub struct CornerWalker<'a, TScalar: RealNumber> {
-    table: &'a CornerTable<TScalar>,
-    corner_index: usize
+pub struct CornerWalker<'a, TScalar: RealNumber, TIndex: ElementIndex = usize> {
+    table: &'a CornerTable<TScalar, TIndex>,
+    corner_index: CornerId<TIndex>
 }
 
-impl<'a, TScalar: RealNumber> CornerWalker<'a, TScalar> {
+impl<'a, TScalar: RealNumber, TIndex: ElementIndex> CornerWalker<'a, TScalar, TIndex> {
     /// Creates walker starting at given corner
-    pub fn from_corner(table: &'a CornerTable<TScalar>, corner_index: usize) -> Self { 
+    pub fn from_corner(table: &'a CornerTable<TScalar, TIndex>, corner_index: CornerId<TIndex>) -> Self { 
         return Self {
             table, 
             corner_index
@@ -20,7 +20,7 @@ impl<'a, TScalar: RealNumber> CornerWalker<'a, TScalar> {
     }
 
     /// Creates walker starting at random corner of given vertex
-    pub fn from_vertex(table: &'a CornerTable<TScalar>, vertex_index: usize) -> Self { 
+    pub fn from_vertex(table: &'a CornerTable<TScalar, TIndex>, vertex_index: VertexId<TIndex>) -> Self { 
         return Self {
             table, 
             corner_index: table.get_vertex(vertex_index).unwrap().get_corner_index()
@@ -29,7 +29,7 @@ impl<'a, TScalar: RealNumber> CornerWalker<'a, TScalar> {
 
     /// Jumps to given corner
     #[inline]
-    pub fn set_current_corner(&mut self, corner_index: usize) -> &mut Self {
+    pub fn set_current_corner(&mut self, corner_index: CornerId<TIndex>) -> &mut Self {
         self.corner_index = corner_index;
         return self;
     }
@@ -136,31 +136,31 @@ impl<'a, TScalar: RealNumber> CornerWalker<'a, TScalar> {
 
     /// Returns next corner
     #[inline]
-    pub fn get_next_corner(&self) -> &Corner {
+    pub fn get_next_corner(&self) -> &Corner<TIndex> {
         return self.table.get_corner(next(self.get_corner_index())).unwrap(); 
     }
 
     /// Returns next corner index
     #[inline]
-    pub fn get_next_corner_index(&self) -> usize {
+    pub fn get_next_corner_index(&self) -> CornerId<TIndex> {
         return next(self.get_corner_index()); 
     }
 
     /// Returns previous corner index
     #[inline]
-    pub fn get_previous_corner_index(&self) -> usize {
+    pub fn get_previous_corner_index(&self) -> CornerId<TIndex> {
         return previous(self.get_corner_index()); 
     }
 
     /// Returns previous corner
     #[inline]
-    pub fn get_previous_corner(&self) -> &Corner {
+    pub fn get_previous_corner(&self) -> &Corner<TIndex> {
         return self.table.get_corner(self.get_previous_corner_index()).unwrap(); 
     }
 
     /// Returns opposite corner
     #[inline]
-    pub fn get_opposite_corner(&self) -> Option<&Corner> {
+    pub fn get_opposite_corner(&self) -> Option<&Corner<TIndex>> {
         if let Some(opposite) = self.get_corner().get_opposite_corner_index() {
             return Some(self.table.get_corner(opposite).unwrap());
         }
@@ -171,30 +171,30 @@ impl<'a, TScalar: RealNumber> CornerWalker<'a, TScalar> {
 
     /// Returns current corner
     #[inline]
-    pub fn get_corner(&self) -> &Corner {
+    pub fn get_corner(&self) -> &Corner<TIndex> {
         return self.table.get_corner(self.corner_index).unwrap();
     }
 
     /// Returns current corner index
     #[inline]
-    pub fn get_corner_index(&self) -> usize {
+    pub fn get_corner_index(&self) -> CornerId<TIndex> {
         return self.corner_index;
     }
 
     /// Returns vertex of current corner
     #[inline]
-    pub fn get_vertex(&self) -> &Vertex<TScalar> {
+    pub fn get_vertex(&self) -> &Vertex<TScalar, TIndex> {
         return self.table.get_vertex(self.get_corner().get_vertex_index()).unwrap();
     }
 }
 
-impl<'a, TScalar: RealNumber> Position<'a, CornerTable<TScalar>> for CornerWalker<'a, TScalar> {
+impl<'a, TScalar: RealNumber, TIndex: ElementIndex> Position<'a, CornerTable<TScalar, TIndex>> for CornerWalker<'a, TScalar, TIndex> {
     fn from_vertex_on_face(
-        mesh: &'a CornerTable<TScalar>, 
-        corner: &<CornerTable<TScalar> as crate::mesh::traits::Mesh>::FaceDescriptor, 
-        vertex: &<CornerTable<TScalar> as crate::mesh::traits::Mesh>::VertexDescriptor
+        mesh: &'a CornerTable<TScalar, TIndex>, 
+        face: &<CornerTable<TScalar, TIndex> as crate::mesh::traits::Mesh>::FaceDescriptor, 
+        vertex: &<CornerTable<TScalar, TIndex> as crate::mesh::traits::Mesh>::VertexDescriptor
     ) -> Self { 
-        let mut walker = CornerWalker::from_corner(mesh, *corner);
+        let mut walker = CornerWalker::from_corner(mesh, first_corner(*face));
 
         if walker.get_corner().get_vertex_index() == *vertex {
             return walker;
@@ -217,11 +217,11 @@ impl<'a, TScalar: RealNumber> Position<'a, CornerTable<TScalar>> for CornerWalke
 
     #[inline]
     fn from_edge_on_face(
-        mesh: &'a CornerTable<TScalar>, 
-        f: &<CornerTable<TScalar> as crate::mesh::traits::Mesh>::FaceDescriptor, 
-        edge: &<CornerTable<TScalar> as crate::mesh::traits::Mesh>::EdgeDescriptor
+        mesh: &'a CornerTable<TScalar, TIndex>, 
+        f: &<CornerTable<TScalar, TIndex> as crate::mesh::traits::Mesh>::FaceDescriptor, 
+        edge: &<CornerTable<TScalar, TIndex> as crate::mesh::traits::Mesh>::EdgeDescriptor
     ) -> Self {
-        let corner = if face_contains_corner(face(*f), edge.get_corner_index()) { 
+        let corner = if face_contains_corner(*f, edge.get_corner_index()) { 
             edge.get_corner_index()
         } else { 
             mesh.corners[edge.get_corner_index()].get_opposite_corner_index().unwrap() 
@@ -233,10 +233,10 @@ impl<'a, TScalar: RealNumber> Position<'a, CornerTable<TScalar>> for CornerWalke
     #[inline]
     fn set_from_vertex_on_face(
         &mut self, 
-        corner: &<CornerTable<TScalar> as crate::mesh::traits::Mesh>::FaceDescriptor, 
-        vertex: &<CornerTable<TScalar> as crate::mesh::traits::Mesh>::VertexDescriptor
+        face: &<CornerTable<TScalar, TIndex> as crate::mesh::traits::Mesh>::FaceDescriptor, 
+        vertex: &<CornerTable<TScalar, TIndex> as crate::mesh::traits::Mesh>::VertexDescriptor
     ) -> &mut Self {
-        self.set_current_corner(first_corner(face(*corner)));
+        self.set_current_corner(first_corner(*face));
 
         while self.get_corner().get_vertex_index() != *vertex {
             self.next();
@@ -248,10 +248,10 @@ impl<'a, TScalar: RealNumber> Position<'a, CornerTable<TScalar>> for CornerWalke
     #[inline]
     fn set_from_edge_on_face(
         &mut self, 
-        f: &<CornerTable<TScalar> as crate::mesh::traits::Mesh>::FaceDescriptor, 
-        edge: &<CornerTable<TScalar> as crate::mesh::traits::Mesh>::EdgeDescriptor
+        f: &<CornerTable<TScalar, TIndex> as crate::mesh::traits::Mesh>::FaceDescriptor, 
+        edge: &<CornerTable<TScalar, TIndex> as crate::mesh::traits::Mesh>::EdgeDescriptor
     ) -> &mut Self {
-        if face_contains_corner(face(*f), edge.get_corner_index()) { 
+        if face_contains_corner(*f, edge.get_corner_index()) { 
             self.set_current_corner(edge.get_corner_index());
         } else { 
             let corner = self.table.corners[edge.get_corner_index()].get_opposite_corner_index().unwrap();
@@ -267,12 +267,12 @@ impl<'a, TScalar: RealNumber> Position<'a, CornerTable<TScalar>> for CornerWalke
     }
 
     #[inline]
-    fn get_vertex(&self) -> <CornerTable<TScalar> as crate::mesh::traits::Mesh>::VertexDescriptor {
+    fn get_vertex(&self) -> <CornerTable<TScalar, TIndex> as crate::mesh::traits::Mesh>::VertexDescriptor {
         return self.get_corner().get_vertex_index();
     }
 
     #[inline]
-    fn from_edge(mesh: &'a CornerTable<TScalar>, edge: &<CornerTable<TScalar> as crate::mesh::traits::Mesh>::EdgeDescriptor) -> Self {
+    fn from_edge(mesh: &'a CornerTable<TScalar, TIndex>, edge: &<CornerTable<TScalar, TIndex> as crate::mesh::traits::Mesh>::EdgeDescriptor) -> Self {
         return CornerWalker::from_corner(mesh, edge.get_corner_index());
     }
 
@@ -283,38 +283,38 @@ impl<'a, TScalar: RealNumber> Position<'a, CornerTable<TScalar>> for CornerWalke
 }
 
 ///
-/// Iterator over faces of corner table. Face is returned as one of its corners.
+/// Iterator over faces of corner table
 ///
-pub struct CornerTableFacesIter<'a, TScalar: RealNumber> {
-    table: &'a CornerTable<TScalar>,
-    corner_index: usize
+pub struct CornerTableFacesIter<'a, TScalar: RealNumber, TIndex: ElementIndex = usize> {
+    table: &'a CornerTable<TScalar, TIndex>,
+    face_index: usize
 }
 
-impl<'a, TScalar: RealNumber> CornerTableFacesIter<'a, TScalar> {
-    pub fn new(corner_table: &'a CornerTable<TScalar>) -> Self {
+impl<'a, TScalar: RealNumber, TIndex: ElementIndex> CornerTableFacesIter<'a, TScalar, TIndex> {
+    pub fn new(corner_table: &'a CornerTable<TScalar, TIndex>) -> Self {
         return Self {
             table: corner_table,
-            corner_index: 0
+            face_index: 0
         };
     }
 }
 
-impl<'a, TScalar: RealNumber> Iterator for CornerTableFacesIter<'a, TScalar> {
-    type Item = usize;
+impl<'a, TScalar: RealNumber, TIndex: ElementIndex> Iterator for CornerTableFacesIter<'a, TScalar, TIndex> {
+    type Item = FaceId<TIndex>;
 
     fn next(&mut self) -> Option<Self::Item> {
-        while let Some(next) = self.table.get_corner(self.corner_index) {
+        while let Some(next) = self.table.corners.get(self.face_index * 3) {
             if !next.is_deleted() {
                 break;
             }
 
-            self.corner_index += 3;
+            self.face_index += 1;
         }
 
-        match self.table.get_corner(self.corner_index) {
+        match self.table.corners.get(self.face_index * 3) {
             Some(_) => {
-                let current = self.corner_index;
-                self.corner_index += 3;
+                let current = FaceId::new(self.face_index);
+                self.face_index += 1;
     
                 return Some(current);
             },
@@ -326,13 +326,13 @@ impl<'a, TScalar: RealNumber> Iterator for CornerTableFacesIter<'a, TScalar> {
 ///
 /// Iterator over vertices of mesh
 /// 
-pub struct CornerTableVerticesIter<'a, TScalar: RealNumber> {
-    table: &'a CornerTable<TScalar>,
+pub struct CornerTableVerticesIter<'a, TScalar: RealNumber, TIndex: ElementIndex = usize> {
+    table: &'a CornerTable<TScalar, TIndex>,
     vertex_index: usize
 }
 
-impl<'a, TScalar: RealNumber> CornerTableVerticesIter<'a, TScalar> {
-    pub fn new(table: &'a CornerTable<TScalar>) -> Self {
+impl<'a, TScalar: RealNumber, TIndex: ElementIndex> CornerTableVerticesIter<'a, TScalar, TIndex> {
+    pub fn new(table: &'a CornerTable<TScalar, TIndex>) -> Self {
         return Self { 
             table,
             vertex_index: 0
@@ -340,11 +340,11 @@ impl<'a, TScalar: RealNumber> CornerTableVerticesIter<'a, TScalar> {
     }
 }
 
-impl<'a, TScalar: RealNumber> Iterator for CornerTableVerticesIter<'a, TScalar> {
-    type Item = usize;
+impl<'a, TScalar: RealNumber, TIndex: ElementIndex> Iterator for CornerTableVerticesIter<'a, TScalar, TIndex> {
+    type Item = VertexId<TIndex>;
 
     fn next(&mut self) -> Option<Self::Item> {
-        let next_index = self.vertex_index;
+        let next_index = VertexId::new(self.vertex_index);
         self.vertex_index += 1;
 
         match self.table.get_vertex(next_index) {
@@ -364,13 +364,13 @@ impl<'a, TScalar: RealNumber> Iterator for CornerTableVerticesIter<'a, TScalar>
 ///
 /// Iterator over edges of mesh. Edge is returned as corner opposite to it. Uses `is_visited` flag
 /// 
-pub struct CornerTableEdgesIter<'a, TScalar: RealNumber> {
-    table: &'a CornerTable<TScalar>,
+pub struct CornerTableEdgesIter<'a, TScalar: RealNumber, TIndex: ElementIndex = usize> {
+    table: &'a CornerTable<TScalar, TIndex>,
     corner_index: usize
 }
 
-impl<'a, TScalar: RealNumber> CornerTableEdgesIter<'a, TScalar> {
-    pub fn new(table: &'a CornerTable<TScalar>) -> Self {
+impl<'a, TScalar: RealNumber, TIndex: ElementIndex> CornerTableEdgesIter<'a, TScalar, TIndex> {
+    pub fn new(table: &'a CornerTable<TScalar, TIndex>) -> Self {
         clear_visited(table.corners.iter());
         return Self {
             table,
@@ -379,11 +379,11 @@ impl<'a, TScalar: RealNumber> CornerTableEdgesIter<'a, TScalar> {
     }
 }
 
-impl<'a, TScalar: RealNumber> Iterator for CornerTableEdgesIter<'a, TScalar> {
-    type Item = EdgeRef;
+impl<'a, TScalar: RealNumber, TIndex: ElementIndex> Iterator for CornerTableEdgesIter<'a, TScalar, TIndex> {
+    type Item = EdgeRef<TIndex>;
 
     fn next(&mut self) -> Option<Self::Item> {
-        while let Some(n) = self.table.get_corner(self.corner_index) {
+        while let Some(n) = self.table.corners.get(self.corner_index) {
             if !(n.is_visited() || n.is_deleted()) {
                 break;
             }
@@ -391,7 +391,7 @@ impl<'a, TScalar: RealNumber> Iterator for CornerTableEdgesIter<'a, TScalar> {
             self.corner_index += 1;
         }
 
-        match self.table.get_corner(self.corner_index) {
+        match self.table.corners.get(self.corner_index) {
             Some(next) => {
                 // Visit current
                 next.set_visited(true);
@@ -402,7 +402,7 @@ impl<'a, TScalar: RealNumber> Iterator for CornerTableEdgesIter<'a, TScalar> {
                 }
 
                 // Move to next
-                let current = self.corner_index;
+                let current = CornerId::new(self.corner_index);
                 self.corner_index += 1;
 
                 let edge = EdgeRef::new(current, self.table);
@@ -414,7 +414,7 @@ impl<'a, TScalar: RealNumber> Iterator for CornerTableEdgesIter<'a, TScalar> {
 }
 
 /// Iterates over corners that are adjacent to given vertex
-pub fn corners_around_vertex<TScalar: RealNumber, TFunc: FnMut(&usize)>(corner_table: &CornerTable<TScalar>, vertex_index: usize, mut visit: TFunc) {
+pub fn corners_around_vertex<TScalar: RealNumber, TIndex: ElementIndex, TFunc: FnMut(&CornerId<TIndex>)>(corner_table: &CornerTable<TScalar, TIndex>, vertex_index: VertexId<TIndex>, mut visit: TFunc) {
     let mut walker = CornerWalker::from_vertex(corner_table, vertex_index);
     walker.previous();
     let started_at = walker.get_corner_index();
@@ -456,7 +456,7 @@ pub fn corners_around_vertex<TScalar: RealNumber, TFunc: FnMut(&usize)>(corner_t
     }
 }
 
-pub fn collect_corners_around_vertex<TScalar: RealNumber>(corner_table: &CornerTable<TScalar>, vertex_index: usize) -> Vec<usize> {
+pub fn collect_corners_around_vertex<TScalar: RealNumber, TIndex: ElementIndex>(corner_table: &CornerTable<TScalar, TIndex>, vertex_index: VertexId<TIndex>) -> Vec<CornerId<TIndex>> {
     let mut corners = Vec::with_capacity(MAX_VERTEX_VALENCE);
     corners_around_vertex(corner_table, vertex_index, |corner_index| {
         corners.push(*corner_index)
@@ -466,7 +466,7 @@ pub fn collect_corners_around_vertex<TScalar: RealNumber>(corner_table: &CornerT
 }
 
 /// Iterates over one-ring vertices of vertex
-pub fn vertices_around_vertex<TScalar: RealNumber, TFunc: FnMut(&usize)>(corner_table: &CornerTable<TScalar>, vertex_index: usize, mut visit: TFunc) {
+pub fn vertices_around_vertex<TScalar: RealNumber, TIndex: ElementIndex, TFunc: FnMut(&VertexId<TIndex>)>(corner_table: &CornerTable<TScalar, TIndex>, vertex_index: VertexId<TIndex>, mut visit: TFunc) {
     let mut walker = CornerWalker::from_vertex(corner_table, vertex_index);
     walker.previous();
     let started_at = walker.get_corner_index();
@@ -505,15 +505,15 @@ pub fn vertices_around_vertex<TScalar: RealNumber, TFunc: FnMut(&usize)>(corner_
     }
 }
 
-/// Iterates over one-ring faces of vertex. Face is returned as one of it`s corners.
-pub fn faces_around_vertex<TScalar: RealNumber, TFunc: FnMut(&usize)>(corner_table: &CornerTable<TScalar>, vertex_index: usize, mut visit: TFunc) {
+/// Iterates over one-ring faces of vertex
+pub fn faces_around_vertex<TScalar: RealNumber, TIndex: ElementIndex, TFunc: FnMut(&FaceId<TIndex>)>(corner_table: &CornerTable<TScalar, TIndex>, vertex_index: VertexId<TIndex>, mut visit: TFunc) {
     let mut walker = CornerWalker::from_vertex(corner_table, vertex_index);
     walker.previous();
     let started_at = walker.get_corner_index();
     let mut border_reached = false;
 
     loop {
-        visit(&walker.get_corner_index());
+        visit(&face(walker.get_corner_index()));
 
         walker.previous();
         
@@ -535,7 +535,7 @@ pub fn faces_around_vertex<TScalar: RealNumber, TFunc: FnMut(&usize)>(corner_tab
         walker.opposite();
 
         loop {    
-            visit(&walker.get_corner_index());
+            visit(&face(walker.get_corner_index()));
 
             walker.next();
 
@@ -549,7 +549,7 @@ pub fn faces_around_vertex<TScalar: RealNumber, TFunc: FnMut(&usize)>(corner_tab
 }
 
 /// Iterates over edges incident to vertex. Edge is represented by opposite corner index.
-pub fn edges_around_vertex<TScalar: RealNumber, TFunc: FnMut(&EdgeRef)>(corner_table: &CornerTable<TScalar>, vertex_index: usize, mut visit: TFunc) {
+pub fn edges_around_vertex<TScalar: RealNumber, TIndex: ElementIndex, TFunc: FnMut(&EdgeRef<TIndex>)>(corner_table: &CornerTable<TScalar, TIndex>, vertex_index: VertexId<TIndex>, mut visit: TFunc) {
     let mut walker = CornerWalker::from_vertex(corner_table, vertex_index);
     walker.next();
     let started_at = walker.get_corner_index();
@@ -591,7 +591,7 @@ mod tests {
     use crate::mesh::{
         corner_table::{
             test_helpers::{create_unit_square_mesh, create_unit_cross_square_mesh}, 
-            traversal::{vertices_around_vertex, faces_around_vertex, corners_around_vertex}, descriptors::EdgeRef
+            traversal::{vertices_around_vertex, faces_around_vertex, corners_around_vertex}, descriptors::{EdgeRef, CornerId, VertexId}
         }, 
         traits::Mesh
     };
@@ -600,11 +600,11 @@ mod tests {
     fn edges_iterator() {
         let mesh = create_unit_square_mesh();
         let expected_edges: Vec<EdgeRef> = vec![
-            EdgeRef::new(0, &mesh), 
-            EdgeRef::new(1, &mesh), 
-            EdgeRef::new(2, &mesh), 
-            EdgeRef::new(3, &mesh), 
-            EdgeRef::new(5, &mesh)
+            EdgeRef::new(CornerId::new(0), &mesh), 
+            EdgeRef::new(CornerId::new(1), &mesh), 
+            EdgeRef::new(CornerId::new(2), &mesh), 
+            EdgeRef::new(CornerId::new(3), &mesh), 
+            EdgeRef::new(CornerId::new(5), &mesh)
         ];
 
         assert_eq!(expected_edges.len(), mesh.edges().count());
@@ -624,7 +624,7 @@ mod tests {
         let expected_corners: Vec<usize> = vec![11, 2, 5, 8];
         let mut corners: Vec<usize> = Vec::new();
 
-        corners_around_vertex(&mesh, 4, |corner_index| corners.push(*corner_index));
+        corners_around_vertex(&mesh, VertexId::new(4), |corner_index| corners.push(corner_index.index()));
 
         assert_eq!(corners, expected_corners);
     }
@@ -635,7 +635,7 @@ mod tests {
         let expected_corners: Vec<usize> = vec![10, 0];
         let mut corners: Vec<usize> = Vec::new();
 
-        corners_around_vertex(&mesh, 0, |corner_index| corners.push(*corner_index));
+        corners_around_vertex(&mesh, VertexId::new(0), |corner_index| corners.push(corner_index.index()));
 
         assert_eq!(corners, expected_corners);
     }
@@ -647,7 +647,7 @@ mod tests {
         let mesh = create_unit_cross_square_mesh();
         let expected_vertices: Vec<usize> = vec![0, 1, 2, 3];
         let mut vertices: Vec<usize> = Vec::new();
-        vertices_around_vertex(&mesh, 4, |vertex_index| vertices.push(*vertex_index));
+        vertices_around_vertex(&mesh, VertexId::new(4), |vertex_index| vertices.push(vertex_index.index()));
     
         assert_eq!(vertices, expected_vertices);
     }
@@ -657,7 +657,7 @@ mod tests {
         let mesh = create_unit_cross_square_mesh();
         let expected_vertices: Vec<usize> = vec![3, 4, 1];
         let mut vertices: Vec<usize> = Vec::new();
-        vertices_around_vertex(&mesh, 0, |vertex_index| vertices.push(*vertex_index));
+        vertices_around_vertex(&mesh, VertexId::new(0), |vertex_index| vertices.push(vertex_index.index()));
     
         assert_eq!(vertices, expected_vertices);
     }
@@ -667,9 +667,9 @@ mod tests {
     #[test]
     fn faces_around_internal_vertex_macro() {
         let mesh = create_unit_cross_square_mesh();
-        let expected_faces: Vec<usize> = vec![10, 1, 4, 7];
+        let expected_faces: Vec<usize> = vec![3, 0, 1, 2];
         let mut faces: Vec<usize> = Vec::new();
-        faces_around_vertex(&mesh, 4, |face_index| faces.push(*face_index));
+        faces_around_vertex(&mesh, VertexId::new(4), |face_index| faces.push(face_index.index()));
     
         assert_eq!(faces, expected_faces);
     }
@@ -677,9 +677,9 @@ mod tests {
     #[test]
     fn faces_around_boundary_vertex_macro() {
         let mesh = create_unit_cross_square_mesh();
-        let expected_faces: Vec<usize> = vec![9, 1];
+        let expected_faces: Vec<usize> = vec![3, 0];
         let mut faces: Vec<usize> = Vec::new();
-        faces_around_vertex(&mesh, 0, |face_index| faces.push(*face_index));
+        faces_around_vertex(&mesh, VertexId::new(0), |face_index| faces.push(face_index.index()));
     
         assert_eq!(faces, expected_faces);
     }
@@ -0,0 +1,153 @@
+use std::fmt::Display;
+
+use crate::geometry::traits::RealNumber;
+use super::{
+    table::CornerTable,
+    connectivity::{corner::{next, previous, face}, traits::Flags},
+    descriptors::{ElementIndex, CornerId}
+};
+
+///
+/// Violation of corner table connectivity invariant found by [CornerTable::validate]
+///
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Violation {
+    /// Opposite corner does not exist, is deleted or belongs to same face
+    InvalidOppositeCorner { corner: usize, opposite: usize },
+    /// Opposite of opposite corner is not corner itself
+    AsymmetricOppositeCorner { corner: usize, opposite: usize },
+    /// Edges opposite to corners are not same edge with reversed direction
+    OppositeEdgeMismatch { corner: usize, opposite: usize },
+    /// Corner of vertex does not exist, is deleted or references other vertex
+    InvalidVertexCorner { vertex: usize, corner: usize },
+    /// Live corner references vertex that does not exist or is deleted
+    DeletedVertexReference { corner: usize, vertex: usize }
+}
+
+impl Display for Violation {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Violation::InvalidOppositeCorner { corner, opposite } =>
+                return write!(f, "corner {} has invalid opposite corner {}", corner, opposite),
+            Violation::AsymmetricOppositeCorner { corner, opposite } =>
+                return write!(f, "corner {} is opposite to {}, but not vice versa", corner, opposite),
+            Violation::OppositeEdgeMismatch { corner, opposite } =>
+                return write!(f, "edges opposite to corners {} and {} do not match", corner, opposite),
+            Violation::InvalidVertexCorner { vertex, corner } =>
+                return write!(f, "vertex {} references corner {} that is not incident to it", vertex, corner),
+            Violation::DeletedVertexReference { corner, vertex } =>
+                return write!(f, "corner {} references deleted vertex {}", corner, vertex),
+        }
+    }
+}
+
+impl<TScalar: RealNumber, TIndex: ElementIndex> CornerTable<TScalar, TIndex> {
+    ///
+    /// Checks connectivity invariants and returns list of found violations, empty for valid corner table.
+    /// Isolated vertices (not referencing any corner) are allowed.
+    ///
+    pub fn validate(&self) -> Vec<Violation> {
+        let mut violations = Vec::new();
+
+        for (index, corner) in self.corners.iter().enumerate() {
+            if corner.is_deleted() {
+                continue;
+            }
+
+            let corner_index = CornerId::new(index);
+            let vertex = corner.get_vertex_index();
+
+            if self.get_vertex(vertex).is_none_or(|vertex| vertex.is_deleted()) {
+                violations.push(Violation::DeletedVertexReference { corner: index, vertex: vertex.index() });
+            }
+
+            let Some(opposite) = corner.get_opposite_corner_index() else {
+                continue;
+            };
+
+            let opposite_corner = self.get_corner(opposite);
+
+            if opposite_corner.is_none_or(|corner| corner.is_deleted()) || face(opposite) == face(corner_index) {
+                violations.push(Violation::InvalidOppositeCorner { corner: index, opposite: opposite.index() });
+                continue;
+            }
+
+            if opposite_corner.unwrap().get_opposite_corner_index() != Some(corner_index) {
+                violations.push(Violation::AsymmetricOppositeCorner { corner: index, opposite: opposite.index() });
+            }
+
+            let vertex_index = |corner: CornerId<TIndex>| self.corners[corner].get_vertex_index();
+
+            if vertex_index(next(corner_index)) != vertex_index(previous(opposite)) ||
+               vertex_index(previous(corner_index)) != vertex_index(next(opposite)) {
+                violations.push(Violation::OppositeEdgeMismatch { corner: index, opposite: opposite.index() });
+            }
+        }
+
+        for (vertex_index, vertex) in self.vertices.iter().enumerate() {
+            let corner = vertex.get_corner_index();
+
+            if vertex.is_deleted() || !corner.is_valid() {
+                continue;
+            }
+
+            let is_valid = self.get_corner(corner)
+                .is_some_and(|corner| !corner.is_deleted() && corner.get_vertex_index().index() == vertex_index);
+
+            if !is_valid {
+                violations.push(Violation::InvalidVertexCorner { vertex: vertex_index, corner: corner.index() });
+            }
+        }
+
+        return violations;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::mesh::corner_table::{
+        connectivity::traits::Flags,
+        descriptors::{CornerId, VertexId},
+        test_helpers::{create_collapse_edge_sample_mesh1, create_unit_square_mesh, create_torus_mesh}
+    };
+    use super::Violation;
+
+    #[test]
+    fn should_accept_valid_meshes() {
+        assert_eq!(create_unit_square_mesh().validate(), vec![]);
+        assert_eq!(create_collapse_edge_sample_mesh1().validate(), vec![]);
+        assert_eq!(create_torus_mesh(6, 5).validate(), vec![]);
+    }
+
+    #[test]
+    fn should_report_opposite_corner_violations() {
+        let mut mesh = create_unit_square_mesh();
+        mesh.get_corner_mut(CornerId::new(0)).unwrap().set_opposite_corner_index(Some(CornerId::new(1)));
+        assert_eq!(mesh.validate(), vec![Violation::InvalidOppositeCorner { corner: 0, opposite: 1 }]);
+
+        // Corners 1 and 4 are opposite to diagonal
+        let mut mesh = create_unit_square_mesh();
+        mesh.get_corner_mut(CornerId::new(4)).unwrap().set_opposite_corner_index(Some(CornerId::new(0)));
+        assert_eq!(mesh.validate(), vec![
+            Violation::AsymmetricOppositeCorner { corner: 1, opposite: 4 },
+            Violation::AsymmetricOppositeCorner { corner: 4, opposite: 0 },
+            Violation::OppositeEdgeMismatch { corner: 4, opposite: 0 }
+        ]);
+    }
+
+    #[test]
+    fn should_report_vertex_violations() {
+        let mut mesh = create_unit_square_mesh();
+        mesh.get_vertex_mut(VertexId::new(1)).unwrap().set_corner_index(CornerId::new(0));
+        mesh.get_vertex_mut(VertexId::new(3)).unwrap().set_deleted(true);
+        assert_eq!(mesh.validate(), vec![
+            Violation::DeletedVertexReference { corner: 4, vertex: 3 },
+            Violation::InvalidVertexCorner { vertex: 1, corner: 0 }
+        ]);
+
+        let mesh = create_unit_square_mesh();
+        let corner = mesh.get_vertex(VertexId::new(0)).unwrap().get_corner_index();
+        mesh.get_corner(corner).unwrap().set_deleted(true);
+        assert!(mesh.validate().contains(&Violation::InvalidVertexCorner { vertex: 0, corner: corner.index() }));
+    }
+}
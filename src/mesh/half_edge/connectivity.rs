@@ -0,0 +1,185 @@
+use nalgebra::Point3;
+use crate::{geometry::traits::RealNumber, mesh::corner_table::connectivity::{traits::Flags, flags}};
+
+///
+/// Vertex of half-edge mesh. References one of outgoing half-edges,
+/// for boundary vertex it is always outgoing boundary half-edge.
+///
+#[derive(Debug)]
+pub struct Vertex<TScalar: RealNumber> {
+    halfedge: usize,
+    position: Point3<TScalar>,
+    flags: flags::FlagsCell
+}
+
+impl<TScalar: RealNumber> Vertex<TScalar> {
+    pub fn new(halfedge: usize, position: Point3<TScalar>) -> Self {
+        return Self {
+            halfedge,
+            position,
+            flags: Default::default()
+        };
+    }
+
+    #[inline]
+    pub fn get_position(&self) -> &Point3<TScalar> {
+        return &self.position;
+    }
+
+    #[inline]
+    pub fn set_position(&mut self, point: Point3<TScalar>) -> &mut Self {
+        self.position = point;
+        return self;
+    }
+
+    /// Returns outgoing half-edge, `usize::MAX` for isolated vertex
+    #[inline]
+    pub fn get_halfedge(&self) -> usize {
+        return self.halfedge;
+    }
+
+    #[inline]
+    pub fn set_halfedge(&mut self, halfedge: usize) -> &mut Self {
+        self.halfedge = halfedge;
+        return self;
+    }
+
+    /// Returns `true` if vertex has no incident edges
+    #[inline]
+    pub fn is_isolated(&self) -> bool {
+        return self.halfedge == usize::MAX;
+    }
+}
+
+impl<TScalar: RealNumber> Flags for Vertex<TScalar> {
+    #[inline]
+    fn get_flags(&self) -> &flags::FlagsCell {
+        return &self.flags;
+    }
+}
+
+///
+/// Directed edge of half-edge mesh. Half-edges are allocated in pairs, so twin of half-edge `h` is `h ^ 1`.
+/// Boundary half-edges have no face and form loops around holes.
+///
+#[derive(Debug, Default)]
+pub struct HalfEdge {
+    vertex: usize,
+    face: Option<usize>,
+    next: usize,
+    previous: usize,
+    flags: flags::FlagsCell
+}
+
+impl HalfEdge {
+    /// Returns vertex half-edge is pointing to
+    #[inline]
+    pub fn get_vertex(&self) -> usize {
+        return self.vertex;
+    }
+
+    #[inline]
+    pub fn set_vertex(&mut self, vertex: usize) -> &mut Self {
+        self.vertex = vertex;
+        return self;
+    }
+
+    /// Returns incident face, `None` for boundary half-edge
+    #[inline]
+    pub fn get_face(&self) -> Option<usize> {
+        return self.face;
+    }
+
+    #[inline]
+    pub fn set_face(&mut self, face: Option<usize>) -> &mut Self {
+        self.face = face;
+        return self;
+    }
+
+    #[inline]
+    pub fn get_next(&self) -> usize {
+        return self.next;
+    }
+
+    #[inline]
+    pub fn set_next(&mut self, next: usize) -> &mut Self {
+        self.next = next;
+        return self;
+    }
+
+    #[inline]
+    pub fn get_previous(&self) -> usize {
+        return self.previous;
+    }
+
+    #[inline]
+    pub fn set_previous(&mut self, previous: usize) -> &mut Self {
+        self.previous = previous;
+        return self;
+    }
+
+    #[inline]
+    pub fn is_boundary(&self) -> bool {
+        return self.face.is_none();
+    }
+}
+
+impl Flags for HalfEdge {
+    #[inline]
+    fn get_flags(&self) -> &flags::FlagsCell {
+        return &self.flags;
+    }
+}
+
+/// Face of half-edge mesh
+#[derive(Debug)]
+pub struct Face {
+    halfedge: usize,
+    flags: flags::FlagsCell
+}
+
+impl Face {
+    pub fn new(halfedge: usize) -> Self {
+        return Self {
+            halfedge,
+            flags: Default::default()
+        };
+    }
+
+    /// Returns one of face half-edges
+    #[inline]
+    pub fn get_halfedge(&self) -> usize {
+        return self.halfedge;
+    }
+
+    #[inline]
+    pub fn set_halfedge(&mut self, halfedge: usize) -> &mut Self {
+        self.halfedge = halfedge;
+        return self;
+    }
+}
+
+impl Flags for Face {
+    #[inline]
+    fn get_flags(&self) -> &flags::FlagsCell {
+        return &self.flags;
+    }
+}
+
+/// Returns twin of half-edge
+#[inline]
+pub fn twin(halfedge: usize) -> usize {
+    return halfedge ^ 1;
+}
+
+/// Returns edge of half-edge
+#[inline]
+pub fn edge(halfedge: usize) -> usize {
+    return halfedge >> 1;
+}
+
+/// Returns first half-edge of edge
+#[inline]
+pub fn first_halfedge(edge: usize) -> usize {
+    return edge << 1;
+}
@@ -0,0 +1,345 @@
+use nalgebra::Point3;
+use crate::{
+    mesh::{traits::{EditableMesh, SplitFaceAtPoint}, corner_table::connectivity::traits::Flags},
+    geometry::traits::RealNumber
+};
+use super::{
+    mesh::HalfEdgeMesh,
+    connectivity::{Vertex, HalfEdge, Face, twin, first_halfedge}
+};
+
+impl<TScalar: RealNumber> HalfEdgeMesh<TScalar> {
+    /// Creates new isolated vertex
+    fn create_vertex(&mut self, position: Point3<TScalar>) -> usize {
+        self.vertices.push(Vertex::new(usize::MAX, position));
+        return self.vertices.len() - 1;
+    }
+
+    /// Creates pair of half-edges without faces, returns half-edge going from `start` to `end`
+    fn create_edge(&mut self, start: usize, end: usize) -> usize {
+        let halfedge = self.halfedges.len();
+
+        let mut forward = HalfEdge::default();
+        forward.set_vertex(end);
+        let mut backward = HalfEdge::default();
+        backward.set_vertex(start);
+
+        self.halfedges.push(forward);
+        self.halfedges.push(backward);
+
+        return halfedge;
+    }
+
+    /// Links three half-edges into face. Returns face index, existing face is reused when given.
+    fn create_face(&mut self, face: Option<usize>, halfedges: [usize; 3]) -> usize {
+        let face = match face {
+            Some(face) => {
+                self.faces[face].set_halfedge(halfedges[0]);
+                face
+            },
+            None => {
+                self.faces.push(Face::new(halfedges[0]));
+                self.faces.len() - 1
+            },
+        };
+
+        for i in 0..3 {
+            self.link(halfedges[i], halfedges[(i + 1) % 3]);
+            self.halfedges[halfedges[i]].set_face(Some(face));
+        }
+
+        return face;
+    }
+
+    /// Marks both half-edges of edge as deleted
+    #[inline]
+    fn delete_edge(&mut self, halfedge: usize) {
+        self.halfedges[halfedge].set_deleted(true);
+        self.halfedges[twin(halfedge)].set_deleted(true);
+    }
+
+    ///
+    /// Removes edge of `remove` half-edge after collapse made it parallel to `keep` (both are on the same collapsed face).
+    /// `keep` takes place of twin of `remove` in adjacent face or boundary loop.
+    ///
+    fn remove_loop(&mut self, keep: usize, remove: usize) {
+        let outer = twin(remove);
+        let outer_previous = self.halfedges[outer].get_previous();
+        let outer_next = self.halfedges[outer].get_next();
+        let outer_face = self.halfedges[outer].get_face();
+
+        self.halfedges[keep].set_face(outer_face);
+        self.link(outer_previous, keep);
+        self.link(keep, outer_next);
+
+        if let Some(face) = outer_face {
+            if self.faces[face].get_halfedge() == outer {
+                self.faces[face].set_halfedge(keep);
+            }
+        }
+
+        // Vertices should not reference deleted half-edges
+        let remove_start = self.target(outer);
+        if self.vertices[remove_start].get_halfedge() == remove {
+            self.vertices[remove_start].set_halfedge(twin(keep));
+        }
+
+        let outer_start = self.target(remove);
+        if self.vertices[outer_start].get_halfedge() == outer {
+            self.vertices[outer_start].set_halfedge(keep);
+        }
+
+        self.delete_edge(remove);
+    }
+}
+
+impl<TScalar: RealNumber> EditableMesh for HalfEdgeMesh<TScalar> {
+    /// Collapses edge to its start vertex (first vertex returned by `edge_vertices`), end vertex is removed
+    fn collapse_edge(&mut self, edge: &Self::EdgeDescriptor, at: &Point3<Self::ScalarType>) {
+        let h = self.face_halfedge(*edge);
+        let t = twin(h);
+        let v_keep = self.source(h);
+        let v_remove = self.target(h);
+
+        let h_next = self.halfedges[h].get_next();
+        let h_previous = self.halfedges[h].get_previous();
+        let h_face = self.halfedges[h].get_face();
+        let t_next = self.halfedges[t].get_next();
+        let t_previous = self.halfedges[t].get_previous();
+        let t_face = self.halfedges[t].get_face();
+
+        // Redirect half-edges incoming to removed vertex
+        let mut incoming = Vec::new();
+        self.outgoing_halfedges(v_remove, |halfedge| incoming.push(twin(halfedge)));
+
+        for halfedge in incoming {
+            self.halfedges[halfedge].set_vertex(v_keep);
+        }
+
+        // Remove degenerated faces
+        self.remove_loop(h_next, h_previous);
+
+        if t_face.is_some() {
+            self.remove_loop(t_previous, t_next);
+        } else {
+            self.link(t_previous, t_next);
+        }
+
+        for face in [h_face, t_face].into_iter().flatten() {
+            self.faces[face].set_deleted(true);
+        }
+
+        self.delete_edge(h);
+        self.vertices[v_remove].set_deleted(true);
+
+        self.vertices[v_keep].set_position(*at);
+        self.vertices[v_keep].set_halfedge(h_next);
+        self.adjust_outgoing_halfedge(v_keep);
+    }
+
+    fn flip_edge(&mut self, edge: &Self::EdgeDescriptor) {
+        let h = self.face_halfedge(*edge);
+        let t = twin(h);
+
+        // Face 1: (v0, v1, v2), face 2: (v1, v0, v3)
+        let h_next = self.halfedges[h].get_next();
+        let h_previous = self.halfedges[h].get_previous();
+        let t_next = self.halfedges[t].get_next();
+        let t_previous = self.halfedges[t].get_previous();
+
+        let v0 = self.source(h);
+        let v1 = self.target(h);
+        let v2 = self.target(h_next);
+        let v3 = self.target(t_next);
+
+        let f1 = self.halfedges[h].get_face();
+        let f2 = self.halfedges[t].get_face();
+
+        // Flipped edge goes from v3 to v2
+        self.halfedges[h].set_vertex(v2);
+        self.halfedges[t].set_vertex(v3);
+
+        self.create_face(f1, [h, h_previous, t_next]);
+        self.create_face(f2, [t, t_previous, h_next]);
+
+        // Make sure vertices are referencing outgoing half-edges
+        if self.vertices[v0].get_halfedge() == h {
+            self.vertices[v0].set_halfedge(t_next);
+        }
+
+        if self.vertices[v1].get_halfedge() == t {
+            self.vertices[v1].set_halfedge(h_next);
+        }
+    }
+
+    /// Inserts new vertex at `at`, edge is split into two and each incident face into two as well
+    fn split_edge(&mut self, edge: &Self::EdgeDescriptor, at: &Point3<Self::ScalarType>) {
+        let h = self.face_halfedge(*edge);
+        let t = twin(h);
+
+        // Face 1: (v0, v1, v2)
+        let h_next = self.halfedges[h].get_next();
+        let h_previous = self.halfedges[h].get_previous();
+        let v1 = self.target(h);
+        let v2 = self.target(h_next);
+
+        let new_vertex = self.create_vertex(*at);
+
+        // `h` now ends at new vertex, second half of edge goes from new vertex to v1
+        self.halfedges[h].set_vertex(new_vertex);
+        let second_half = self.create_edge(new_vertex, v1);
+
+        let to_v2 = self.create_edge(new_vertex, v2);
+        self.create_face(self.halfedges[h].get_face(), [h, to_v2, h_previous]);
+        self.create_face(None, [second_half, h_next, twin(to_v2)]);
+
+        // Face 2: (v1, v0, v3)
+        match self.halfedges[t].get_face() {
+            Some(t_face) => {
+                let t_next = self.halfedges[t].get_next();
+                let t_previous = self.halfedges[t].get_previous();
+                let v3 = self.target(t_next);
+
+                let to_v3 = self.create_edge(new_vertex, v3);
+                self.create_face(Some(t_face), [t, t_next, twin(to_v3)]);
+                self.create_face(None, [twin(second_half), to_v3, t_previous]);
+            },
+            None => {
+                let t_previous = self.halfedges[t].get_previous();
+                self.link(t_previous, twin(second_half));
+                self.link(twin(second_half), t);
+            }
+        }
+
+        // New vertex references `t` that is on boundary for boundary edge
+        self.vertices[new_vertex].set_halfedge(t);
+
+        if self.vertices[v1].get_halfedge() == t {
+            self.vertices[v1].set_halfedge(twin(second_half));
+        }
+    }
+
+    #[inline]
+    fn shift_vertex(&mut self, vertex: &Self::VertexDescriptor, to: &Point3<Self::ScalarType>) {
+        self.vertices[*vertex].set_position(*to);
+    }
+
+    #[inline]
+    fn edge_exist(&self, edge: &Self::EdgeDescriptor) -> bool {
+        return !self.halfedges[first_halfedge(*edge)].is_deleted();
+    }
+}
+
+impl<TScalar: RealNumber> SplitFaceAtPoint for HalfEdgeMesh<TScalar> {
+    fn split_face(&mut self, face: &Self::FaceDescriptor, point: Point3<Self::ScalarType>) {
+        let h0 = self.faces[*face].get_halfedge();
+        let h1 = self.halfedges[h0].get_next();
+        let h2 = self.halfedges[h1].get_next();
+        let (v0, v1, v2) = (self.source(h0), self.target(h0), self.target(h1));
+
+        let new_vertex = self.create_vertex(point);
+        let from_v0 = self.create_edge(v0, new_vertex);
+        let from_v1 = self.create_edge(v1, new_vertex);
+        let from_v2 = self.create_edge(v2, new_vertex);
+
+        self.create_face(Some(*face), [h0, from_v1, twin(from_v0)]);
+        self.create_face(None, [h1, from_v2, twin(from_v1)]);
+        self.create_face(None, [h2, from_v0, twin(from_v2)]);
+
+        self.vertices[new_vertex].set_halfedge(twin(from_v0));
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use nalgebra::Point3;
+
+    use crate::mesh::{
+        corner_table::{
+            prelude::CornerTableF,
+            descriptors::{EdgeRef, FaceId, CornerId},
+            test_helpers::{create_unit_cross_square_mesh, create_collapse_edge_sample_mesh1, create_flip_edge_sample_mesh, create_unit_square_mesh}
+        },
+        half_edge::{prelude::HalfEdgeMeshF, test_helpers::{assert_connectivity, find_edge, sorted_triangles}},
+        traits::{EditableMesh, Mesh, SplitFaceAtPoint}
+    };
+
+    /// Returns same edge of half-edge mesh and corner table
+    fn edges(table: &CornerTableF, mesh: &HalfEdgeMeshF, corner: usize) -> (EdgeRef, usize) {
+        let edge_ref = EdgeRef::new(CornerId::new(corner), table);
+        let (v1, v2) = table.edge_vertices(&edge_ref);
+        return (edge_ref, find_edge(mesh, v1.index(), v2.index()).unwrap());
+    }
+
+    #[test]
+    fn split_inner_and_boundary_edges() {
+        for corner in 0..12 {
+            let mut table = create_unit_cross_square_mesh();
+            let mut mesh = HalfEdgeMeshF::from_corner_table(&table);
+            let (edge_ref, edge) = edges(&table, &mesh, corner);
+
+            let (start, end) = table.edge_positions(&edge_ref);
+            let at = Point3::from((start.coords + end.coords) * 0.5);
+            table.split_edge(&edge_ref, &at);
+            mesh.split_edge(&edge, &at);
+
+            assert_connectivity(&mesh);
+            assert_eq!(sorted_triangles(&mesh), sorted_triangles(&table));
+        }
+    }
+
+    #[test]
+    fn collapse_edge() {
+        let mut table = create_collapse_edge_sample_mesh1();
+        let mut mesh = HalfEdgeMeshF::from_corner_table(&table);
+        let (edge_ref, edge) = edges(&table, &mesh, 9);
+
+        let at = Point3::new(0.5, 0.5, 0.0);
+        table.collapse_edge(&edge_ref, &at);
+        mesh.collapse_edge(&edge, &at);
+
+        assert_connectivity(&mesh);
+        assert!(!mesh.edge_exist(&edge));
+        assert_eq!(mesh.faces().count(), table.faces().count());
+        assert_eq!(mesh.vertices().count(), table.vertices().count());
+        assert_eq!(sorted_triangles(&mesh), sorted_triangles(&table));
+    }
+
+    #[test]
+    fn collapse_boundary_edge() {
+        let mut mesh = HalfEdgeMeshF::from_corner_table(&create_unit_cross_square_mesh());
+        let edge = find_edge(&mesh, 0, 1).unwrap();
+
+        mesh.collapse_edge(&edge, &Point3::new(0.0, 0.5, 0.0));
+
+        assert_connectivity(&mesh);
+        assert_eq!(mesh.faces().count(), 3);
+        assert_eq!(mesh.vertices().count(), 4);
+    }
+
+    #[test]
+    fn flip_edge() {
+        let mut table = create_flip_edge_sample_mesh();
+        let mut mesh = HalfEdgeMeshF::from_corner_table(&table);
+        let (edge_ref, edge) = edges(&table, &mesh, 1);
+
+        table.flip_edge(&edge_ref);
+        mesh.flip_edge(&edge);
+
+        assert_connectivity(&mesh);
+        assert_eq!(sorted_triangles(&mesh), sorted_triangles(&table));
+    }
+
+    #[test]
+    fn split_face() {
+        let mut table = create_unit_square_mesh();
+        let mut mesh = HalfEdgeMeshF::from_corner_table(&table);
+
+        let point = Point3::new(0.2, 0.6, 0.0);
+        table.split_face(&FaceId::new(0), point);
+        mesh.split_face(&0, point);
+
+        assert_connectivity(&mesh);
+        assert_eq!(sorted_triangles(&mesh), sorted_triangles(&table));
+    }
+}
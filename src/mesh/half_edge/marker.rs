@@ -0,0 +1,61 @@
+use crate::{
+    geometry::traits::RealNumber,
+    mesh::{traits::{Marker, Mesh}, corner_table::connectivity::traits::Flags}
+};
+use super::{mesh::HalfEdgeMesh, connectivity::first_halfedge};
+
+/// Implementation of [Marker] API for [HalfEdgeMesh]. Edge flag is stored on first half-edge of pair.
+pub struct HalfEdgeMeshMarker<TScalar: RealNumber> {
+    mesh: *const HalfEdgeMesh<TScalar>
+}
+
+impl<TScalar: RealNumber> HalfEdgeMeshMarker<TScalar> {
+    pub fn new(mesh: &HalfEdgeMesh<TScalar>) -> Self {
+        return Self { mesh };
+    }
+}
+
+impl<TScalar: RealNumber> Marker<HalfEdgeMesh<TScalar>> for HalfEdgeMeshMarker<TScalar> {
+
+    //
+    // Face
+    //
+
+    #[inline]
+    fn mark_face(&mut self, face: &<HalfEdgeMesh<TScalar> as Mesh>::FaceDescriptor, marked: bool) {
+        unsafe { (&(*self.mesh).faces)[*face].set_marked_1(marked); }
+    }
+
+    #[inline]
+    fn is_face_marked(&self, face: &<HalfEdgeMesh<TScalar> as Mesh>::FaceDescriptor) -> bool {
+        unsafe { return (&(*self.mesh).faces)[*face].is_marked_1(); }
+    }
+
+    //
+    // Vertex
+    //
+
+    #[inline]
+    fn mark_vertex(&mut self, vertex: &<HalfEdgeMesh<TScalar> as Mesh>::VertexDescriptor, marked: bool) {
+        unsafe { (&(*self.mesh).vertices)[*vertex].set_marked_1(marked); }
+    }
+
+    #[inline]
+    fn is_vertex_marked(&self, vertex: &<HalfEdgeMesh<TScalar> as Mesh>::VertexDescriptor) -> bool {
+        unsafe { return (&(*self.mesh).vertices)[*vertex].is_marked_1(); }
+    }
+
+    //
+    // Edge
+    //
+
+    #[inline]
+    fn mark_edge(&mut self, edge: &<HalfEdgeMesh<TScalar> as Mesh>::EdgeDescriptor, marked: bool) {
+        unsafe { (&(*self.mesh).halfedges)[first_halfedge(*edge)].set_marked_2(marked); }
+    }
+
+    #[inline]
+    fn is_edge_marked(&self, edge: &<HalfEdgeMesh<TScalar> as Mesh>::EdgeDescriptor) -> bool {
+        unsafe { return (&(*self.mesh).halfedges)[first_halfedge(*edge)].is_marked_2(); }
+    }
+}
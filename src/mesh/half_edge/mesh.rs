@@ -0,0 +1,496 @@
+use std::collections::HashMap;
+
+use nalgebra::{Point3, Vector3};
+use crate::{
+    geometry::traits::RealNumber,
+    mesh::{
+        traits::{Mesh, TopologicalMesh, MeshMarker},
+        corner_table::{
+            table::CornerTable,
+            connectivity::{traits::Flags, corner::face_corners},
+            descriptors::{ElementIndex, VertexId}
+        }
+    }
+};
+use super::{
+    connectivity::{Vertex, HalfEdge, Face, twin, edge, first_halfedge},
+    traversal::{HalfEdgeWalker, ElementsIter},
+    marker::HalfEdgeMeshMarker
+};
+
+///
+/// Half-edge mesh. Every edge is represented by pair of directed half-edges,
+/// boundary half-edges have no face and are linked into loops around holes.
+/// Deleted elements are marked with flag and are not reused.
+///
+pub struct HalfEdgeMesh<TScalar: RealNumber> {
+    pub(super) vertices: Vec<Vertex<TScalar>>,
+    pub(super) halfedges: Vec<HalfEdge>,
+    pub(super) faces: Vec<Face>
+}
+
+impl<TScalar: RealNumber> Default for HalfEdgeMesh<TScalar> {
+    fn default() -> Self {
+        return Self {
+            vertices: Vec::new(),
+            halfedges: Vec::new(),
+            faces: Vec::new()
+        };
+    }
+}
+
+impl<TScalar: RealNumber> HalfEdgeMesh<TScalar> {
+    #[inline]
+    pub fn new() -> Self {
+        return Default::default();
+    }
+
+    ///
+    /// Creates half-edge mesh with same vertices, faces and connectivity as corner table.
+    /// Vertex indices are preserved, faces are numbered in order of corner table faces.
+    ///
+    pub fn from_corner_table<TIndex: ElementIndex>(table: &CornerTable<TScalar, TIndex>) -> Self {
+        let mut mesh = Self::new();
+
+        while let Some(vertex) = table.get_vertex(VertexId::new(mesh.vertices.len())) {
+            let new_vertex = Vertex::new(usize::MAX, *vertex.get_position());
+            new_vertex.set_deleted(vertex.is_deleted());
+            mesh.vertices.push(new_vertex);
+        }
+
+        // Half-edge corresponding to corner goes along edge opposite to it
+        let mut corner_halfedge = HashMap::new();
+
+        for table_face in table.faces() {
+            let face = mesh.faces.len();
+            let corners = face_corners(table_face);
+            let corner_vertices = corners.map(|corner| table.get_corner(corner).unwrap().get_vertex_index().index());
+
+            let halfedges = [0, 1, 2].map(|i| {
+                let corner = table.get_corner(corners[i]).unwrap();
+                let opposite_halfedge = corner.get_opposite_corner_index().and_then(|opposite| corner_halfedge.get(&opposite));
+
+                let halfedge = match opposite_halfedge {
+                    Some(opposite) => twin(*opposite),
+                    None => {
+                        let halfedge = mesh.halfedges.len();
+                        mesh.halfedges.push(Default::default());
+                        mesh.halfedges.push(Default::default());
+                        mesh.halfedges[twin(halfedge)].set_vertex(corner_vertices[(i + 1) % 3]);
+
+                        halfedge
+                    }
+                };
+
+                mesh.halfedges[halfedge]
+                    .set_vertex(corner_vertices[(i + 2) % 3])
+                    .set_face(Some(face));
+                corner_halfedge.insert(corners[i], halfedge);
+
+                return halfedge;
+            });
+
+            for i in 0..3 {
+                mesh.halfedges[halfedges[i]]
+                    .set_next(halfedges[(i + 1) % 3])
+                    .set_previous(halfedges[(i + 2) % 3]);
+
+                // Half-edge of corner `i + 2` starts at vertex of corner `i`
+                mesh.vertices[corner_vertices[i]].set_halfedge(halfedges[(i + 2) % 3]);
+            }
+
+            mesh.faces.push(Face::new(halfedges[2]));
+        }
+
+        mesh.link_boundary_halfedges();
+
+        return mesh;
+    }
+
+    #[inline]
+    pub fn get_vertex(&self, vertex: usize) -> Option<&Vertex<TScalar>> {
+        return self.vertices.get(vertex);
+    }
+
+    #[inline]
+    pub fn get_halfedge(&self, halfedge: usize) -> Option<&HalfEdge> {
+        return self.halfedges.get(halfedge);
+    }
+
+    #[inline]
+    pub fn get_face(&self, face: usize) -> Option<&Face> {
+        return self.faces.get(face);
+    }
+
+    /// Returns vertex half-edge is starting from
+    #[inline]
+    pub fn source(&self, halfedge: usize) -> usize {
+        return self.halfedges[twin(halfedge)].get_vertex();
+    }
+
+    /// Returns vertex half-edge is pointing to
+    #[inline]
+    pub fn target(&self, halfedge: usize) -> usize {
+        return self.halfedges[halfedge].get_vertex();
+    }
+
+    /// Returns half-edge of edge that has incident face
+    #[inline]
+    pub fn face_halfedge(&self, edge: usize) -> usize {
+        let halfedge = first_halfedge(edge);
+
+        if self.halfedges[halfedge].is_boundary() {
+            return twin(halfedge);
+        }
+
+        return halfedge;
+    }
+
+    /// Iterates over half-edges outgoing from vertex
+    pub fn outgoing_halfedges<TVisit: FnMut(usize)>(&self, vertex: usize, mut visit: TVisit) {
+        let start = self.vertices[vertex].get_halfedge();
+
+        if start == usize::MAX {
+            return;
+        }
+
+        let mut halfedge = start;
+
+        loop {
+            visit(halfedge);
+            halfedge = self.halfedges[twin(halfedge)].get_next();
+
+            if halfedge == start {
+                break;
+            }
+        }
+    }
+
+    /// Makes given half-edges consecutive
+    #[inline]
+    pub(super) fn link(&mut self, previous: usize, next: usize) {
+        self.halfedges[previous].set_next(next);
+        self.halfedges[next].set_previous(previous);
+    }
+
+    /// Makes vertex reference outgoing boundary half-edge if there is one
+    pub(super) fn adjust_outgoing_halfedge(&mut self, vertex: usize) {
+        let mut boundary = None;
+        self.outgoing_halfedges(vertex, |halfedge| {
+            if boundary.is_none() && self.halfedges[halfedge].is_boundary() {
+                boundary = Some(halfedge);
+            }
+        });
+
+        if let Some(halfedge) = boundary {
+            self.vertices[vertex].set_halfedge(halfedge);
+        }
+    }
+
+    /// Links boundary half-edges into loops, expects manifold vertices
+    fn link_boundary_halfedges(&mut self) {
+        let boundary: Vec<_> = (0..self.halfedges.len())
+            .filter(|halfedge| self.halfedges[*halfedge].is_boundary())
+            .collect();
+
+        for halfedge in &boundary {
+            let source = self.source(*halfedge);
+            self.vertices[source].set_halfedge(*halfedge);
+        }
+
+        for halfedge in boundary {
+            let next = self.vertices[self.target(halfedge)].get_halfedge();
+            self.link(halfedge, next);
+        }
+    }
+}
+
+///
+/// Implementation of mesh trait for half-edge mesh.
+///
+/// Edge is represented by index of half-edge pair.
+/// Vertex is represented by it`s index in vertices vector.
+/// Face is represented by it`s index in faces vector.
+///
+impl<TScalar: RealNumber> Mesh for HalfEdgeMesh<TScalar> {
+    type ScalarType = TScalar;
+
+    /// Index of half-edge pair
+    type EdgeDescriptor = usize;
+    /// Vertex index
+    type VertexDescriptor = usize;
+    /// Face index
+    type FaceDescriptor = usize;
+
+    type FacesIter<'iter> = ElementsIter<'iter, Face>;
+    type VerticesIter<'iter> = ElementsIter<'iter, Vertex<TScalar>>;
+    type EdgesIter<'iter> = ElementsIter<'iter, HalfEdge>;
+
+    /// Non-manifold input is handled same way as by [CornerTable], vertices of non-manifold fans are duplicated
+    fn from_vertices_and_indices(vertices: &[Point3<Self::ScalarType>], faces: &[usize]) -> Self {
+        return Self::from_corner_table(&CornerTable::<TScalar, usize>::from_vertices_and_indices(vertices, faces));
+    }
+
+    /// Vertices of corner table keep their indices in half-edge mesh, so duplicates are the same
+    fn from_vertices_and_indices_with_duplicates(vertices: &[Point3<Self::ScalarType>], faces: &[usize]) -> (Self, Vec<(usize, usize)>) {
+        let (table, duplicates) = CornerTable::<TScalar, usize>::from_vertices_and_indices_with_duplicates(vertices, faces);
+        return (Self::from_corner_table(&table), duplicates);
+    }
+
+    #[inline]
+    fn faces(&self) -> Self::FacesIter<'_> {
+        return ElementsIter::new(&self.faces, 1);
+    }
+
+    #[inline]
+    fn vertices(&self) -> Self::VerticesIter<'_> {
+        return ElementsIter::new(&self.vertices, 1);
+    }
+
+    #[inline]
+    fn edges(&self) -> Self::EdgesIter<'_> {
+        return ElementsIter::new(&self.halfedges, 2);
+    }
+
+    #[inline]
+    fn face_vertices(&self, face: &Self::FaceDescriptor) -> (Self::VertexDescriptor, Self::VertexDescriptor, Self::VertexDescriptor) {
+        let halfedge = self.faces[*face].get_halfedge();
+        return (
+            self.source(halfedge),
+            self.target(halfedge),
+            self.target(self.halfedges[halfedge].get_next())
+        );
+    }
+
+    #[inline]
+    fn edge_positions(&self, edge: &Self::EdgeDescriptor) -> (Point3<Self::ScalarType>, Point3<Self::ScalarType>) {
+        let (start, end) = self.edge_vertices(edge);
+        return (
+            *self.vertices[start].get_position(),
+            *self.vertices[end].get_position()
+        );
+    }
+
+    #[inline]
+    fn edge_vertices(&self, edge: &Self::EdgeDescriptor) -> (Self::VertexDescriptor, Self::VertexDescriptor) {
+        let halfedge = self.face_halfedge(*edge);
+        return (self.source(halfedge), self.target(halfedge));
+    }
+
+    #[inline]
+    fn vertex_position(&self, vertex: &Self::VertexDescriptor) -> &Point3<Self::ScalarType> {
+        return self.vertices[*vertex].get_position();
+    }
+
+    fn vertex_normal(&self, vertex: &Self::VertexDescriptor) -> Option<Vector3<Self::ScalarType>> {
+        let mut sum = Vector3::zeros();
+
+        self.faces_around_vertex(vertex, |face| {
+            sum += self.face_normal(face);
+        });
+
+        if sum.iter().all(|i| i.is_zero()) {
+            return None;
+        }
+
+        return Some(sum.normalize());
+    }
+}
+
+impl<TScalar: RealNumber> TopologicalMesh for HalfEdgeMesh<TScalar> {
+    type Position<'a> = HalfEdgeWalker<'a, TScalar>;
+
+    #[inline]
+    fn vertices_around_vertex<TVisit: FnMut(&Self::VertexDescriptor)>(&self, vertex: &Self::VertexDescriptor, mut visit: TVisit) {
+        self.outgoing_halfedges(*vertex, |halfedge| visit(&self.target(halfedge)));
+    }
+
+    #[inline]
+    fn faces_around_vertex<TVisit: FnMut(&Self::FaceDescriptor)>(&self, vertex: &Self::VertexDescriptor, mut visit: TVisit) {
+        self.outgoing_halfedges(*vertex, |halfedge| {
+            if let Some(face) = self.halfedges[halfedge].get_face() {
+                visit(&face);
+            }
+        });
+    }
+
+    #[inline]
+    fn edges_around_vertex<TVisit: FnMut(&Self::EdgeDescriptor)>(&self, vertex: &Self::VertexDescriptor, mut visit: TVisit) {
+        self.outgoing_halfedges(*vertex, |halfedge| visit(&edge(halfedge)));
+    }
+
+    #[inline]
+    fn is_vertex_on_boundary(&self, vertex: &Self::VertexDescriptor) -> bool {
+        let vertex = &self.vertices[*vertex];
+        return !vertex.is_isolated() && self.halfedges[vertex.get_halfedge()].is_boundary();
+    }
+
+    #[inline]
+    fn is_edge_on_boundary(&self, edge: &Self::EdgeDescriptor) -> bool {
+        let halfedge = first_halfedge(*edge);
+        return self.halfedges[halfedge].is_boundary() || self.halfedges[twin(halfedge)].is_boundary();
+    }
+
+    #[inline]
+    fn edge_faces(&self, edge: &Self::EdgeDescriptor) -> (Self::FaceDescriptor, Option<Self::FaceDescriptor>) {
+        let halfedge = self.face_halfedge(*edge);
+        return (
+            self.halfedges[halfedge].get_face().unwrap(),
+            self.halfedges[twin(halfedge)].get_face()
+        );
+    }
+
+    /// Returns edges opposite to face vertices, in order of [Mesh::face_vertices]
+    #[inline]
+    fn face_edges(&self, face: &Self::FaceDescriptor) -> (Self::EdgeDescriptor, Self::EdgeDescriptor, Self::EdgeDescriptor) {
+        let halfedge = self.faces[*face].get_halfedge();
+        return (
+            edge(self.halfedges[halfedge].get_next()),
+            edge(self.halfedges[halfedge].get_previous()),
+            edge(halfedge)
+        );
+    }
+}
+
+impl<TScalar: RealNumber> MeshMarker for HalfEdgeMesh<TScalar> {
+    type Marker = HalfEdgeMeshMarker<TScalar>;
+
+    #[inline]
+    fn marker(&self) -> Self::Marker {
+        return HalfEdgeMeshMarker::new(self);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use nalgebra::Point3;
+
+    use crate::{
+        mesh::{
+            corner_table::{
+                prelude::CornerTableF,
+                descriptors::VertexId,
+                test_helpers::{create_unit_square_mesh, create_unit_cross_square_mesh, create_uv_sphere_mesh}
+            },
+            traits::{Mesh, TopologicalMesh}
+        },
+        decimation::{prelude::EdgeDecimator, edge_decimation::AlwaysDecimate},
+        remeshing::incremental::IncrementalRemesher,
+        reeb_graph::reeb_graph::ReebGraph
+    };
+    use super::super::{prelude::HalfEdgeMeshF, test_helpers::assert_connectivity};
+
+    #[test]
+    fn from_corner_table() {
+        let table = create_unit_square_mesh();
+        let mesh = HalfEdgeMeshF::from_corner_table(&table);
+
+        assert_connectivity(&mesh);
+        assert_eq!(mesh.faces().count(), 2);
+        assert_eq!(mesh.vertices().count(), 4);
+        assert_eq!(mesh.edges().count(), 5);
+
+        let faces: Vec<_> = mesh.faces().map(|face| mesh.face_vertices(&face)).collect();
+        let expected: Vec<_> = table.faces()
+            .map(|face| table.face_vertices(&face))
+            .map(|(v1, v2, v3)| (v1.index(), v2.index(), v3.index()))
+            .collect();
+        assert_eq!(faces, expected);
+
+        for vertex in mesh.vertices() {
+            assert_eq!(mesh.vertex_position(&vertex), table.vertex_position(&VertexId::new(vertex)));
+            assert!(mesh.is_vertex_on_boundary(&vertex));
+        }
+
+        let boundary_edges = mesh.edges().filter(|edge| mesh.is_edge_on_boundary(edge)).count();
+        assert_eq!(boundary_edges, 4);
+    }
+
+    #[test]
+    fn topological_queries() {
+        let mesh = HalfEdgeMeshF::from_corner_table(&create_unit_cross_square_mesh());
+        assert_connectivity(&mesh);
+
+        // Center vertex
+        let mut neighbors = Vec::new();
+        mesh.vertices_around_vertex(&4, |vertex| neighbors.push(*vertex));
+        neighbors.sort();
+        assert_eq!(neighbors, vec![0, 1, 2, 3]);
+        assert!(!mesh.is_vertex_on_boundary(&4));
+
+        let mut faces_count = 0;
+        mesh.faces_around_vertex(&4, |_| faces_count += 1);
+        assert_eq!(faces_count, 4);
+
+        let mut edges = Vec::new();
+        mesh.edges_around_vertex(&4, |edge| edges.push(*edge));
+        assert_eq!(edges.len(), 4);
+
+        for edge in edges {
+            assert!(!mesh.is_edge_on_boundary(&edge));
+            let (f1, f2) = mesh.edge_faces(&edge);
+            assert_ne!(Some(f1), f2);
+        }
+
+        for face in mesh.faces() {
+            let (e1, e2, e3) = mesh.face_edges(&face);
+            let (v1, v2, v3) = mesh.face_vertices(&face);
+
+            // Edges are opposite to vertices
+            for (edge, vertex) in [(e1, v1), (e2, v2), (e3, v3)] {
+                let (start, end) = mesh.edge_vertices(&edge);
+                assert!(start != vertex && end != vertex);
+            }
+        }
+    }
+
+    #[test]
+    fn decimation() {
+        let mut mesh = HalfEdgeMeshF::from_corner_table(&create_uv_sphere_mesh(16, 24));
+        let faces_before = mesh.faces().count();
+
+        let mut decimator = EdgeDecimator::<HalfEdgeMeshF, AlwaysDecimate>::new().min_faces_count(Some(100));
+        decimator.decimate(&mut mesh);
+
+        assert_connectivity(&mesh);
+        assert!(mesh.faces().count() < faces_before);
+        assert!(mesh.faces().count() >= 98);
+    }
+
+    #[test]
+    fn remeshing() {
+        let mut mesh = HalfEdgeMeshF::from_corner_table(&create_uv_sphere_mesh(8, 12));
+
+        IncrementalRemesher::new()
+            .with_iterations_count(3)
+            .remesh(&mut mesh, 0.2);
+
+        assert_connectivity(&mesh);
+        assert!(mesh.faces().count() > 8 * 12 * 2);
+
+        // Vertices are projected back on sphere
+        for vertex in mesh.vertices() {
+            assert!((mesh.vertex_position(&vertex).coords.norm() - 1.0).abs() < 0.1);
+        }
+    }
+
+    #[test]
+    fn reeb_graph() {
+        let vertices = [
+            Point3::new(0.0, 0.0, 0.0),
+            Point3::new(2.0, 3.0, 0.0),
+            Point3::new(-1.0, 4.0, 0.0),
+            Point3::new(-2.0, 2.0, 0.0),
+        ];
+        let indices = [0, 1, 2, 0, 2, 3];
+
+        let table = CornerTableF::from_vertices_and_indices(&vertices, &indices);
+        let mesh = HalfEdgeMeshF::from_vertices_and_indices(&vertices, &indices);
+
+        let table_graph = ReebGraph::new().scalars(|m: &CornerTableF, v| m.vertex_position(v).y).build(&table);
+        let mesh_graph = ReebGraph::new().scalars(|m: &HalfEdgeMeshF, v| m.vertex_position(v).y).build(&mesh);
+
+        assert_eq!(mesh_graph.node_count(), table_graph.node_count());
+        assert_eq!(mesh_graph.edge_count(), table_graph.edge_count());
+    }
+}
@@ -0,0 +1,11 @@
+pub mod mesh;
+pub mod prelude;
+pub mod traversal;
+pub mod connectivity;
+
+mod marker;
+mod editable;
+mod property_maps;
+
+#[cfg(test)]
+pub(crate) mod test_helpers;
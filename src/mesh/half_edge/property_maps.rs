@@ -0,0 +1,76 @@
+use std::ops::{Index, IndexMut};
+
+use crate::{mesh::traits::{PropertyMap, VertexProperties, FaceProperties, EdgeProperties}, geometry::traits::RealNumber};
+
+use super::mesh::HalfEdgeMesh;
+
+/// 
+/// Property map for half-edge mesh elements.
+/// All descriptors of half-edge mesh are indices, so same map is used for vertices, faces and edges.
+/// 
+pub struct IndexPropertyMap<TProperty: Default> {
+    props: Vec<TProperty>
+}
+
+impl<TProperty: Default> IndexPropertyMap<TProperty> {
+    pub fn new(elements_count: usize) -> Self {
+        let mut props = Vec::new();
+        props.resize_with(elements_count, Default::default);
+        return Self { props };
+    }
+}
+
+impl<TProperty: Default> Index<usize> for IndexPropertyMap<TProperty> {
+    type Output = TProperty;
+
+    #[inline]
+    fn index(&self, index: usize) -> &Self::Output {
+        return &self.props[index];
+    }
+}
+
+impl<TProperty: Default> IndexMut<usize> for IndexPropertyMap<TProperty> {
+    #[inline]
+    fn index_mut(&mut self, index: usize) -> &mut Self::Output {
+        return &mut self.props[index];
+    }
+}
+
+impl<TProperty: Default> PropertyMap<usize, TProperty> for IndexPropertyMap<TProperty> {
+    #[inline]
+    fn get(&self, key: &usize) -> Option<&TProperty> {
+        return self.props.get(*key);
+    }
+
+    #[inline]
+    fn get_mut(&mut self, key: &usize) -> Option<&mut TProperty> {
+        return self.props.get_mut(*key);
+    }
+}
+
+impl<TScalar: RealNumber> VertexProperties for HalfEdgeMesh<TScalar> {
+    type VertexPropertyMap<TProperty: Default> = IndexPropertyMap<TProperty>;
+
+    #[inline]
+    fn create_vertex_properties_map<TProperty: Default>(&self) -> Self::VertexPropertyMap<TProperty> {
+        return IndexPropertyMap::new(self.vertices.len());
+    }
+}
+
+impl<TScalar: RealNumber> FaceProperties for HalfEdgeMesh<TScalar> {
+    type FacePropertyMap<TProperty: Default> = IndexPropertyMap<TProperty>;
+
+    #[inline]
+    fn create_face_properties_map<TProperty: Default>(&self) -> Self::FacePropertyMap<TProperty> {
+        return IndexPropertyMap::new(self.faces.len());
+    }
+}
+
+impl<TScalar: RealNumber> EdgeProperties for HalfEdgeMesh<TScalar> {
+    type EdgePropertyMap<TProperty: Default> = IndexPropertyMap<TProperty>;
+
+    #[inline]
+    fn create_edge_properties_map<TProperty: Default>(&self) -> Self::EdgePropertyMap<TProperty> {
+        return IndexPropertyMap::new(self.halfedges.len() / 2);
+    }
+}
@@ -0,0 +1,68 @@
+use crate::mesh::{traits::Mesh, corner_table::connectivity::traits::Flags};
+use super::{mesh::HalfEdgeMesh, connectivity::{twin, edge}};
+
+/// Checks that links between half-edges, faces and vertices are consistent
+pub fn assert_connectivity(mesh: &HalfEdgeMesh<f32>) {
+    for (index, halfedge) in mesh.halfedges.iter().enumerate() {
+        if halfedge.is_deleted() {
+            assert!(mesh.halfedges[twin(index)].is_deleted(), "Twin of deleted half-edge {} is not deleted", index);
+            continue;
+        }
+
+        let next = halfedge.get_next();
+        let previous = halfedge.get_previous();
+
+        assert_eq!(mesh.halfedges[next].get_previous(), index, "Half-edge {}", index);
+        assert_eq!(mesh.halfedges[previous].get_next(), index, "Half-edge {}", index);
+        assert_eq!(mesh.target(previous), mesh.source(index), "Half-edge {}", index);
+        assert_eq!(mesh.halfedges[next].get_face(), halfedge.get_face(), "Half-edge {}", index);
+        assert!(!mesh.halfedges[next].is_deleted(), "Half-edge {}", index);
+        assert!(!mesh.vertices[halfedge.get_vertex()].is_deleted(), "Half-edge {}", index);
+
+        if let Some(face) = halfedge.get_face() {
+            assert!(!mesh.faces[face].is_deleted(), "Half-edge {}", index);
+            assert_eq!(mesh.halfedges[mesh.halfedges[next].get_next()].get_next(), index, "Half-edge {}", index);
+        }
+    }
+
+    for face in mesh.faces() {
+        let halfedge = mesh.faces[face].get_halfedge();
+        assert_eq!(mesh.halfedges[halfedge].get_face(), Some(face), "Face {}", face);
+    }
+
+    for vertex in mesh.vertices() {
+        let halfedge = mesh.vertices[vertex].get_halfedge();
+        assert!(!mesh.halfedges[halfedge].is_deleted(), "Vertex {}", vertex);
+        assert_eq!(mesh.source(halfedge), vertex, "Vertex {}", vertex);
+
+        let mut has_boundary = false;
+        mesh.outgoing_halfedges(vertex, |outgoing| has_boundary |= mesh.halfedges[outgoing].is_boundary());
+        assert_eq!(mesh.halfedges[halfedge].is_boundary(), has_boundary, "Vertex {} must reference boundary half-edge", vertex);
+    }
+}
+
+/// Returns edge between given vertices
+pub fn find_edge(mesh: &HalfEdgeMesh<f32>, v1: usize, v2: usize) -> Option<usize> {
+    let mut found = None;
+    mesh.outgoing_halfedges(v1, |halfedge| {
+        if mesh.target(halfedge) == v2 {
+            found = Some(edge(halfedge));
+        }
+    });
+
+    return found;
+}
+
+/// Returns faces as sorted positions, can be used to compare meshes with different representations
+pub fn sorted_triangles<TMesh: Mesh<ScalarType = f32>>(mesh: &TMesh) -> Vec<[[i32; 3]; 3]> {
+    let mut triangles: Vec<_> = mesh.faces()
+        .map(|face| {
+            let triangle = mesh.face_positions(&face);
+            let mut vertices = [triangle.p1(), triangle.p2(), triangle.p3()].map(|p| [p.x, p.y, p.z].map(|c| (c * 1000.0).round() as i32));
+            vertices.sort();
+            vertices
+        })
+        .collect();
+    triangles.sort();
+    return triangles;
+}
@@ -0,0 +1,195 @@
+use std::marker::PhantomData;
+
+use crate::{
+    geometry::traits::RealNumber,
+    mesh::{traits::{Position, Mesh}, corner_table::connectivity::traits::Flags}
+};
+use super::{mesh::HalfEdgeMesh, connectivity::{twin, first_halfedge}};
+
+///
+/// Can be used to traverse half-edge mesh topology.
+/// Position is stored as half-edge and represents face corner opposite to it,
+/// so it can be used interchangeably with corner table walker in generic algorithms.
+///
+pub struct HalfEdgeWalker<'a, TScalar: RealNumber> {
+    mesh: &'a HalfEdgeMesh<TScalar>,
+    halfedge: usize
+}
+
+impl<'a, TScalar: RealNumber> HalfEdgeWalker<'a, TScalar> {
+    /// Creates walker starting at given half-edge
+    pub fn from_halfedge(mesh: &'a HalfEdgeMesh<TScalar>, halfedge: usize) -> Self {
+        return Self { mesh, halfedge };
+    }
+
+    /// Returns current half-edge
+    #[inline]
+    pub fn get_halfedge(&self) -> usize {
+        return self.halfedge;
+    }
+
+    /// Jumps to given half-edge
+    #[inline]
+    pub fn set_halfedge(&mut self, halfedge: usize) -> &mut Self {
+        self.halfedge = halfedge;
+        return self;
+    }
+
+    /// Moves to next half-edge of face
+    #[allow(clippy::should_implement_trait)]
+    #[inline]
+    pub fn next(&mut self) -> &mut Self {
+        self.halfedge = self.mesh.halfedges[self.halfedge].get_next();
+        return self;
+    }
+
+    /// Moves to previous half-edge of face
+    #[inline]
+    pub fn previous(&mut self) -> &mut Self {
+        self.halfedge = self.mesh.halfedges[self.halfedge].get_previous();
+        return self;
+    }
+
+    /// Moves to twin half-edge
+    #[inline]
+    pub fn twin(&mut self) -> &mut Self {
+        self.halfedge = twin(self.halfedge);
+        return self;
+    }
+
+    /// Returns vertex of corner opposite to current half-edge
+    #[inline]
+    pub fn get_corner_vertex(&self) -> usize {
+        let next = self.mesh.halfedges[self.halfedge].get_next();
+        return self.mesh.target(next);
+    }
+
+    /// Returns face of current half-edge
+    #[inline]
+    pub fn get_face(&self) -> Option<usize> {
+        return self.mesh.halfedges[self.halfedge].get_face();
+    }
+}
+
+impl<'a, TScalar: RealNumber> Position<'a, HalfEdgeMesh<TScalar>> for HalfEdgeWalker<'a, TScalar> {
+    #[inline]
+    fn from_vertex_on_face(
+        mesh: &'a HalfEdgeMesh<TScalar>,
+        face: &<HalfEdgeMesh<TScalar> as Mesh>::FaceDescriptor,
+        vertex: &<HalfEdgeMesh<TScalar> as Mesh>::VertexDescriptor
+    ) -> Self {
+        let mut walker = HalfEdgeWalker::from_halfedge(mesh, 0);
+        walker.set_from_vertex_on_face(face, vertex);
+        return walker;
+    }
+
+    #[inline]
+    fn from_edge_on_face(
+        mesh: &'a HalfEdgeMesh<TScalar>,
+        face: &<HalfEdgeMesh<TScalar> as Mesh>::FaceDescriptor,
+        edge: &<HalfEdgeMesh<TScalar> as Mesh>::EdgeDescriptor
+    ) -> Self {
+        let mut walker = HalfEdgeWalker::from_halfedge(mesh, 0);
+        walker.set_from_edge_on_face(face, edge);
+        return walker;
+    }
+
+    #[inline]
+    fn from_edge(mesh: &'a HalfEdgeMesh<TScalar>, edge: &<HalfEdgeMesh<TScalar> as Mesh>::EdgeDescriptor) -> Self {
+        return HalfEdgeWalker::from_halfedge(mesh, mesh.face_halfedge(*edge));
+    }
+
+    fn set_from_vertex_on_face(
+        &mut self,
+        face: &<HalfEdgeMesh<TScalar> as Mesh>::FaceDescriptor,
+        vertex: &<HalfEdgeMesh<TScalar> as Mesh>::VertexDescriptor
+    ) -> &mut Self {
+        self.set_halfedge(self.mesh.faces[*face].get_halfedge());
+
+        for _ in 0..3 {
+            if self.get_corner_vertex() == *vertex {
+                return self;
+            }
+
+            self.next();
+        }
+
+        unreachable!("Input must be invalid or non-manifold");
+    }
+
+    #[inline]
+    fn set_from_edge_on_face(
+        &mut self,
+        face: &<HalfEdgeMesh<TScalar> as Mesh>::FaceDescriptor,
+        edge: &<HalfEdgeMesh<TScalar> as Mesh>::EdgeDescriptor
+    ) -> &mut Self {
+        let halfedge = first_halfedge(*edge);
+
+        if self.mesh.halfedges[halfedge].get_face() == Some(*face) {
+            return self.set_halfedge(halfedge);
+        }
+
+        return self.set_halfedge(twin(halfedge));
+    }
+
+    #[inline]
+    fn next(&mut self) -> &mut Self {
+        return self.next();
+    }
+
+    /// Moves to opposite corner, expects that current half-edge is not on boundary
+    #[inline]
+    fn opposite(&mut self) -> &mut Self {
+        debug_assert!(!self.mesh.halfedges[twin(self.halfedge)].is_boundary(), "Moving to not existing corner");
+        return self.twin();
+    }
+
+    #[inline]
+    fn get_vertex(&self) -> <HalfEdgeMesh<TScalar> as Mesh>::VertexDescriptor {
+        return self.get_corner_vertex();
+    }
+}
+
+///
+/// Iterator over not deleted elements of half-edge mesh.
+/// Returns index of element divided by `step` (edges are iterated as half-edge pairs).
+///
+pub struct ElementsIter<'a, TElement: Flags> {
+    elements: &'a [TElement],
+    index: usize,
+    step: usize,
+    phantom: PhantomData<TElement>
+}
+
+impl<'a, TElement: Flags> ElementsIter<'a, TElement> {
+    pub fn new(elements: &'a [TElement], step: usize) -> Self {
+        return Self {
+            elements,
+            index: 0,
+            step,
+            phantom: PhantomData
+        };
+    }
+}
+
+impl<'a, TElement: Flags> Iterator for ElementsIter<'a, TElement> {
+    type Item = usize;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        while let Some(element) = self.elements.get(self.index) {
+            let current = self.index;
+            self.index += self.step;
+
+            if !element.is_deleted() {
+                return Some(current / self.step);
+            }
+        }
+
+        return None;
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        return (0, Some(self.elements.len().saturating_sub(self.index).div_ceil(self.step)));
+    }
+}
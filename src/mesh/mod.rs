@@ -1,3 +1,5 @@
 pub mod corner_table;
+pub mod half_edge;
+pub mod polygon;
 pub mod polygon_soup;
 pub mod traits;
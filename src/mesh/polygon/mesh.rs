@@ -0,0 +1,342 @@
+use std::ops::Range;
+
+use nalgebra::{Point3, Vector3};
+use num_traits::Float;
+#[cfg(feature = "serde")]
+use serde::{Serialize, Deserialize};
+use crate::{
+    geometry::traits::RealNumber,
+    mesh::{
+        corner_table::{table::CornerTable, traversal::CornerWalker, descriptors::ElementIndex},
+        traits::{Mesh, TopologicalMesh}
+    }
+};
+use super::triangulation::{triangulate_polygon, newell_normal};
+
+///
+/// Mesh with faces of arbitrary number of vertices (quads, n-gons).
+/// Faces are stored as loops of vertex indices in counter-clockwise order.
+///
+/// ## Example
+/// ```ignore
+/// let mut mesh = PolygonMeshF::new();
+/// let v0 = mesh.add_vertex(Point3::new(0.0, 0.0, 0.0));
+/// let v1 = mesh.add_vertex(Point3::new(1.0, 0.0, 0.0));
+/// let v2 = mesh.add_vertex(Point3::new(1.0, 1.0, 0.0));
+/// let v3 = mesh.add_vertex(Point3::new(0.0, 1.0, 0.0));
+/// mesh.add_face(&[v0, v1, v2, v3]);
+///
+/// let triangulated = mesh.to_corner_table();
+/// ```
+///
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct PolygonMesh<TScalar: RealNumber> {
+    vertices: Vec<Point3<TScalar>>,
+    indices: Vec<usize>,
+    face_offsets: Vec<usize>
+}
+
+impl<TScalar: RealNumber> PolygonMesh<TScalar> {
+    #[inline]
+    pub fn new() -> Self {
+        return Default::default();
+    }
+
+    /// Adds vertex and returns its index
+    #[inline]
+    pub fn add_vertex(&mut self, position: Point3<TScalar>) -> usize {
+        self.vertices.push(position);
+        return self.vertices.len() - 1;
+    }
+
+    /// Adds face defined by loop of vertex indices and returns its index
+    pub fn add_face(&mut self, vertices: &[usize]) -> usize {
+        debug_assert!(vertices.len() >= 3, "Face must have at least 3 vertices");
+        debug_assert!(vertices.iter().all(|vertex| *vertex < self.vertices.len()), "Invalid vertex index");
+
+        self.indices.extend_from_slice(vertices);
+        self.face_offsets.push(self.indices.len());
+
+        return self.face_offsets.len() - 2;
+    }
+
+    /// Returns range of vertex indices
+    #[inline]
+    pub fn vertices(&self) -> Range<usize> {
+        return 0..self.vertices.len();
+    }
+
+    /// Returns range of face indices
+    #[inline]
+    pub fn faces(&self) -> Range<usize> {
+        return 0..self.face_offsets.len() - 1;
+    }
+
+    #[inline]
+    pub fn vertices_count(&self) -> usize {
+        return self.vertices.len();
+    }
+
+    #[inline]
+    pub fn faces_count(&self) -> usize {
+        return self.face_offsets.len() - 1;
+    }
+
+    #[inline]
+    pub fn vertex_position(&self, vertex: &usize) -> &Point3<TScalar> {
+        return &self.vertices[*vertex];
+    }
+
+    /// Returns loop of face vertices
+    #[inline]
+    pub fn face_vertices(&self, face: &usize) -> &[usize] {
+        return &self.indices[self.face_offsets[*face]..self.face_offsets[face + 1]];
+    }
+
+    /// Returns iterator over positions of face vertices
+    #[inline]
+    pub fn face_positions(&self, face: &usize) -> impl Iterator<Item = &Point3<TScalar>> + '_ {
+        return self.face_vertices(face).iter().map(|vertex| &self.vertices[*vertex]);
+    }
+
+    /// Returns unit normal of face. Non-planar faces are handled with Newell's method.
+    pub fn face_normal(&self, face: &usize) -> Vector3<TScalar> {
+        let positions: Vec<_> = self.face_positions(face).cloned().collect();
+        return newell_normal(&positions).normalize();
+    }
+
+    /// Returns `true` if all faces of mesh are quads
+    pub fn is_quad_mesh(&self) -> bool {
+        return self.faces().all(|face| self.face_vertices(&face).len() == 4);
+    }
+
+    /// Triangulates all faces using ear clipping and returns corner table
+    pub fn to_corner_table(&self) -> CornerTable<TScalar> {
+        let mut indices = Vec::with_capacity(self.indices.len() * 3);
+
+        for face in self.faces() {
+            let face_vertices = self.face_vertices(&face);
+
+            if face_vertices.len() == 3 {
+                indices.extend_from_slice(face_vertices);
+                continue;
+            }
+
+            let positions: Vec<_> = self.face_positions(&face).cloned().collect();
+            let normal = newell_normal(&positions);
+
+            for triangle in triangulate_polygon(&positions, &normal) {
+                indices.extend(triangle.iter().map(|index| face_vertices[*index]));
+            }
+        }
+
+        return CornerTable::from_vertices_and_indices(&self.vertices, &indices);
+    }
+
+    ///
+    /// Creates polygon mesh from corner table merging pairs of adjacent triangles into quads.
+    /// Pair is merged when angle between triangle normals does not exceed `max_angle` (in radians)
+    /// and resulting quad is convex. Longer shared edges are merged first, so quads
+    /// triangulated along diagonals are restored. Not merged triangles are kept as is.
+    ///
+    pub fn from_corner_table<TIndex: ElementIndex>(mesh: &CornerTable<TScalar, TIndex>, max_angle: TScalar) -> Self {
+        let mut polygon_mesh = Self::new();
+
+        // Vertices
+        let mut vertex_map = Vec::new();
+
+        for vertex in mesh.vertices() {
+            if vertex.index() >= vertex_map.len() {
+                vertex_map.resize(vertex.index() + 1, usize::MAX);
+            }
+
+            vertex_map[vertex.index()] = polygon_mesh.add_vertex(*mesh.vertex_position(&vertex));
+        }
+
+        // Collect quad candidates
+        let cos_max_angle = Float::cos(max_angle);
+        let mut candidates = Vec::new();
+
+        for edge in mesh.edges() {
+            let (face, Some(opposite_face)) = mesh.edge_faces(&edge) else {
+                continue;
+            };
+
+            // Walk from corners opposite to shared edge
+            let mut walker = CornerWalker::from_corner(mesh, edge.get_corner_index());
+            let p = walker.get_corner().get_vertex_index();
+            let x = walker.next().get_corner().get_vertex_index();
+            let y = walker.next().get_corner().get_vertex_index();
+            let q = walker.next().opposite().get_corner().get_vertex_index();
+
+            let p_pos = mesh.vertex_position(&p);
+            let q_pos = mesh.vertex_position(&q);
+            let x_pos = mesh.vertex_position(&x);
+            let y_pos = mesh.vertex_position(&y);
+
+            let n1 = (x_pos - p_pos).cross(&(y_pos - p_pos));
+            let n2 = (y_pos - q_pos).cross(&(x_pos - q_pos));
+
+            let is_coplanar = n1.dot(&n2) >= cos_max_angle * n1.norm() * n2.norm();
+            let is_convex =
+                (x_pos - p_pos).cross(&(q_pos - p_pos)).dot(&n1) > TScalar::zero() &&
+                (y_pos - q_pos).cross(&(p_pos - q_pos)).dot(&n1) > TScalar::zero();
+
+            if n1.norm_squared() > TScalar::zero() && n2.norm_squared() > TScalar::zero() && is_coplanar && is_convex {
+                let length_squared = (y_pos - x_pos).norm_squared();
+                candidates.push((length_squared, face.index(), opposite_face.index(), [p, x, q, y]));
+            }
+        }
+
+        candidates.sort_by(|(l1, ..), (l2, ..)| l2.partial_cmp(l1).unwrap());
+
+        // Greedily merge pairs
+        let faces_count = mesh.faces().last().map_or(0, |face| face.index() + 1);
+        let mut quads = Vec::new();
+        let mut face_quad: Vec<Option<usize>> = vec![None; faces_count];
+
+        for (_, face, opposite_face, quad) in candidates {
+            if face_quad[face].is_some() || face_quad[opposite_face].is_some() {
+                continue;
+            }
+
+            face_quad[face] = Some(quads.len());
+            face_quad[opposite_face] = Some(quads.len());
+            quads.push((quad, false));
+        }
+
+        // Faces
+        for face in mesh.faces() {
+            match face_quad[face.index()] {
+                Some(quad_index) => {
+                    let (quad, added) = &mut quads[quad_index];
+
+                    if !*added {
+                        *added = true;
+                        polygon_mesh.add_face(&quad.map(|vertex| vertex_map[vertex.index()]));
+                    }
+                },
+                None => {
+                    let (v1, v2, v3) = mesh.face_vertices(&face);
+                    polygon_mesh.add_face(&[vertex_map[v1.index()], vertex_map[v2.index()], vertex_map[v3.index()]]);
+                }
+            }
+        }
+
+        return polygon_mesh;
+    }
+}
+
+impl<TScalar: RealNumber> Default for PolygonMesh<TScalar> {
+    fn default() -> Self {
+        return Self {
+            vertices: Vec::new(),
+            indices: Vec::new(),
+            face_offsets: vec![0]
+        };
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use nalgebra::{Point3, Vector3};
+    use crate::mesh::{
+        corner_table::{prelude::CornerTableF, test_helpers::{create_uv_sphere_mesh, create_torus_mesh}},
+        traits::Mesh
+    };
+    use super::super::prelude::PolygonMeshF;
+
+    fn create_grid(size: usize) -> PolygonMeshF {
+        let mut mesh = PolygonMeshF::new();
+
+        for i in 0..=size {
+            for j in 0..=size {
+                mesh.add_vertex(Point3::new(j as f32, i as f32, 0.0));
+            }
+        }
+
+        let vertex = |i: usize, j: usize| i * (size + 1) + j;
+
+        for i in 0..size {
+            for j in 0..size {
+                mesh.add_face(&[vertex(i, j), vertex(i, j + 1), vertex(i + 1, j + 1), vertex(i + 1, j)]);
+            }
+        }
+
+        return mesh;
+    }
+
+    #[test]
+    fn should_iterate_face_loops() {
+        let mut mesh = PolygonMeshF::new();
+
+        for i in 0..6 {
+            let angle = i as f32 * std::f32::consts::PI / 3.0;
+            mesh.add_vertex(Point3::new(angle.cos(), angle.sin(), 0.0));
+        }
+
+        mesh.add_vertex(Point3::new(0.0, 0.0, -1.0));
+
+        let hexagon = mesh.add_face(&[0, 1, 2, 3, 4, 5]);
+        let triangle = mesh.add_face(&[1, 0, 6]);
+
+        assert_eq!(mesh.faces().collect::<Vec<_>>(), vec![0, 1]);
+        assert_eq!(mesh.face_vertices(&hexagon), &[0, 1, 2, 3, 4, 5]);
+        assert_eq!(mesh.face_vertices(&triangle), &[1, 0, 6]);
+        assert_eq!(mesh.face_positions(&triangle).count(), 3);
+        assert!((mesh.face_normal(&hexagon) - Vector3::z()).norm() < 1e-6);
+        assert!(!mesh.is_quad_mesh());
+
+        let corner_table = mesh.to_corner_table();
+        assert_eq!(corner_table.faces().count(), 5);
+    }
+
+    #[test]
+    fn should_restore_quads_after_triangulation() {
+        let mesh = create_grid(4);
+        let corner_table = mesh.to_corner_table();
+        assert_eq!(corner_table.faces().count(), 32);
+
+        let restored = PolygonMeshF::from_corner_table(&corner_table, 1e-3);
+        assert_eq!(restored.faces_count(), 16);
+        assert_eq!(restored.vertices_count(), 25);
+        assert!(restored.is_quad_mesh());
+
+        for face in restored.faces() {
+            assert!((restored.face_normal(&face) - Vector3::z()).norm() < 1e-6);
+        }
+    }
+
+    #[test]
+    fn should_keep_triangles_of_non_coplanar_pairs() {
+        let vertices = vec![
+            Point3::new(0.0, 0.0, 0.0),
+            Point3::new(1.0, 0.0, 0.0),
+            Point3::new(1.0, 1.0, 1.0),
+            Point3::new(0.0, 1.0, 0.0)
+        ];
+        let corner_table = CornerTableF::from_vertices_and_indices(&vertices, &[0, 1, 3, 1, 2, 3]);
+
+        let mesh = PolygonMeshF::from_corner_table(&corner_table, 1e-3);
+        assert_eq!(mesh.faces_count(), 2);
+
+        let mesh = PolygonMeshF::from_corner_table(&corner_table, std::f32::consts::FRAC_PI_2);
+        assert_eq!(mesh.faces_count(), 1);
+        assert_eq!(mesh.face_vertices(&0).len(), 4);
+    }
+
+    #[test]
+    fn should_merge_planar_quads_of_curved_surfaces() {
+        let sphere = create_uv_sphere_mesh(8, 12);
+        let mesh = PolygonMeshF::from_corner_table(&sphere, 1e-3);
+        let quads = mesh.faces().filter(|face| mesh.face_vertices(face).len() == 4).count();
+        assert_eq!(quads, 12 * 6);
+        assert_eq!(mesh.faces_count(), 12 * 6 + 2 * 12);
+
+        let torus = create_torus_mesh(8, 6);
+        let mesh = PolygonMeshF::from_corner_table(&torus, 1e-3);
+        assert!(mesh.is_quad_mesh());
+        assert_eq!(mesh.faces_count(), 48);
+        assert_eq!(mesh.to_corner_table().faces().count(), 96);
+    }
+}
@@ -0,0 +1,4 @@
+pub mod mesh;
+pub mod prelude;
+
+pub(crate) mod triangulation;
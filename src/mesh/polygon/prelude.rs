@@ -0,0 +1,4 @@
+use super::mesh::PolygonMesh;
+
+pub type PolygonMeshF = PolygonMesh<f32>;
+pub type PolygonMeshD = PolygonMesh<f64>;
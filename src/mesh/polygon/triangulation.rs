@@ -0,0 +1,123 @@
+use nalgebra::{Point3, Vector3};
+use crate::geometry::traits::RealNumber;
+
+///
+/// Triangulates simple polygon lying approximately in plane with given normal using ear clipping.
+/// Returns triangles as indices into `polygon`. Falls back to fan triangulation
+/// when no ear can be found (degenerate or self-intersecting polygon).
+///
+pub fn triangulate_polygon<TScalar: RealNumber>(polygon: &[Point3<TScalar>], normal: &Vector3<TScalar>) -> Vec<[usize; 3]> {
+    let mut triangles = Vec::with_capacity(polygon.len().saturating_sub(2));
+    let mut remaining: Vec<usize> = (0..polygon.len()).collect();
+
+    while remaining.len() > 3 {
+        let ear = (0..remaining.len()).find(|&i| is_ear(polygon, &remaining, i, normal));
+
+        let Some(ear) = ear else {
+            break;
+        };
+
+        let count = remaining.len();
+        triangles.push([
+            remaining[(ear + count - 1) % count],
+            remaining[ear],
+            remaining[(ear + 1) % count]
+        ]);
+        remaining.remove(ear);
+    }
+
+    for i in 1..remaining.len().saturating_sub(1) {
+        triangles.push([remaining[0], remaining[i], remaining[i + 1]]);
+    }
+
+    return triangles;
+}
+
+fn is_ear<TScalar: RealNumber>(polygon: &[Point3<TScalar>], remaining: &[usize], index: usize, normal: &Vector3<TScalar>) -> bool {
+    let count = remaining.len();
+    let previous = remaining[(index + count - 1) % count];
+    let current = remaining[index];
+    let next = remaining[(index + 1) % count];
+
+    let a = &polygon[previous];
+    let b = &polygon[current];
+    let c = &polygon[next];
+
+    // Reflex or degenerate corner
+    if (b - a).cross(&(c - b)).dot(normal) <= TScalar::zero() {
+        return false;
+    }
+
+    return remaining.iter()
+        .filter(|&&vertex| vertex != previous && vertex != current && vertex != next)
+        .all(|&vertex| !is_inside_triangle(&polygon[vertex], a, b, c, normal));
+}
+
+/// Returns `true` if projection of point to plane is inside of triangle or on its boundary
+fn is_inside_triangle<TScalar: RealNumber>(
+    point: &Point3<TScalar>,
+    a: &Point3<TScalar>,
+    b: &Point3<TScalar>,
+    c: &Point3<TScalar>,
+    normal: &Vector3<TScalar>
+) -> bool {
+    return 
+        (b - a).cross(&(point - a)).dot(normal) >= TScalar::zero() &&
+        (c - b).cross(&(point - b)).dot(normal) >= TScalar::zero() &&
+        (a - c).cross(&(point - c)).dot(normal) >= TScalar::zero();
+}
+
+///
+/// Returns normal of polygon computed by Newell's method.
+/// Not normalized, zero for degenerate polygon.
+///
+pub fn newell_normal<TScalar: RealNumber>(polygon: &[Point3<TScalar>]) -> Vector3<TScalar> {
+    let mut normal = Vector3::zeros();
+
+    for i in 0..polygon.len() {
+        let current = &polygon[i];
+        let next = &polygon[(i + 1) % polygon.len()];
+
+        normal.x += (current.y - next.y) * (current.z + next.z);
+        normal.y += (current.z - next.z) * (current.x + next.x);
+        normal.z += (current.x - next.x) * (current.y + next.y);
+    }
+
+    return normal;
+}
+
+#[cfg(test)]
+mod tests {
+    use nalgebra::{Point3, Vector3};
+    use crate::geometry::primitives::triangle3::Triangle3;
+    use super::{triangulate_polygon, newell_normal};
+
+    #[test]
+    fn should_triangulate_non_convex_polygon() {
+        // L-shape
+        let polygon = vec![
+            Point3::<f32>::new(0.0, 0.0, 0.0),
+            Point3::<f32>::new(2.0, 0.0, 0.0),
+            Point3::<f32>::new(2.0, 1.0, 0.0),
+            Point3::<f32>::new(1.0, 1.0, 0.0),
+            Point3::<f32>::new(1.0, 2.0, 0.0),
+            Point3::<f32>::new(0.0, 2.0, 0.0)
+        ];
+
+        let normal = newell_normal(&polygon);
+        assert_eq!(normal.normalize(), Vector3::z());
+
+        let triangles = triangulate_polygon(&polygon, &normal);
+        assert_eq!(triangles.len(), 4);
+
+        let mut area = 0.0;
+
+        for [a, b, c] in triangles {
+            let triangle_normal = (polygon[b] - polygon[a]).cross(&(polygon[c] - polygon[a]));
+            assert!(triangle_normal.z > 0.0);
+            area += Triangle3::area(&polygon[a], &polygon[b], &polygon[c]);
+        }
+
+        assert_eq!(area, 3.0);
+    }
+}
@@ -1,10 +1,17 @@
 use nalgebra::{Point3, Vector3};
-use crate::{mesh::traits::{Mesh}, geometry::{traits::RealNumber, primitives::triangle3::Triangle3}};
+#[cfg(feature = "serde")]
+use serde::{Serialize, Deserialize};
+use crate::{
+    mesh::traits::{Mesh},
+    geometry::{traits::RealNumber, primitives::triangle3::Triangle3},
+    algo::{merge_points::merge_points, orientation::{orient_faces, FaceOrientation}}
+};
 use super::traversal::{FacesIter, VerticesIter, EdgesIter};
 
 ///
 /// Polygon soup
 /// 
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub struct PolygonSoup<TScalar: RealNumber> {
    pub(super) vertices: Vec<Point3<TScalar>>
 }
@@ -20,6 +27,24 @@ impl<TScalar: RealNumber> PolygonSoup<TScalar> {
         self.vertices.push(v2);
         self.vertices.push(v3);
     }
+
+    ///
+    /// Repairs winding of faces, see [orient_faces]. Faces are connected through exactly coincident vertices.
+    /// Returns flipped faces.
+    ///
+    pub fn orient_faces(&mut self, orientation: FaceOrientation) -> Vec<usize> {
+        let mut indexed = merge_points(&self.vertices);
+        let flipped: Vec<_> = orient_faces(&indexed.points, &mut indexed.indices, orientation)
+            .into_iter()
+            .map(|face| face * 3)
+            .collect();
+
+        for face in &flipped {
+            self.vertices.swap(face + 1, face + 2);
+        }
+
+        return flipped;
+    }
 }
 
 impl<TScalar: RealNumber> Default for PolygonSoup<TScalar> {
@@ -1,6 +1,8 @@
 use std::{hash::Hash, fmt::Display, ops::{Index, IndexMut}};
 
 use nalgebra::{Point3, Vector3};
+#[cfg(feature = "rayon")]
+use rayon::iter::{IntoParallelIterator, ParallelIterator};
 
 use crate::geometry::{traits::RealNumber, primitives::triangle3::Triangle3};
 
@@ -35,6 +37,18 @@ pub trait Mesh {
     /// Creates mesh from vertices and face indices
     fn from_vertices_and_indices(vertices: &[Point3<Self::ScalarType>], faces: &[usize]) -> Self;
 
+    ///
+    /// Creates mesh same way as [Mesh::from_vertices_and_indices]. Also returns `(original, duplicate)` pairs of vertices
+    /// added to keep mesh manifold, duplicate is index of vertex in order of [Mesh::vertices].
+    /// Default implementation reports no duplicates, meshes that split vertices should override it.
+    ///
+    fn from_vertices_and_indices_with_duplicates(vertices: &[Point3<Self::ScalarType>], faces: &[usize]) -> (Self, Vec<(usize, usize)>) 
+    where 
+        Self: Sized
+    {
+        return (Self::from_vertices_and_indices(vertices, faces), Vec::new());
+    }
+
     /// Iterator over mesh faces
     fn faces(&self) -> Self::FacesIter<'_>;
     /// Iterator over mesh vertices
@@ -42,6 +56,30 @@ pub trait Mesh {
     /// Iterator over mesh edges
     fn edges(&self) -> Self::EdgesIter<'_>;
 
+    /// Parallel iterator over mesh faces, yields faces in same order as [Mesh::faces].
+    /// Default implementation collects faces before splitting them between threads.
+    #[cfg(feature = "rayon")]
+    #[inline]
+    fn par_faces(&self) -> impl ParallelIterator<Item = Self::FaceDescriptor> + '_
+    where
+        Self: Sync,
+        Self::FaceDescriptor: Send
+    {
+        return self.faces().collect::<Vec<_>>().into_par_iter();
+    }
+
+    /// Parallel iterator over mesh vertices, yields vertices in same order as [Mesh::vertices].
+    /// Default implementation collects vertices before splitting them between threads.
+    #[cfg(feature = "rayon")]
+    #[inline]
+    fn par_vertices(&self) -> impl ParallelIterator<Item = Self::VertexDescriptor> + '_
+    where
+        Self: Sync,
+        Self::VertexDescriptor: Send
+    {
+        return self.vertices().collect::<Vec<_>>().into_par_iter();
+    }
+
     /// Return vertices of given face
     fn face_vertices(&self, face: &Self::FaceDescriptor) -> (Self::VertexDescriptor, Self::VertexDescriptor, Self::VertexDescriptor);
     /// Returns edge length
@@ -86,8 +124,30 @@ pub trait Mesh {
         let (v1, v2) = self.edge_positions(edge);
         return (v1 - v2).norm_squared();
     }
+
+    /// Panics if mesh connectivity is corrupted. Called by algorithms in debug builds after editing mesh.
+    /// Default implementation does nothing.
+    #[inline]
+    fn assert_valid(&self) {}
 }
 
+///
+/// Mesh that can be traversed by parallel algorithms, i.e. mesh that is `Sync` and has `Send + Sync` descriptors.
+/// Implemented for every such mesh. Required only by `par_*` algorithms available with `rayon` feature,
+/// their sequential counterparts have same bounds regardless of enabled features.
+///
+#[cfg(feature = "rayon")]
+pub trait ParallelMesh: Mesh<EdgeDescriptor: Send + Sync, VertexDescriptor: Send + Sync, FaceDescriptor: Send + Sync> + Sync {}
+
+#[cfg(feature = "rayon")]
+impl<TMesh> ParallelMesh for TMesh 
+where 
+    TMesh: Mesh + Sync,
+    TMesh::EdgeDescriptor: Send + Sync,
+    TMesh::VertexDescriptor: Send + Sync,
+    TMesh::FaceDescriptor: Send + Sync
+{}
+
 ///
 /// Position on face corner
 /// 
@@ -203,6 +263,29 @@ pub trait VertexProperties: Mesh {
     fn create_vertex_properties_map<TProperty: Default>(&self) -> Self::VertexPropertyMap<TProperty>;
 }
 
+///
+/// Mesh that supports property maps for faces.
+/// Face-property map can be used to associate arbitrary data with faces of mesh with fast access to it by face reference.
+/// Property map is guaranteed to be valid as far as mesh is not modified.
+/// 
+pub trait FaceProperties: Mesh {
+    type FacePropertyMap<TProperty: Default>: PropertyMap<Self::FaceDescriptor, TProperty>;
+
+    fn create_face_properties_map<TProperty: Default>(&self) -> Self::FacePropertyMap<TProperty>;
+}
+
+///
+/// Mesh that supports property maps for edges.
+/// Edge-property map can be used to associate arbitrary data with edges of mesh with fast access to it by edge reference.
+/// Both half-edges of interior edge share same property.
+/// Property map is guaranteed to be valid as far as mesh is not modified.
+/// 
+pub trait EdgeProperties: Mesh {
+    type EdgePropertyMap<TProperty: Default>: PropertyMap<Self::EdgeDescriptor, TProperty>;
+
+    fn create_edge_properties_map<TProperty: Default>(&self) -> Self::EdgePropertyMap<TProperty>;
+}
+
 pub trait SplitFaceAtPoint: Mesh {
     fn split_face(&mut self, face: & Self::FaceDescriptor, point: Point3<Self::ScalarType>);
 }
@@ -127,6 +127,9 @@ impl<TMesh: TopologicalMesh + EditableMesh> IncrementalRemesher<TMesh> {
             if self.project_vertices {
                 self.project_vertices(mesh, &reference_mesh, target_edge_length);
             }
+
+            #[cfg(debug_assertions)]
+            mesh.assert_valid();
         }
     }
 
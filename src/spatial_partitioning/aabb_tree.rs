@@ -1,5 +1,9 @@
 use nalgebra::{Vector3, Point3};
 use num_traits::{Float, One};
+#[cfg(feature = "rayon")]
+use rayon::iter::ParallelIterator;
+#[cfg(feature = "serde")]
+use serde::{Serialize, Deserialize};
 
 use crate::{
     geometry::{
@@ -16,13 +20,21 @@ use crate::{
     }, 
     mesh::traits::Mesh
 };
+#[cfg(feature = "rayon")]
+use crate::mesh::traits::ParallelMesh;
+
+/// Minimal number of objects in subtree that is worth splitting between threads
+#[cfg(feature = "rayon")]
+const PARALLEL_BUILD_MIN_OBJECTS: usize = 4096;
 
 #[derive(PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 enum NodeType {
     Leaf,
     Branch
 }
 
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 struct BinaryNode<TScalar: RealNumber> {
     node_type: NodeType,
     left: usize,
@@ -51,6 +63,11 @@ impl<TScalar: RealNumber> BinaryNode<TScalar> {
 ///     .top_down::<MedianCut>();
 /// ```
 /// 
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "serde", serde(bound(
+    serialize = "TObject: Serialize, TObject::ScalarType: Serialize",
+    deserialize = "TObject: Deserialize<'de>, TObject::ScalarType: Deserialize<'de>"
+)))]
 pub struct AABBTree<TObject>
 where
     TObject: HasBBox3,
@@ -119,6 +136,19 @@ where
         return self;
     }
 
+    /// 
+    /// Parallel version of [top_down](AABBTree::top_down), builds the same tree.
+    /// Subtrees with many objects are built in parallel, each of them using own instance of partitioning strategy.
+    /// 
+    #[cfg(feature = "rayon")]
+    pub fn par_top_down<TPartition: PartitionStrategy<TObject>>(mut self) -> Self 
+    where
+        TObject: Send
+    {
+        self.nodes.clear();
+        return self.par_top_down_build_subtree::<TPartition>(1);
+    }
+
     /// Traverse leaf node of tree
     #[inline]
     pub fn traverse<TFunc>(&self, visit: &mut TFunc) 
@@ -184,6 +214,65 @@ where
         }
     }
 
+    /// Builds subtree over all objects of tree. Splits objects between two trees built in parallel
+    /// and merges them afterwards, so resulting tree is the same as one built sequentially
+    #[cfg(feature = "rayon")]
+    fn par_top_down_build_subtree<TPartition: PartitionStrategy<TObject>>(mut self, depth: usize) -> Self 
+    where
+        TObject: Send
+    {
+        let last = self.objects.len() - 1;
+
+        if self.objects.len() < PARALLEL_BUILD_MIN_OBJECTS || depth >= self.max_depth || last <= self.min_objects_per_leaf {
+            self.top_down_build_node(0, last, depth, &mut TPartition::default());
+            return self;
+        }
+
+        let Ok(split_at) = TPartition::default().split(&mut self.objects, 0, last) else {
+            // Create leaf node if split failed
+            self.leaf_node_from_objects(0, last);
+            return self;
+        };
+
+        let right = Self {
+            nodes: Vec::new(),
+            objects: self.objects.split_off(split_at),
+            min_objects_per_leaf: self.min_objects_per_leaf,
+            max_depth: self.max_depth
+        };
+
+        let (mut left, right) = rayon::join(
+            || self.par_top_down_build_subtree::<TPartition>(depth + 1), 
+            || right.par_top_down_build_subtree::<TPartition>(depth + 1)
+        );
+
+        // Append right subtree, shifting indices of its nodes and objects
+        let nodes_offset = left.nodes.len();
+        let objects_offset = left.objects.len();
+        let left_root = nodes_offset - 1;
+        let right_root = nodes_offset + right.nodes.len() - 1;
+
+        left.nodes.extend(right.nodes.into_iter().map(|mut node| {
+            let offset = if node.is_leaf() { objects_offset } else { nodes_offset };
+            node.left += offset;
+            node.right += offset;
+            return node;
+        }));
+        left.objects.extend(right.objects);
+
+        let mut bbox = left.nodes[left_root].bbox;
+        bbox.add_box3(&left.nodes[right_root].bbox);
+
+        left.nodes.push(BinaryNode {
+            bbox,
+            node_type: NodeType::Branch,
+            left: left_root,
+            right: right_root
+        });
+
+        return left;
+    }
+
     /// Create leaf node from set of objects
     fn leaf_node_from_objects(&mut self, first: usize, last: usize) -> usize {
         // Compute bounding box of set of objects
@@ -217,6 +306,20 @@ impl<TScalar: RealNumber> AABBTree<Triangle3<TScalar>>{
 
         return Self::new(faces);
     }
+
+    /// Parallel version of [from_mesh](AABBTree::from_mesh), collects faces and their bounding boxes in parallel
+    #[cfg(feature = "rayon")]
+    pub fn par_from_mesh<TMesh: ParallelMesh<ScalarType = TScalar>>(mesh: &TMesh) -> Self {
+        let objects = mesh.par_faces()
+            .map(|face| {
+                let triangle = mesh.face_positions(&face);
+                let bbox = triangle.bbox();
+                return (triangle, bbox);
+            })
+            .collect();
+
+        return Self { objects, ..Self::empty() };
+    }
 }
 
 impl<TObject> AABBTree<TObject>
@@ -365,3 +468,53 @@ where
             .or_else(|_| Self::try_split_by_axis(split_axises[2].1, objects, first, last));
     }
 }
+
+#[cfg(all(test, feature = "rayon"))]
+mod tests {
+    use nalgebra::Point3;
+
+    use crate::{
+        geometry::primitives::triangle3::Triangle3,
+        mesh::{corner_table::test_helpers::create_uv_sphere_mesh, traits::Mesh}
+    };
+    use super::{AABBTree, MedianCut, PARALLEL_BUILD_MIN_OBJECTS};
+
+    type Leaf = (Vec<Point3<f32>>, Point3<f32>, Point3<f32>);
+
+    #[test]
+    fn parallel_from_mesh_should_collect_same_objects_as_sequential() {
+        let mesh = create_uv_sphere_mesh(20, 40);
+
+        let parallel = AABBTree::par_from_mesh(&mesh);
+        let sequential = AABBTree::from_mesh(&mesh);
+
+        let objects = |tree: &AABBTree<Triangle3<f32>>| {
+            return tree.objects.iter()
+                .map(|(triangle, bbox)| ([*triangle.p1(), *triangle.p2(), *triangle.p3()], *bbox.get_min(), *bbox.get_max()))
+                .collect::<Vec<_>>();
+        };
+
+        assert_eq!(objects(&parallel), objects(&sequential));
+    }
+
+    #[test]
+    fn parallel_top_down_should_build_same_tree_as_sequential() {
+        let mesh = create_uv_sphere_mesh(50, 100);
+
+        let parallel = AABBTree::par_from_mesh(&mesh).par_top_down::<MedianCut>();
+        let sequential = AABBTree::from_mesh(&mesh).top_down::<MedianCut>();
+
+        let leaves = |tree: &AABBTree<Triangle3<f32>>| {
+            let mut leaves: Vec<Leaf> = Vec::new();
+            tree.traverse(&mut |(objects, bbox)| {
+                let points = objects.iter().map(|(triangle, _)| *triangle.p1()).collect();
+                leaves.push((points, *bbox.get_min(), *bbox.get_max()));
+            });
+            return leaves;
+        };
+
+        assert!(mesh.faces().count() > PARALLEL_BUILD_MIN_OBJECTS);
+        assert_eq!(parallel.nodes.len(), sequential.nodes.len());
+        assert_eq!(leaves(&parallel), leaves(&sequential));
+    }
+}
@@ -2,6 +2,8 @@ use std::collections::HashMap;
 
 use nalgebra::{Vector3, Point3};
 use num_traits::{cast, Float, Zero};
+#[cfg(feature = "rayon")]
+use rayon::iter::ParallelIterator;
 
 use crate::{
     geometry::{
@@ -18,6 +20,8 @@ use crate::{
     }, 
     mesh::traits::Mesh, algo::utils
 };
+#[cfg(feature = "rayon")]
+use crate::mesh::traits::ParallelMesh;
 
 type Cell = Vector3<isize>;
 type CellRange = Box3<isize>;
@@ -206,4 +210,37 @@ impl<TScalar: RealNumber> Grid<Triangle3<TScalar>>{
 
         return Self::new(faces);
     }
+
+    /// Create grid from faces of triangular mesh, faces are collected in parallel
+    #[cfg(feature = "rayon")]
+    pub fn par_from_mesh<TMesh: ParallelMesh<ScalarType = TScalar>>(mesh: &TMesh) -> Self {
+        let faces: Vec<Triangle3<TScalar>> = mesh.par_faces()
+            .map(|face| mesh.face_positions(&face))
+            .collect();
+
+        return Self::new(faces);
+    }
+}
+
+#[cfg(all(test, feature = "rayon"))]
+mod tests {
+    use crate::mesh::corner_table::test_helpers::create_uv_sphere_mesh;
+
+    use super::Grid;
+
+    #[test]
+    fn parallel_from_mesh_should_build_same_grid_as_sequential() {
+        let mesh = create_uv_sphere_mesh(20, 40);
+
+        let parallel = Grid::par_from_mesh(&mesh);
+        let sequential = Grid::from_mesh(&mesh);
+
+        assert_eq!(parallel.cell_size, sequential.cell_size);
+        assert_eq!(parallel.cells, sequential.cells);
+        assert_eq!(parallel.objects.len(), sequential.objects.len());
+
+        for (p, s) in parallel.objects.iter().zip(&sequential.objects) {
+            assert_eq!([p.p1(), p.p2(), p.p3()], [s.p1(), s.p2(), s.p3()]);
+        }
+    }
 }